@@ -1,10 +1,10 @@
 //! Cartesian system of coordinates.
 
-use std::ops::{Add, Div, Mul};
+use core::ops::{Add, Div, Mul, Sub};
 
 use num_traits::{Float, FloatConst, Signed, Zero};
 
-use crate::{geographic::Geographic, transform::Transform};
+use crate::{geographic::Geographic, radian::Radian, tolerance::Tolerance, transform::Transform};
 
 /// Coordinates according to the cartesian system of coordinates.
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
@@ -15,47 +15,26 @@ pub struct Cartesian<T> {
     pub z: T,
 }
 
+/// Converts the geographic point into the cartesian one whose radial distance is the altitude.
+///
+/// An altitude of zero is taken as a point on the unit sphere, instead of the center of it. Use
+/// [`Cartesian::from_geographic_exact`] to get the origin instead.
 impl<T> From<Geographic<T>> for Cartesian<T>
 where
     T: Signed + Float + FloatConst,
 {
     fn from(coords: Geographic<T>) -> Self {
-        let radial_distance = match coords.altitude.into_inner() {
-            altitude if altitude == T::zero() => T::one(),
-            altitude => altitude,
-        };
-
         let theta = T::FRAC_PI_2() - coords.latitude.into_inner();
         let phi = coords.longitude.into_inner();
 
-        // improves sin & cos precision for exact numbers
-        let precise_sin_cos = |rad: T| -> (T, T) {
-            if rad.abs() == T::FRAC_PI_2() {
-                return (rad.signum(), T::zero());
-            } else if rad.abs() == T::PI() {
-                return (T::zero(), -T::one());
-            } else if rad == T::zero() {
-                return (T::zero(), T::one());
-            }
-
-            (rad.sin(), rad.cos())
-        };
-
-        let (theta_sin, theta_cos) = precise_sin_cos(theta);
-        let (phi_sin, phi_cos) = precise_sin_cos(phi);
-
-        Self {
-            x: radial_distance * theta_sin * phi_cos,
-            y: radial_distance * theta_sin * phi_sin,
-            z: radial_distance * theta_cos,
-        }
+        Self::spherical(coords.radial_distance(), theta, phi)
     }
 }
 
 impl<T> IntoIterator for Cartesian<T> {
     type Item = T;
 
-    type IntoIter = std::array::IntoIter<T, 3>;
+    type IntoIter = core::array::IntoIter<T, 3>;
 
     fn into_iter(self) -> Self::IntoIter {
         [self.x, self.y, self.z].into_iter()
@@ -77,6 +56,21 @@ where
     }
 }
 
+impl<T> Sub for Cartesian<T>
+where
+    T: Copy + Sub<Output = T>,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+            z: self.z - rhs.z,
+        }
+    }
+}
+
 impl<T> Mul<T> for Cartesian<T>
 where
     T: Copy + Mul<Output = T>,
@@ -138,8 +132,140 @@ where
             .with_y(self.z * rhs.x - self.x * rhs.z)
             .with_z(self.x * rhs.y - self.y * rhs.x)
     }
+
+    /// Returns the [scalar triple product](https://en.wikipedia.org/wiki/Triple_product) of self,
+    /// b and c, which is the signed volume of the parallelepiped they define: self · (b × c).
+    pub fn scalar_triple(&self, b: &Self, c: &Self) -> T {
+        self.dot(&b.cross(c))
+    }
+
+    /// Returns the mirror reflection of the direction of self about the given surface normal,
+    /// as a ray bouncing off that surface: self - 2(self · n̂)n̂.
+    ///
+    /// The normal does not need to be a unit vector, but it must not be zero.
+    pub fn reflect(&self, normal: &Self) -> Self {
+        let normal = normal.normal();
+        *self - normal * (self.dot(&normal) * (T::one() + T::one()))
+    }
+
+    /// Returns the angle (in radians) between a ray with the direction of self and the given
+    /// surface normal, measured from the normal to the opposite of the ray.
+    ///
+    /// The angle is zero for a ray hitting the surface head-on and π/2 for a grazing one. Greater
+    /// angles mean the ray leaves the surface instead of hitting it.
+    pub fn angle_of_incidence(&self, normal: &Self) -> T {
+        let cos = -self.dot(normal) / (self.magnitude() * normal.magnitude());
+        cos.max(-T::one()).min(T::one()).acos()
+    }
+
+    /// Returns true if, and only if, the distance between self and rhs is not greater than the
+    /// given tolerance.
+    pub fn approx_eq(&self, rhs: &Self, tolerance: Tolerance<T>) -> bool {
+        tolerance.approx_eq(self.distance(rhs), T::zero())
+    }
+
+    /// Returns the component-wise minimum between self and rhs.
+    pub fn min(&self, rhs: &Self) -> Self {
+        Self::new(self.x.min(rhs.x), self.y.min(rhs.y), self.z.min(rhs.z))
+    }
+
+    /// Returns the component-wise maximum between self and rhs.
+    pub fn max(&self, rhs: &Self) -> Self {
+        Self::new(self.x.max(rhs.x), self.y.max(rhs.y), self.z.max(rhs.z))
+    }
+
+    /// Returns self with each component restricted to the range given by the corresponding
+    /// components of min and max.
+    pub fn clamp(&self, min: &Self, max: &Self) -> Self {
+        self.max(min).min(max)
+    }
 }
 
+impl<T> Cartesian<T>
+where
+    T: Signed + Float + FloatConst,
+{
+    /// Returns the cartesian point with the given
+    /// [spherical coordinates](https://en.wikipedia.org/wiki/Spherical_coordinate_system), using
+    /// the physics convention: the radial distance, the polar angle (from the z axis) and the
+    /// azimuthal angle (from the x axis).
+    pub fn from_spherical(radius: T, theta: Radian<T>, phi: Radian<T>) -> Self {
+        Self::spherical(radius, theta.into_inner(), phi.into_inner())
+    }
+
+    /// Returns the cartesian point whose radial distance is the altitude of the given geographic
+    /// point, as it is.
+    ///
+    /// Unlike the lenient [`From`] conversion, which takes a zero altitude as a point on the unit
+    /// sphere, this one converts any point of zero altitude into the origin.
+    pub fn from_geographic_exact(coords: Geographic<T>) -> Self {
+        Self::spherical(
+            coords.altitude.into_inner(),
+            T::FRAC_PI_2() - coords.latitude.into_inner(),
+            coords.longitude.into_inner(),
+        )
+    }
+
+    /// Returns the radial distance, the polar angle and the azimuthal angle of self, as defined
+    /// by [`Cartesian::from_spherical`].
+    ///
+    /// The angles of the origin are both zero.
+    pub fn to_spherical(&self) -> (T, Radian<T>, Radian<T>) {
+        let radius = self.magnitude();
+        if radius == T::zero() {
+            return (radius, Radian::from(T::zero()), Radian::from(T::zero()));
+        }
+
+        let theta = (self.z / radius).max(-T::one()).min(T::one()).acos();
+        let phi = self.y.atan2(self.x);
+
+        (radius, theta.into(), phi.into())
+    }
+
+    fn spherical(radius: T, theta: T, phi: T) -> Self {
+        // improves sin & cos precision for exact numbers
+        let precise_sin_cos = |rad: T| -> (T, T) {
+            if rad.abs() == T::FRAC_PI_2() {
+                return (rad.signum(), T::zero());
+            } else if rad.abs() == T::PI() {
+                return (T::zero(), -T::one());
+            } else if rad == T::zero() {
+                return (T::zero(), T::one());
+            }
+
+            (rad.sin(), rad.cos())
+        };
+
+        let (theta_sin, theta_cos) = precise_sin_cos(theta);
+        let (phi_sin, phi_cos) = precise_sin_cos(phi);
+
+        Self {
+            x: radius * theta_sin * phi_cos,
+            y: radius * theta_sin * phi_sin,
+            z: radius * theta_cos,
+        }
+    }
+}
+
+macro_rules! impl_cartesian_consts {
+    ($($float:ty),+) => {
+        $(
+            impl Cartesian<$float> {
+                /// The cartesian origin of coordinates.
+                pub const ORIGIN: Self = Self::new(0., 0., 0.);
+                /// The unit vector along the x axis.
+                pub const UNIT_X: Self = Self::new(1., 0., 0.);
+                /// The unit vector along the y axis.
+                pub const UNIT_Y: Self = Self::new(0., 1., 0.);
+                /// The unit vector along the z axis.
+                pub const UNIT_Z: Self = Self::new(0., 0., 1.);
+            }
+        )+
+    };
+}
+
+impl_cartesian_consts!(f32, f64);
+
 impl<T> Cartesian<T>
 where
     T: Zero,
@@ -155,6 +281,11 @@ where
 }
 
 impl<T> Cartesian<T> {
+    /// Returns the cartesian point with the given coordinates.
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+
     pub fn with_x(self, x: T) -> Self {
         Self { x, ..self }
     }
@@ -173,13 +304,67 @@ impl<T> Cartesian<T> {
     }
 }
 
+/// Serialization of [`Cartesian`] as a `[x, y, z]` array, which is more compact than the default
+/// map representation.
+///
+/// Meant to be used through the `#[serde(with = "geocart::cartesian::serde_array")]` attribute.
+///
+/// ## Example
+/// ```
+/// use geocart::Cartesian;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Vertex {
+///     #[serde(with = "geocart::cartesian::serde_array")]
+///     position: Cartesian<f64>,
+/// }
+///
+/// let vertex = Vertex {
+///     position: Cartesian::new(1., 2., 3.),
+/// };
+///
+/// assert_eq!(
+///     serde_json::to_string(&vertex).unwrap(),
+///     r#"{"position":[1.0,2.0,3.0]}"#
+/// );
+/// ```
+#[cfg(feature = "serde")]
+pub mod serde_array {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::Cartesian;
+
+    /// Serializes the given point as a `[x, y, z]` array.
+    pub fn serialize<T, S>(point: &Cartesian<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize,
+        S: Serializer,
+    {
+        [&point.x, &point.y, &point.z].serialize(serializer)
+    }
+
+    /// Deserializes a point from a `[x, y, z]` array, rejecting arrays of any other length.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Cartesian<T>, D::Error>
+    where
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        let [x, y, z] = <[T; 3]>::deserialize(deserializer)?;
+        Ok(Cartesian { x, y, z })
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use std::f64::consts::{FRAC_PI_2, PI};
+    use std::{
+        f32,
+        f64::consts::{FRAC_PI_2, FRAC_PI_4, PI},
+    };
 
     use crate::{
         cartesian::Cartesian,
-        geographic::{Geographic, Latitude, Longitude},
+        geographic::{Altitude, Geographic, Latitude, Longitude},
     };
 
     #[test]
@@ -238,4 +423,383 @@ mod tests {
             );
         });
     }
+
+    #[test]
+    fn cartesian_from_geographic_f32() {
+        struct Test {
+            name: &'static str,
+            input: Geographic<f32>,
+            output: Cartesian<f32>,
+        }
+
+        vec![
+            Test {
+                name: "north point",
+                input: Geographic::origin().with_latitude(Latitude::from(f32::consts::FRAC_PI_2)),
+                output: Cartesian::origin().with_z(1.),
+            },
+            Test {
+                name: "east point",
+                input: Geographic::origin().with_longitude(Longitude::from(f32::consts::FRAC_PI_2)),
+                output: Cartesian::origin().with_y(1.),
+            },
+            Test {
+                name: "back point",
+                input: Geographic::origin().with_longitude(Longitude::from(f32::consts::PI)),
+                output: Cartesian::origin().with_x(-1.),
+            },
+            Test {
+                name: "arbitrary point",
+                input: Geographic::origin()
+                    .with_longitude(Longitude::from(f32::consts::FRAC_PI_4))
+                    .with_latitude(Latitude::from(f32::consts::FRAC_PI_4)),
+                output: Cartesian::origin()
+                    .with_x(0.5)
+                    .with_y(0.5)
+                    .with_z(0.5_f32.sqrt()),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let point = Cartesian::from(test.input);
+            let tolerance = 1e-06;
+
+            point.into_iter().zip(test.output).for_each(|(got, want)| {
+                assert!(
+                    (got - want).abs() < tolerance,
+                    "{}: got cartesian point = {:#?}, want {:#?}",
+                    test.name,
+                    point,
+                    test.output
+                );
+            });
+        });
+    }
+
+    #[test]
+    fn cartesian_consts() {
+        struct Test {
+            name: &'static str,
+            constant: Cartesian<f64>,
+            runtime: Cartesian<f64>,
+        }
+
+        vec![
+            Test {
+                name: "origin",
+                constant: Cartesian::<f64>::ORIGIN,
+                runtime: Cartesian::origin(),
+            },
+            Test {
+                name: "unit x",
+                constant: Cartesian::<f64>::UNIT_X,
+                runtime: Cartesian::origin().with_x(1.),
+            },
+            Test {
+                name: "unit y",
+                constant: Cartesian::<f64>::UNIT_Y,
+                runtime: Cartesian::origin().with_y(1.),
+            },
+            Test {
+                name: "unit z",
+                constant: Cartesian::<f64>::UNIT_Z,
+                runtime: Cartesian::origin().with_z(1.),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            assert_eq!(
+                test.constant, test.runtime,
+                "{}: got constant = {:?}, want {:?}",
+                test.name, test.constant, test.runtime
+            );
+        });
+
+        const TABLE: [Cartesian<f32>; 2] = [Cartesian::<f32>::ORIGIN, Cartesian::new(1., 2., 3.)];
+        assert_eq!(TABLE[0], Cartesian::origin());
+        assert_eq!(
+            TABLE[1],
+            Cartesian::origin().with_x(1.).with_y(2.).with_z(3.)
+        );
+    }
+
+    #[test]
+    fn cartesian_component_wise_bounds() {
+        struct Test {
+            name: &'static str,
+            got: Cartesian<f64>,
+            want: Cartesian<f64>,
+        }
+
+        let lhs = Cartesian::new(1., 5., 3.);
+        let rhs = Cartesian::new(4., 2., 6.);
+
+        vec![
+            Test {
+                name: "min must pick the smallest of each component",
+                got: lhs.min(&rhs),
+                want: Cartesian::new(1., 2., 3.),
+            },
+            Test {
+                name: "max must pick the greatest of each component",
+                got: lhs.max(&rhs),
+                want: Cartesian::new(4., 5., 6.),
+            },
+            Test {
+                name: "clamp must pin out of range components to the bounds",
+                got: Cartesian::new(-1., 0.5, 7.)
+                    .clamp(&Cartesian::origin(), &Cartesian::new(1., 1., 1.)),
+                want: Cartesian::new(0., 0.5, 1.),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            assert_eq!(
+                test.got, test.want,
+                "{}: got {:?}, want {:?}",
+                test.name, test.got, test.want
+            );
+        });
+    }
+
+    #[test]
+    fn cartesian_spherical() {
+        struct Test {
+            name: &'static str,
+            spherical: (f64, f64, f64),
+            cartesian: Cartesian<f64>,
+        }
+
+        vec![
+            Test {
+                name: "null polar angle must be the z axis",
+                spherical: (2., 0., 0.),
+                cartesian: Cartesian::origin().with_z(2.),
+            },
+            Test {
+                name: "right polar angle with null azimuth must be the x axis",
+                spherical: (1., FRAC_PI_2, 0.),
+                cartesian: Cartesian::origin().with_x(1.),
+            },
+            Test {
+                name: "right polar angle and azimuth must be the y axis",
+                spherical: (3., FRAC_PI_2, FRAC_PI_2),
+                cartesian: Cartesian::origin().with_y(3.),
+            },
+            Test {
+                name: "arbitrary point",
+                spherical: (1.5, 0.7, 4.),
+                cartesian: Cartesian::origin()
+                    .with_x(1.5 * 0.7_f64.sin() * 4_f64.cos())
+                    .with_y(1.5 * 0.7_f64.sin() * 4_f64.sin())
+                    .with_z(1.5 * 0.7_f64.cos()),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let (radius, theta, phi) = test.spherical;
+            let point = Cartesian::from_spherical(radius, theta.into(), phi.into());
+            let tolerance = 1e-09;
+
+            point
+                .into_iter()
+                .zip(test.cartesian)
+                .for_each(|(got, want)| {
+                    assert!(
+                        (got - want).abs() < tolerance,
+                        "{}: got point = {:?}, want {:?}",
+                        test.name,
+                        point,
+                        test.cartesian
+                    );
+                });
+
+            let (got_radius, got_theta, got_phi) = point.to_spherical();
+            let want_phi = if theta == 0. { 0. } else { phi };
+            [
+                (got_radius, radius),
+                (got_theta.into_inner(), theta),
+                (got_phi.into_inner(), want_phi),
+            ]
+            .into_iter()
+            .for_each(|(got, want)| {
+                assert!(
+                    (got - want).abs() < tolerance,
+                    "{}: got spherical = {:?}, want {:?}",
+                    test.name,
+                    (got_radius, got_theta, got_phi),
+                    test.spherical
+                );
+            });
+        });
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn cartesian_serde_array() {
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Wrapper {
+            #[serde(with = "crate::cartesian::serde_array")]
+            point: Cartesian<f64>,
+        }
+
+        struct Test {
+            name: &'static str,
+            json: &'static str,
+            point: Option<Cartesian<f64>>,
+        }
+
+        vec![
+            Test {
+                name: "three elements array",
+                json: r#"{"point":[1.5,-2.0,0.25]}"#,
+                point: Some(Cartesian::new(1.5, -2., 0.25)),
+            },
+            Test {
+                name: "shorter array must be rejected",
+                json: r#"{"point":[1.5,-2.0]}"#,
+                point: None,
+            },
+            Test {
+                name: "longer array must be rejected",
+                json: r#"{"point":[1.5,-2.0,0.25,3.0]}"#,
+                point: None,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let decoded = serde_json::from_str::<Wrapper>(test.json).ok();
+            assert_eq!(
+                decoded.as_ref().map(|wrapper| wrapper.point),
+                test.point,
+                "{}: got point = {:?}, want {:?}",
+                test.name,
+                decoded,
+                test.point
+            );
+
+            if let Some(decoded) = decoded {
+                let encoded = serde_json::to_string(&decoded).unwrap();
+                assert_eq!(
+                    encoded, test.json,
+                    "{}: got json = {}, want {}",
+                    test.name, encoded, test.json
+                );
+            }
+        });
+    }
+
+    #[test]
+    fn cartesian_reflect() {
+        struct Test {
+            name: &'static str,
+            ray: Cartesian<f64>,
+            normal: Cartesian<f64>,
+            reflected: Cartesian<f64>,
+            incidence: f64,
+        }
+
+        vec![
+            Test {
+                name: "downward ray off an up-facing normal must go upward",
+                ray: Cartesian::origin().with_z(-2.),
+                normal: Cartesian::origin().with_z(1.),
+                reflected: Cartesian::origin().with_z(2.),
+                incidence: 0.,
+            },
+            Test {
+                name: "the magnitude of the normal must not matter",
+                ray: Cartesian::origin().with_z(-1.),
+                normal: Cartesian::origin().with_z(5.),
+                reflected: Cartesian::origin().with_z(1.),
+                incidence: 0.,
+            },
+            Test {
+                name: "oblique ray must keep its tangential component",
+                ray: Cartesian::origin().with_x(1.).with_z(-1.),
+                normal: Cartesian::origin().with_z(1.),
+                reflected: Cartesian::origin().with_x(1.).with_z(1.),
+                incidence: FRAC_PI_4,
+            },
+            Test {
+                name: "grazing ray must not change",
+                ray: Cartesian::origin().with_x(3.),
+                normal: Cartesian::origin().with_z(1.),
+                reflected: Cartesian::origin().with_x(3.),
+                incidence: FRAC_PI_2,
+            },
+            Test {
+                name: "ray leaving the surface must be beyond grazing",
+                ray: Cartesian::origin().with_z(1.),
+                normal: Cartesian::origin().with_z(1.),
+                reflected: Cartesian::origin().with_z(-1.),
+                incidence: PI,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let tolerance = 1e-09;
+
+            let reflected = test.ray.reflect(&test.normal);
+            assert!(
+                reflected.distance(&test.reflected) < tolerance,
+                "{}: got reflected = {:?}, want {:?}",
+                test.name,
+                reflected,
+                test.reflected
+            );
+
+            let incidence = test.ray.angle_of_incidence(&test.normal);
+            assert!(
+                (incidence - test.incidence).abs() < tolerance,
+                "{}: got angle of incidence = {}, want {}",
+                test.name,
+                incidence,
+                test.incidence
+            );
+        });
+    }
+
+    #[test]
+    fn cartesian_from_geographic_exact() {
+        struct Test {
+            name: &'static str,
+            geographic: Geographic<f64>,
+            lenient: Cartesian<f64>,
+            exact: Cartesian<f64>,
+        }
+
+        vec![
+            Test {
+                name: "zero altitude must be the unit sphere or the origin",
+                geographic: Geographic::origin(),
+                lenient: Cartesian::origin().with_x(1.),
+                exact: Cartesian::origin(),
+            },
+            Test {
+                name: "non zero altitude must be the same in both",
+                geographic: Geographic::origin()
+                    .with_latitude(Latitude::from(FRAC_PI_2))
+                    .with_altitude(Altitude::from(2.)),
+                lenient: Cartesian::origin().with_z(2.),
+                exact: Cartesian::origin().with_z(2.),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let lenient = Cartesian::from(test.geographic);
+            assert_eq!(
+                lenient, test.lenient,
+                "{}: got lenient cartesian = {:?}, want {:?}",
+                test.name, lenient, test.lenient
+            );
+
+            let exact = Cartesian::from_geographic_exact(test.geographic);
+            assert_eq!(
+                exact, test.exact,
+                "{}: got exact cartesian = {:?}, want {:?}",
+                test.name, exact, test.exact
+            );
+        });
+    }
 }
@@ -0,0 +1,180 @@
+//! Distance definitions and implementations.
+
+use num_traits::Float;
+
+use crate::{Cartesian, Geographic};
+
+/// A metric between values of the same type.
+pub trait Distance {
+    /// The type of the distance.
+    type Output;
+
+    /// Returns the distance between self and rhs.
+    fn distance(&self, rhs: &Self) -> Self::Output;
+}
+
+impl<T> Distance for Cartesian<T>
+where
+    T: Float,
+{
+    type Output = T;
+
+    /// Returns the euclidean distance between self and rhs.
+    fn distance(&self, rhs: &Self) -> Self::Output {
+        Cartesian::distance(self, rhs)
+    }
+}
+
+impl<T> Distance for Geographic<T>
+where
+    T: Float,
+{
+    type Output = T;
+
+    /// Returns the great-circle distance between self and rhs, same as [`Geographic::distance`].
+    fn distance(&self, rhs: &Self) -> Self::Output {
+        Geographic::distance(self, rhs)
+    }
+}
+
+/// Returns the candidate closest to the given query, or [`None`] if there are no candidates.
+///
+/// If several candidates are equally close, the first of them is returned. Candidates whose
+/// distance to the query is not comparable (e.g. NaN) are ignored.
+pub fn nearest<'a, D>(query: &D, candidates: &'a [D]) -> Option<&'a D>
+where
+    D: Distance,
+    D::Output: PartialOrd,
+{
+    candidates
+        .iter()
+        .map(|candidate| (candidate, query.distance(candidate)))
+        .fold(None, |nearest, (candidate, distance)| {
+            // a value not comparable with itself cannot be compared with any other.
+            let comparable = distance.partial_cmp(&distance).is_some();
+            let closer = !matches!(&nearest, Some((_, min)) if *min <= distance);
+
+            if comparable && closer {
+                Some((candidate, distance))
+            } else {
+                nearest
+            }
+        })
+        .map(|(candidate, _)| candidate)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        distance::{nearest, Distance},
+        Altitude, Cartesian, Geographic, Latitude, Longitude,
+    };
+
+    #[test]
+    fn nearest_geographic() {
+        struct Test {
+            name: &'static str,
+            query: Geographic<f64>,
+            candidates: Vec<Geographic<f64>>,
+            nearest: Option<usize>,
+        }
+
+        vec![
+            Test {
+                name: "no candidates must have no nearest",
+                query: Geographic::origin(),
+                candidates: vec![],
+                nearest: None,
+            },
+            Test {
+                name: "closest point along the great circle",
+                query: Geographic::origin().with_longitude(Longitude::from(3.)),
+                candidates: vec![
+                    Geographic::origin(),
+                    Geographic::origin().with_longitude(Longitude::from(-3.)),
+                    Geographic::origin().with_latitude(Latitude::from(1.)),
+                ],
+                nearest: Some(1),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let nearest = nearest(&test.query, &test.candidates);
+            let want = test.nearest.map(|index| &test.candidates[index]);
+
+            assert_eq!(
+                nearest, want,
+                "{}: got nearest = {:?}, want {:?}",
+                test.name, nearest, want
+            );
+        });
+    }
+
+    #[test]
+    fn nearest_cartesian() {
+        struct Test {
+            name: &'static str,
+            query: Cartesian<f64>,
+            candidates: Vec<Cartesian<f64>>,
+            nearest: Option<usize>,
+        }
+
+        vec![
+            Test {
+                name: "closest point in the space",
+                query: Cartesian::origin().with_x(1.),
+                candidates: vec![
+                    Cartesian::origin().with_y(1.),
+                    Cartesian::origin().with_x(1.5).with_z(0.1),
+                    Cartesian::origin().with_x(-1.),
+                ],
+                nearest: Some(1),
+            },
+            Test {
+                name: "incomparable candidates must be ignored and ties resolved to the first one",
+                query: Cartesian::origin(),
+                candidates: vec![
+                    Cartesian::origin().with_y(f64::NAN),
+                    Cartesian::origin().with_y(2.),
+                    Cartesian::origin().with_x(-2.),
+                ],
+                nearest: Some(1),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let nearest = nearest(&test.query, &test.candidates);
+            let want = test.nearest.map(|index| &test.candidates[index]);
+
+            assert_eq!(
+                nearest, want,
+                "{}: got nearest = {:?}, want {:?}",
+                test.name, nearest, want
+            );
+        });
+    }
+
+    #[test]
+    fn geographic_distance_must_match_inherent() {
+        let from = Geographic::origin()
+            .with_latitude(Latitude::from(0.4))
+            .with_altitude(Altitude::from(3.));
+
+        [
+            Geographic::origin(),
+            Geographic::origin()
+                .with_longitude(Longitude::from(-2.))
+                .with_altitude(Altitude::from(5.)),
+        ]
+        .into_iter()
+        .for_each(|to| {
+            let got = Distance::distance(&from, &to);
+            let want = from.distance(&to);
+
+            assert_eq!(
+                got, want,
+                "got trait distance to {to:?} = {got}, want {want}"
+            );
+        });
+    }
+}
@@ -0,0 +1,57 @@
+//! Reference ellipsoid definition.
+
+use num_traits::{Float, Signed};
+
+use crate::positive::Positive;
+
+/// An [ellipsoid of revolution](https://en.wikipedia.org/wiki/Earth_ellipsoid) approximating the
+/// figure of a globe.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(deserialize = "T: serde::Deserialize<'de> + Signed"))
+)]
+pub struct Ellipsoid<T> {
+    /// The equatorial radius of the ellipsoid.
+    pub semi_major_axis: Positive<T>,
+    /// The ratio between the difference of both semi-axes and the semi-major one.
+    pub flattening: T,
+}
+
+impl<T> Ellipsoid<T>
+where
+    T: Signed + Float,
+{
+    /// Returns the [WGS 84](https://en.wikipedia.org/wiki/World_Geodetic_System) ellipsoid, with
+    /// its semi-major axis in meters.
+    pub fn wgs84() -> Self {
+        Self {
+            semi_major_axis: T::from(6_378_137.).unwrap_or_else(T::nan).into(),
+            flattening: T::one() / T::from(298.257_223_563).unwrap_or_else(T::nan),
+        }
+    }
+}
+
+impl<T> Ellipsoid<T>
+where
+    T: Float,
+{
+    /// Returns the polar radius of the ellipsoid.
+    pub fn semi_minor_axis(&self) -> T {
+        self.semi_major_axis.into_inner() * (T::one() - self.flattening)
+    }
+}
+
+impl<T> Ellipsoid<T> {
+    pub fn with_semi_major_axis(self, semi_major_axis: Positive<T>) -> Self {
+        Self {
+            semi_major_axis,
+            ..self
+        }
+    }
+
+    pub fn with_flattening(self, flattening: T) -> Self {
+        Self { flattening, ..self }
+    }
+}
@@ -1,8 +1,27 @@
 //! Geographic system of coordinates.
 
+use alloc::{vec, vec::Vec};
+use core::{
+    cmp::Ordering,
+    num::NonZeroUsize,
+    ops::{Add, Sub},
+};
+
 use num_traits::{Euclid, Float, FloatConst, Signed};
+#[cfg(feature = "rand")]
+use rand::{
+    distr::{Distribution, StandardUniform},
+    Rng,
+};
 
-use crate::{cartesian::Cartesian, positive::Positive};
+use crate::{
+    cartesian::Cartesian,
+    distance,
+    positive::Positive,
+    radian::Radian,
+    shape::{Arc, Polygon},
+    tolerance::Tolerance,
+};
 
 /// The horizontal axis in a geographic system of coordinates.
 ///
@@ -26,8 +45,22 @@ use crate::{cartesian::Cartesian, positive::Positive};
 ///     Longitude::from(-PI + 1.)
 /// );
 /// ```
+///
+/// ### Antimeridian
+/// The antimeridian is always represented as -π, see [`Longitude::canonical`]. Since values
+/// computed independently may fall on either side of it, [`Longitude::approx_eq`] compares
+/// longitudes across the boundary.
 #[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(
+        from = "T",
+        bound(
+            deserialize = "T: serde::Deserialize<'de> + PartialOrd + Signed + FloatConst + Euclid"
+        )
+    )
+)]
 pub struct Longitude<T>(T);
 
 impl<T> From<T> for Longitude<T>
@@ -35,14 +68,25 @@ where
     T: PartialOrd + Signed + FloatConst + Euclid,
 {
     fn from(value: T) -> Self {
-        Self(if (-T::PI()..T::PI()).contains(&value) {
-            value
-        } else {
-            // Both boundaries of the range are consecutive, which means that
-            // overflowing one is the same as continuing from the other one
-            // in the same direction.
-            (value + T::PI()).rem_euclid(&T::TAU()) - T::PI()
-        })
+        if (-T::PI()..T::PI()).contains(&value) {
+            return Self(value);
+        }
+
+        // Both boundaries of the range are consecutive, which means that
+        // overflowing one is the same as continuing from the other one
+        // in the same direction.
+        let value = (value + T::PI()).rem_euclid(&T::TAU()) - T::PI();
+
+        // Rounding errors may land on the excluded boundary.
+        let longitude = Self(if value >= T::PI() { -T::PI() } else { value });
+
+        // Non-finite values have no equivalent inside the range, and go through as NaN.
+        debug_assert!(
+            longitude.is_valid() || longitude.0.partial_cmp(&longitude.0).is_none(),
+            "normalized longitude out of range"
+        );
+
+        longitude
     }
 }
 
@@ -74,6 +118,78 @@ impl<T> Longitude<T> {
     }
 }
 
+impl<T> Longitude<T>
+where
+    T: PartialOrd + Signed + FloatConst,
+{
+    /// Returns true if, and only if, the inner value lies in the range __[-π, +π)__.
+    ///
+    /// Values built through the normalizing constructors, deserialization included, always satisfy
+    /// this invariant.
+    pub fn is_valid(&self) -> bool {
+        (-T::PI()..T::PI()).contains(&self.0)
+    }
+}
+
+impl<T> Longitude<T>
+where
+    T: Copy + PartialOrd + Signed + FloatConst + Euclid,
+{
+    /// Returns the canonical representation of self.
+    ///
+    /// Both +π and -π stand for the antimeridian, being -π its canonical representation. The
+    /// normalizing constructors, deserialization included, already return it, but values computed
+    /// by other means may still hold +π, or any other value out of range, which is wrapped around
+    /// the antimeridian as in [`wrap_longitude`].
+    pub fn canonical(&self) -> Self {
+        if self.0 == T::PI() {
+            Self(-T::PI())
+        } else {
+            wrap_longitude(self.0)
+        }
+    }
+}
+
+impl<T> Longitude<T>
+where
+    T: Float + FloatConst,
+{
+    /// Returns true if, and only if, the angular distance between self and rhs is not greater
+    /// than the given tolerance (in radians), taking into account that both boundaries of the
+    /// range are consecutive.
+    pub fn approx_eq(&self, rhs: &Self, tolerance: Tolerance<T>) -> bool {
+        let diff = (self.0 - rhs.0).abs();
+        tolerance.approx_eq(diff.min(T::TAU() - diff), T::zero())
+    }
+
+    /// Returns self restricted to the range going eastwards from min to max.
+    ///
+    /// Since both boundaries of the longitude range are consecutive, the range may cross the
+    /// antimeridian (when min is greater than max). Longitudes out of the range are moved to the
+    /// closest of its boundaries.
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        let eastwards = |from: T, to: T| {
+            let diff = (to - from) % T::TAU();
+            if diff < T::zero() {
+                diff + T::TAU()
+            } else {
+                diff
+            }
+        };
+
+        let span = eastwards(min.0, max.0);
+        let offset = eastwards(min.0, self.0);
+
+        if offset <= span {
+            self
+        } else if offset - span <= T::TAU() - offset {
+            max
+        } else {
+            min
+        }
+    }
+}
+
 /// The vertical axis in a geographic system of coordinates.
 ///
 /// ## Definition
@@ -105,6 +221,13 @@ impl<T> Longitude<T> {
 /// ```
 #[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(
+        from = "T",
+        bound(deserialize = "T: serde::Deserialize<'de> + Float + FloatConst")
+    )
+)]
 pub struct Latitude<T>(T);
 
 impl<T> From<T> for Latitude<T>
@@ -112,11 +235,19 @@ where
     T: Float + FloatConst,
 {
     fn from(value: T) -> Self {
-        Self(if (-T::FRAC_PI_2()..=T::FRAC_PI_2()).contains(&value) {
+        let latitude = Self(if (-T::FRAC_PI_2()..=T::FRAC_PI_2()).contains(&value) {
             value
         } else {
             value.sin().asin()
-        })
+        });
+
+        // Non-finite values have no equivalent inside the range, and go through as NaN.
+        debug_assert!(
+            latitude.is_valid() || latitude.0.is_nan(),
+            "normalized latitude out of range"
+        );
+
+        latitude
     }
 }
 
@@ -148,6 +279,45 @@ impl<T> Latitude<T> {
     }
 }
 
+impl<T> Latitude<T>
+where
+    T: Signed,
+{
+    /// Returns the latitude of the equator, which needs no normalization.
+    pub(crate) fn equator() -> Self {
+        Self(T::zero())
+    }
+}
+
+impl<T> Latitude<T>
+where
+    T: Float + FloatConst,
+{
+    /// Returns true if, and only if, the inner value lies in the range __\[-π/2, +π/2\]__.
+    ///
+    /// Values built through the normalizing constructors, deserialization included, always satisfy
+    /// this invariant.
+    pub fn is_valid(&self) -> bool {
+        (-T::FRAC_PI_2()..=T::FRAC_PI_2()).contains(&self.0)
+    }
+}
+
+impl<T> Latitude<T>
+where
+    T: PartialOrd,
+{
+    /// Returns self restricted to the range from min to max.
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        if self < min {
+            min
+        } else if self > max {
+            max
+        } else {
+            self
+        }
+    }
+}
+
 /// The radius in a geographic system of coordinates.
 ///
 /// ## Definition
@@ -166,6 +336,10 @@ impl<T> Latitude<T> {
 /// ```
 #[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(deserialize = "T: serde::Deserialize<'de> + Signed"))
+)]
 pub struct Altitude<T>(Positive<T>);
 
 impl<T> From<T> for Altitude<T>
@@ -190,6 +364,33 @@ where
     }
 }
 
+impl<T> Add for Altitude<T>
+where
+    T: Add<Output = T>,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl<T> Sub for Altitude<T>
+where
+    T: Signed + PartialOrd,
+{
+    type Output = Self;
+
+    /// Subtracts rhs from self, saturating at zero if rhs is greater than self.
+    fn sub(self, rhs: Self) -> Self::Output {
+        if self <= rhs {
+            return T::zero().into();
+        }
+
+        (self.into_inner() - rhs.into_inner()).into()
+    }
+}
+
 impl<T> Altitude<T> {
     /// Returns the inner value.
     pub fn into_inner(self) -> T {
@@ -197,15 +398,54 @@ impl<T> Altitude<T> {
     }
 }
 
+impl<T> Altitude<T>
+where
+    T: PartialOrd,
+{
+    /// Returns self restricted to the range from min to max.
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        if self < min {
+            min
+        } else if self > max {
+            max
+        } else {
+            self
+        }
+    }
+}
+
 /// Coordinates according to the geographical system of coordinates.
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        deserialize = "T: serde::Deserialize<'de> + PartialOrd + Signed + Float + FloatConst + Euclid"
+    ))
+)]
 pub struct Geographic<T> {
     pub longitude: Longitude<T>,
     pub latitude: Latitude<T>,
     pub altitude: Altitude<T>,
 }
 
+impl<T> From<(Longitude<T>, Latitude<T>, Altitude<T>)> for Geographic<T> {
+    fn from((longitude, latitude, altitude): (Longitude<T>, Latitude<T>, Altitude<T>)) -> Self {
+        Self::new(longitude, latitude, altitude)
+    }
+}
+
+impl<T> From<(Longitude<T>, Latitude<T>)> for Geographic<T>
+where
+    T: Default,
+{
+    /// Builds the [`Geographic`] with the given longitude and latitude, and the default
+    /// altitude.
+    fn from((longitude, latitude): (Longitude<T>, Latitude<T>)) -> Self {
+        Self::new(longitude, latitude, Default::default())
+    }
+}
+
 impl<T> From<Cartesian<T>> for Geographic<T>
 where
     T: PartialOrd + Signed + Float + FloatConst + Euclid,
@@ -230,6 +470,195 @@ where
             altitude: T::zero().into(),
         }
     }
+
+    /// Returns the [`Geographic`] representation of the given [`Cartesian`] together with its
+    /// height above the surface of a sphere of the given radius, which is negative below it.
+    ///
+    /// As in any other conversion, the altitude of the returned point is its radial distance, so
+    /// the height is the one [`Geographic::height_above`] returns for the same radius. This is the
+    /// reverse of [`Geographic::at_height_above`].
+    pub fn from_cartesian_with_radius(point: Cartesian<T>, planet_radius: T) -> (Self, T) {
+        (Self::from(point), point.magnitude() - planet_radius)
+    }
+
+    /// Returns the [`Geographic`] representation of each of the given points, in the same order.
+    ///
+    /// This is the same as converting every point through [`Geographic::from`], but it is the
+    /// single place where the conversion of many points at once can be optimized.
+    pub fn from_cartesian_many(points: &[Cartesian<T>]) -> Vec<Self> {
+        let mut geographics = Vec::with_capacity(points.len());
+        Self::from_cartesian_many_into(points, &mut geographics);
+        geographics
+    }
+
+    /// Same as [`Geographic::from_cartesian_many`], but writing the converted points into the
+    /// given buffer, whose previous content is cleared, instead of allocating a new one.
+    pub fn from_cartesian_many_into(points: &[Cartesian<T>], buffer: &mut Vec<Self>) {
+        buffer.clear();
+        buffer.extend(points.iter().copied().map(Self::from));
+    }
+
+    /// Returns a copy of self with all of its coordinates normalized into their ranges.
+    ///
+    /// The constructors of each coordinate, deserialization included, already normalize their
+    /// values, but values computed by other means may be out of range.
+    pub fn normalized(&self) -> Self {
+        Self {
+            longitude: wrap_longitude(self.longitude.into_inner()),
+            latitude: Latitude::from(self.latitude.into_inner()),
+            altitude: Altitude::from(self.altitude.into_inner()),
+        }
+    }
+
+    /// Returns the reflection of this point across the equator, which has the opposite latitude.
+    pub fn mirror_equator(&self) -> Self {
+        Self {
+            latitude: Latitude::from(-self.latitude.into_inner()),
+            ..*self
+        }
+    }
+
+    /// Returns the reflection of this point across the plane of the prime meridian (and so of the
+    /// antimeridian), which has the opposite longitude.
+    pub fn mirror_meridian(&self) -> Self {
+        Self {
+            longitude: wrap_longitude(-self.longitude.into_inner()),
+            ..*self
+        }
+    }
+
+    /// Returns the reflection of this point across the plane of the 90°E and 90°W meridians, which
+    /// swaps the prime meridian and the antimeridian. That is, the longitude λ becomes π - λ.
+    ///
+    /// Reflecting a point across the antimeridian is the same as [`Geographic::mirror_meridian`],
+    /// since both meridians lie on the same great circle.
+    pub fn mirror_ninety_meridians(&self) -> Self {
+        Self {
+            longitude: wrap_longitude(T::PI() - self.longitude.into_inner()),
+            ..*self
+        }
+    }
+
+    /// Returns the initial bearing (clockwise from the north) of the great circle departing from
+    /// this point towards rhs.
+    ///
+    /// The bearing is undefined if both points are the same or antipodal, as well as at the
+    /// poles, where every direction is south (or north). In such cases the result is unspecified.
+    pub fn initial_bearing(&self, rhs: &Self) -> Radian<T> {
+        let (from, to) = (self.latitude.into_inner(), rhs.latitude.into_inner());
+        let longitude_delta = rhs.longitude.into_inner() - self.longitude.into_inner();
+
+        Float::atan2(
+            longitude_delta.sin() * to.cos(),
+            from.cos() * to.sin() - from.sin() * to.cos() * longitude_delta.cos(),
+        )
+        .into()
+    }
+
+    /// Returns the point reached departing from this one with the given initial bearing
+    /// (clockwise from the north) and travelling the given angular distance (in radians) along
+    /// the great circle.
+    pub fn destination(&self, bearing: Radian<T>, angular_distance: T) -> Self {
+        let latitude = self.latitude.into_inner();
+        let bearing = bearing.into_inner();
+
+        let destination_latitude = (latitude.sin() * angular_distance.cos()
+            + latitude.cos() * angular_distance.sin() * bearing.cos())
+        .asin();
+
+        let longitude_delta = Float::atan2(
+            bearing.sin() * angular_distance.sin() * latitude.cos(),
+            angular_distance.cos() - latitude.sin() * destination_latitude.sin(),
+        );
+
+        self.with_longitude((self.longitude.into_inner() + longitude_delta).into())
+            .with_latitude(destination_latitude.into())
+    }
+
+    /// Returns the arc departing from this point with the given initial bearing (clockwise from
+    /// the north) and travelling the given angular distance (in radians), ending at the
+    /// [`Geographic::destination`].
+    pub fn track(&self, bearing: Radian<T>, angular_distance: T, segments: NonZeroUsize) -> Arc<T> {
+        Arc::from_bearing_distance(*self, bearing, angular_distance, segments)
+    }
+
+    /// Returns the point halfway along the [rhumb line](https://en.wikipedia.org/wiki/Rhumb_line)
+    /// (the path of constant bearing) from this point to rhs.
+    ///
+    /// The latitude of the midpoint is the average of both latitudes, while its longitude is
+    /// interpolated along the stretched (Mercator) latitude, following the shortest direction
+    /// around the globe. The altitude is the average of both altitudes.
+    pub fn rhumb_midpoint(&self, rhs: &Self) -> Self {
+        let two = T::one() + T::one();
+        let stretched = |latitude: T| (T::FRAC_PI_4() + latitude / two).tan().ln();
+
+        let (from_lat, to_lat) = (self.latitude.into_inner(), rhs.latitude.into_inner());
+        let from_lon = self.longitude.into_inner();
+
+        // unwrapping the final longitude with respect to the initial one makes the path cross
+        // the antimeridian if that is the shortest direction.
+        let lon_delta = longitude_delta(self.longitude, rhs.longitude);
+        let to_lon = from_lon + lon_delta;
+
+        let latitude = (from_lat + to_lat) / two;
+        let (from_psi, to_psi) = (stretched(from_lat), stretched(to_lat));
+
+        let longitude = if (to_psi - from_psi).abs() > T::epsilon() {
+            (lon_delta * stretched(latitude) + from_lon * to_psi - to_lon * from_psi)
+                / (to_psi - from_psi)
+        } else {
+            // along a parallel the longitude is linear too.
+            (from_lon + to_lon) / two
+        };
+
+        let longitude = if longitude.is_finite() {
+            longitude
+        } else {
+            // at the poles the stretched latitude diverges.
+            (from_lon + to_lon) / two
+        };
+
+        Self {
+            longitude: longitude.into(),
+            latitude: latitude.into(),
+            altitude: ((self.altitude.into_inner() + rhs.altitude.into_inner()) / two).into(),
+        }
+    }
+
+    /// Returns the point reached departing from this one and travelling the given angular
+    /// distance (in radians) along the [rhumb line](https://en.wikipedia.org/wiki/Rhumb_line)
+    /// of the given constant bearing (clockwise from the north).
+    ///
+    /// The latitude advances by the distance times the cosine of the bearing, while the
+    /// longitude does so in proportion to the stretched (Mercator) latitude. Along a parallel,
+    /// where the stretched latitude does not change, the longitude advances by the distance over
+    /// the cosine of the latitude instead. The resulting longitude wraps around the antimeridian.
+    ///
+    /// A rhumb line not heading east or west spirals towards a pole, where it ends. Hence, any
+    /// distance beyond the pole yields the pole itself.
+    pub fn rhumb_destination(&self, bearing: Radian<T>, angular_distance: T) -> Self {
+        let two = T::one() + T::one();
+        let stretched = |latitude: T| (T::FRAC_PI_4() + latitude / two).tan().ln();
+
+        let from_lat = self.latitude.into_inner();
+        let (sin_bearing, cos_bearing) = bearing.into_inner().sin_cos();
+
+        let to_lat = (from_lat + angular_distance * cos_bearing)
+            .max(-T::FRAC_PI_2())
+            .min(T::FRAC_PI_2());
+
+        let psi_delta = stretched(to_lat) - stretched(from_lat);
+        let lon_delta = if psi_delta.abs() > T::epsilon() {
+            psi_delta * sin_bearing / cos_bearing
+        } else {
+            // along a parallel the longitude advances by the distance over the cosine of the
+            // latitude.
+            angular_distance * sin_bearing / from_lat.cos()
+        };
+
+        self.with_longitude((self.longitude.into_inner() + lon_delta).into())
+            .with_latitude(to_lat.into())
+    }
 }
 
 impl<T> Geographic<T>
@@ -237,9 +666,102 @@ where
     T: Signed + Float + FloatConst,
 {
     /// Returns the [`Cartesian`] representation of self.
+    ///
+    /// The altitude is taken as the absolute radial distance from the center of the sphere, and
+    /// so it becomes the magnitude of the cartesian vector. As an exception, a null altitude is
+    /// taken as the surface of the unit sphere (see [`Geographic::at_surface`]). Heights above
+    /// the surface of any other sphere must be turned into radial distances first, for instance
+    /// through [`Geographic::at_height_above`].
     pub fn into_cartesian(self) -> Cartesian<T> {
         self.into()
     }
+
+    /// Returns the distance from the center of the sphere to self, as taken by
+    /// [`Geographic::into_cartesian`].
+    pub(crate) fn radial_distance(&self) -> T {
+        match self.altitude.into_inner() {
+            altitude if altitude == T::zero() => T::one(),
+            altitude => altitude,
+        }
+    }
+
+    /// Returns the [`Cartesian`] representation of self without consuming it, same as
+    /// [`Geographic::into_cartesian`].
+    pub fn to_cartesian(&self) -> Cartesian<T> {
+        (*self).into()
+    }
+
+    /// Returns a copy of self on the surface of the reference sphere, which is the unit one.
+    ///
+    /// That is the same radial distance a null altitude is converted into, so both points have
+    /// the same [`Cartesian`] representation.
+    pub fn at_surface(&self) -> Self {
+        self.with_altitude(T::one().into())
+    }
+
+    /// Returns a copy of self whose altitude is the radial distance of a point at the given
+    /// height above the surface of a sphere of the given radius.
+    ///
+    /// Negative heights are depths below the surface. Since the altitude is always positive,
+    /// depths beyond the center of the sphere get the absolute of the resulting distance.
+    pub fn at_height_above(&self, surface_radius: T, height: T) -> Self {
+        self.with_altitude((surface_radius + height).into())
+    }
+
+    /// Returns the height of self above the surface of a sphere of the given radius, which is
+    /// negative below it.
+    ///
+    /// That is the radial distance [`Geographic::into_cartesian`] takes for self minus the radius.
+    pub fn height_above(&self, surface_radius: T) -> T {
+        self.radial_distance() - surface_radius
+    }
+
+    /// Returns the straight line distance between this point and rhs, taking their altitudes as
+    /// heights above the surface of a sphere of the given radius.
+    ///
+    /// Unlike in [`Geographic::into_cartesian`], the altitudes are not radial distances, so a null
+    /// altitude is a point on the surface of the given sphere.
+    pub fn distance_3d(&self, rhs: &Self, planet_radius: T) -> T {
+        let position = |point: &Self| {
+            point.at_surface().into_cartesian() * (planet_radius + point.altitude.into_inner())
+        };
+
+        position(self).distance(&position(rhs))
+    }
+
+    /// Returns the signed angular distance (in radians) from this point to the great circle that
+    /// goes through from and to.
+    ///
+    /// The distance is positive if the point lies on the side of the great circle towards which
+    /// the cross product of from and to points, and negative otherwise. If both endpoints are the
+    /// same or antipodal the great circle is undefined, and so is the distance (NaN).
+    pub fn cross_track_distance(&self, from: &Self, to: &Self) -> T {
+        let normal = from
+            .into_cartesian()
+            .normal()
+            .cross(&to.into_cartesian().normal())
+            .normal();
+
+        self.into_cartesian().normal().dot(&normal).asin()
+    }
+
+    /// Returns the side of the great circle, given by the normal of its plane, on which this point
+    /// is.
+    ///
+    /// That is [`Ordering::Greater`] if the point is on the same side the normal points towards,
+    /// [`Ordering::Less`] if it is on the opposite one, and [`Ordering::Equal`] if the point lies
+    /// on the great circle (within the machine epsilon).
+    pub fn side_of(&self, circle_normal: &Cartesian<T>) -> Ordering {
+        let dot = self.into_cartesian().normal().dot(&circle_normal.normal());
+
+        if dot.abs() <= T::epsilon() {
+            Ordering::Equal
+        } else if dot > T::zero() {
+            Ordering::Greater
+        } else {
+            Ordering::Less
+        }
+    }
 }
 
 impl<T> Geographic<T>
@@ -247,18 +769,110 @@ where
     T: Copy + Float,
 {
     /// Returns the [great-circle distance](https://en.wikipedia.org/wiki/Great-circle_distance)
-    /// from this point to rhs (in radiants).
+    /// from this point to rhs, along the sphere whose radius is the altitude of self.
+    ///
+    /// As in [`Geographic::into_cartesian`], a null altitude is taken as the unit sphere, so the
+    /// distance between points with no altitude is in radians.
+    ///
+    /// Since the distance is computed through the spherical law of cosines, it is not reliable for
+    /// nearby points when using `f32` (below about 1e-3 radians).
     pub fn distance(&self, rhs: &Self) -> T {
+        let radius = match self.altitude.into_inner() {
+            altitude if altitude == T::zero() => T::one(),
+            altitude => altitude,
+        };
+
+        self.central_angle(rhs) * radius
+    }
+
+    /// Returns the unit vector pointing towards the east at this point, tangent to the sphere.
+    ///
+    /// At the poles, where the east is undefined, it is taken along the meridian zero.
+    pub fn tangent_east(&self) -> Cartesian<T> {
+        let (lon_sin, lon_cos) = self.longitude.into_inner().sin_cos();
+        Cartesian::new(-lon_sin, lon_cos, T::zero())
+    }
+
+    /// Returns the unit vector pointing towards the north at this point, tangent to the sphere.
+    pub fn tangent_north(&self) -> Cartesian<T> {
+        let (lon_sin, lon_cos) = self.longitude.into_inner().sin_cos();
+        let (lat_sin, lat_cos) = self.latitude.into_inner().sin_cos();
+        Cartesian::new(-lat_sin * lon_cos, -lat_sin * lon_sin, lat_cos)
+    }
+
+    /// Returns the unit vector normal to the sphere at this point, pointing outwards.
+    pub fn normal_up(&self) -> Cartesian<T> {
+        let (lon_sin, lon_cos) = self.longitude.into_inner().sin_cos();
+        let (lat_sin, lat_cos) = self.latitude.into_inner().sin_cos();
+        Cartesian::new(lat_cos * lon_cos, lat_cos * lon_sin, lat_sin)
+    }
+
+    /// Returns the great-circle distance from this point to rhs (in meters) over the surface of a
+    /// sphere of the given radius (in meters), regardless of the altitude of both points.
+    pub fn distance_meters(&self, rhs: &Self, planet_radius: T) -> T {
+        self.central_angle(rhs) * planet_radius
+    }
+
+    /// Returns the angle (in radiants) between this point and rhs as seen from the center of the
+    /// sphere.
+    pub(crate) fn central_angle(&self, rhs: &Self) -> T {
         let prod_latitude_sin = self.latitude.into_inner().sin() * rhs.latitude.into_inner().sin();
         let prod_latitude_cos = self.latitude.into_inner().cos() * rhs.latitude.into_inner().cos();
         let longitude_diff = (self.longitude.into_inner() - rhs.longitude.into_inner()).abs();
 
         (prod_latitude_sin + prod_latitude_cos * longitude_diff.cos()).acos()
-            * self.altitude.into_inner()
     }
 }
 
+impl<T> Geographic<T>
+where
+    T: Copy + Float + FloatConst,
+{
+    /// Returns true if, and only if, the sun is above the horizon at this point, which happens
+    /// when it is less than a quarter of a great circle away from the given [subsolar
+    /// point](subsolar_point).
+    ///
+    /// Atmospheric refraction and the apparent size of the sun are not taken into account.
+    pub fn is_illuminated(&self, subsolar: &Geographic<T>) -> bool {
+        self.central_angle(subsolar) < T::FRAC_PI_2()
+    }
+
+    /// Returns true if, and only if, each coordinate of self is not further than the given
+    /// tolerance from the one of rhs, taking into account that both boundaries of the longitude
+    /// range are consecutive (see [`Longitude::approx_eq`]).
+    ///
+    /// Since any longitude describes the poles, points at them may be considered different even
+    /// if they are the same.
+    pub fn approx_eq(&self, rhs: &Self, tolerance: Tolerance<T>) -> bool {
+        self.longitude.approx_eq(&rhs.longitude, tolerance)
+            && tolerance.approx_eq(self.latitude.into_inner(), rhs.latitude.into_inner())
+            && tolerance.approx_eq(self.altitude.into_inner(), rhs.altitude.into_inner())
+    }
+}
+
+macro_rules! impl_geographic_consts {
+    ($($float:ty),+) => {
+        $(
+            impl Geographic<$float> {
+                /// The mean radius of the Earth (in meters), as defined by the IUGG.
+                pub const EARTH_MEAN_RADIUS_M: $float = 6_371_008.8;
+            }
+        )+
+    };
+}
+
+impl_geographic_consts!(f32, f64);
+
 impl<T> Geographic<T> {
+    /// Returns the geographic point with the given coordinates.
+    pub fn new(longitude: Longitude<T>, latitude: Latitude<T>, altitude: Altitude<T>) -> Self {
+        Self {
+            longitude,
+            latitude,
+            altitude,
+        }
+    }
+
     pub fn with_longitude(self, longitude: Longitude<T>) -> Self {
         Self { longitude, ..self }
     }
@@ -272,58 +886,722 @@ impl<T> Geographic<T> {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use std::f64::consts::{FRAC_PI_2, PI};
-
-    use crate::{
-        cartesian::Cartesian,
-        geographic::{Altitude, Geographic, Latitude, Longitude},
-    };
+#[cfg(feature = "rand")]
+impl<T> Geographic<T>
+where
+    T: PartialOrd + Signed + Float + FloatConst + Euclid,
+    StandardUniform: Distribution<T>,
+{
+    /// Returns a point uniformly distributed over the surface of the sphere, with no altitude.
+    ///
+    /// Picking the latitude uniformly would cluster the points at the poles. Instead, the height
+    /// of the point along the axis of the globe (the sine of its latitude) is the one picked
+    /// uniformly in the range [-1, 1], as the area of a spherical zone only depends on its height.
+    pub fn random(rng: &mut impl Rng) -> Self {
+        let two = T::one() + T::one();
+        let height = two * rng.random::<T>() - T::one();
+        let longitude = T::TAU() * rng.random::<T>() - T::PI();
 
-    #[test]
-    fn longitude_must_not_exceed_boundaries() {
-        struct Test {
-            name: &'static str,
-            input: f64,
-            output: f64,
-        }
+        Self::origin()
+            .with_longitude(Longitude::from(longitude))
+            .with_latitude(Latitude::from(height.asin()))
+    }
+}
 
-        vec![
-            Test {
-                name: "positive longitude value must not change",
-                input: 1.,
-                output: 1.,
-            },
-            Test {
-                name: "negative longitude value must not change",
-                input: -3.,
-                output: -3.,
-            },
-            Test {
-                name: "positive overflowing longitude must change",
-                input: PI + 1.,
-                output: -PI + 1.,
-            },
-            Test {
-                name: "negative overflowing longitude must change",
-                input: -PI - 1.,
-                output: PI - 1.,
-            },
-        ]
-        .into_iter()
-        .for_each(|test| {
-            let longitude = Longitude::from(test.input).into_inner();
+/// Returns the [`Longitude`] equivalent to the given value (in radians), wrapping it around the
+/// antimeridian if out of range.
+///
+/// This is the same normalization performed when constructing a [`Longitude`].
+pub fn wrap_longitude<T>(value: T) -> Longitude<T>
+where
+    T: PartialOrd + Signed + FloatConst + Euclid,
+{
+    Longitude::from(value)
+}
 
-            assert_eq!(
-                longitude, test.output,
-                "{}: got longitude = {}, want {}",
-                test.name, longitude, test.output
-            );
-        });
+/// Returns the signed angle (in radians) from one longitude to the other taking the shortest
+/// direction around the globe, which is positive eastward and lies in the range __(-π, +π]__.
+///
+/// Unlike the plain difference between both values, this one accounts for the antimeridian: going
+/// from 170° to -170° is 20° eastward, instead of 340° westward. A half turn is always eastward.
+pub fn longitude_delta<T>(from: Longitude<T>, to: Longitude<T>) -> T
+where
+    T: PartialOrd + Signed + FloatConst + Euclid,
+{
+    let delta = Longitude::from(to.into_inner() - from.into_inner()).into_inner();
+    if delta <= -T::PI() {
+        return T::PI();
     }
 
-    #[test]
+    delta
+}
+
+/// Returns the [`Latitude`] closest to the given value (in radians), saturating it at the poles if
+/// out of range.
+///
+/// Unlike constructing a [`Latitude`], which reflects values beyond a pole as if travelling over
+/// it, this function keeps any value overflowing the north (or south) pole at that same pole.
+pub fn clamp_latitude<T>(value: T) -> Latitude<T>
+where
+    T: Float + FloatConst,
+{
+    Latitude::from(value.max(-T::FRAC_PI_2()).min(T::FRAC_PI_2()))
+}
+
+/// Returns the area of the spherical triangle with the given vertices on a sphere of the given
+/// radius.
+///
+/// The area is computed from the [spherical excess](https://en.wikipedia.org/wiki/Spherical_trigonometry#Area_and_spherical_excess)
+/// E = A + B + C − π, where each interior angle is derived from the sides of the triangle through
+/// the spherical law of cosines. Degenerate triangles (with coincident or colinear vertices) have
+/// no area.
+pub fn triangle_area<T>(a: &Geographic<T>, b: &Geographic<T>, c: &Geographic<T>, radius: T) -> T
+where
+    T: Float + FloatConst,
+{
+    // each side is named after the vertex it is opposite to.
+    let side_a = b.central_angle(c);
+    let side_b = a.central_angle(c);
+    let side_c = a.central_angle(b);
+
+    let angle = |opposite: T, adjacent: T, other_adjacent: T| -> Option<T> {
+        let denominator = adjacent.sin() * other_adjacent.sin();
+        if denominator.abs() <= T::epsilon() {
+            return None;
+        }
+
+        let cos = (opposite.cos() - adjacent.cos() * other_adjacent.cos()) / denominator;
+        Some(cos.max(-T::one()).min(T::one()).acos())
+    };
+
+    let (Some(angle_a), Some(angle_b), Some(angle_c)) = (
+        angle(side_a, side_b, side_c),
+        angle(side_b, side_a, side_c),
+        angle(side_c, side_a, side_b),
+    ) else {
+        return T::zero();
+    };
+
+    let excess = angle_a + angle_b + angle_c - T::PI();
+    excess.max(T::zero()) * radius * radius
+}
+
+/// Returns the given sequence of points simplified through the [Ramer–Douglas–Peucker
+/// algorithm](https://en.wikipedia.org/wiki/Ramer%E2%80%93Douglas%E2%80%93Peucker_algorithm),
+/// where the distance of each point is its [cross-track
+/// distance](Geographic::cross_track_distance) (in radians) to the great circle through the
+/// endpoints of the run.
+///
+/// Both the first and last points are always preserved. If the endpoints of a run are the same
+/// or antipodal (as in closed rings) the great circle through them is undefined, so the distance
+/// of each point is its angular distance to the initial endpoint instead.
+pub fn simplify<T>(points: &[Geographic<T>], tolerance: T) -> Vec<Geographic<T>>
+where
+    T: Signed + Float + FloatConst,
+{
+    if points.len() < 3 {
+        return points.to_vec();
+    }
+
+    let mut retained = vec![false; points.len()];
+    retained[0] = true;
+    retained[points.len() - 1] = true;
+
+    // the runs are processed through an explicit stack, so that no input can exhaust the call
+    // stack.
+    let mut runs = vec![(0, points.len() - 1)];
+    while let Some((start, end)) = runs.pop() {
+        if end - start < 2 {
+            continue;
+        }
+
+        let (first, last) = (&points[start], &points[end]);
+        let undefined = first
+            .into_cartesian()
+            .normal()
+            .cross(&last.into_cartesian().normal())
+            .magnitude()
+            <= T::epsilon();
+
+        let (farthest, distance) = points[start + 1..end]
+            .iter()
+            .enumerate()
+            .map(|(index, point)| {
+                let distance = if undefined {
+                    point.central_angle(first)
+                } else {
+                    point.cross_track_distance(first, last).abs()
+                };
+
+                (start + 1 + index, distance)
+            })
+            .fold((start, T::zero()), |farthest, candidate| {
+                if candidate.1 > farthest.1 {
+                    candidate
+                } else {
+                    farthest
+                }
+            });
+
+        if distance > tolerance {
+            retained[farthest] = true;
+            runs.push((start, farthest));
+            runs.push((farthest, end));
+        }
+    }
+
+    points
+        .iter()
+        .zip(retained)
+        .filter_map(|(point, retained)| retained.then_some(*point))
+        .collect()
+}
+
+/// Returns the longitudes of the given sequence of points unwrapped, so that no pair of
+/// consecutive values differs in more than π radians.
+///
+/// This avoids the jump from +π to -π when a path crosses the antimeridian, which is useful for
+/// rendering purposes. Since the resulting values may exceed the range __[-π, +π)__, they are not
+/// [`Longitude`]s; use [`wrap_longitudes`] to normalize them back.
+pub fn unwrap_longitudes<T>(points: &[Geographic<T>]) -> Vec<T>
+where
+    T: Signed + Float + FloatConst + Euclid,
+{
+    points
+        .iter()
+        .scan(None, |prev: &mut Option<(Longitude<T>, T)>, point| {
+            let unwrapped = match *prev {
+                Some((wrapped, unwrapped)) => unwrapped + longitude_delta(wrapped, point.longitude),
+                None => point.longitude.into_inner(),
+            };
+
+            *prev = Some((point.longitude, unwrapped));
+            Some(unwrapped)
+        })
+        .collect()
+}
+
+/// Returns the given sequence of longitudes normalized into the range __[-π, +π)__. This is the
+/// inverse of [`unwrap_longitudes`].
+pub fn wrap_longitudes<T>(longitudes: &[T]) -> Vec<Longitude<T>>
+where
+    T: Signed + Float + FloatConst + Euclid,
+{
+    longitudes.iter().copied().map(Longitude::from).collect()
+}
+
+/// Returns the point at the given global parameter t (in the range \[0, 1\]) of the spherical
+/// [Catmull–Rom spline](https://en.wikipedia.org/wiki/Cubic_Hermite_spline#Catmull%E2%80%93Rom_spline)
+/// going through all the given control points, or [`None`] if there are no points at all.
+///
+/// The spline is uniform: each pair of consecutive control points takes the same share of the
+/// parameter, so the i-th control point is reached at t = i / (n - 1). The missing neighbors at
+/// both ends of the sequence are the reflections of the second and second to last points through
+/// the first and last ones, respectively.
+pub fn catmull_rom<T>(points: &[Geographic<T>], t: T) -> Option<Geographic<T>>
+where
+    T: PartialOrd + Signed + Float + FloatConst + Euclid,
+{
+    let unit = |point: &Geographic<T>| point.into_cartesian().normal();
+    let two = T::one() + T::one();
+
+    let (first, last) = match points {
+        [] => return None,
+        [point] => return Some(*point),
+        [first, .., last] => (unit(first), unit(last)),
+    };
+
+    let segments = T::from(points.len() - 1)?;
+    let position = t.max(T::zero()).min(T::one()) * segments;
+    let index = position
+        .floor()
+        .to_usize()
+        .map(|index| index.min(points.len() - 2))?;
+    let u = position - T::from(index)?;
+
+    let p1 = unit(&points[index]);
+    let p2 = unit(&points[index + 1]);
+    let p0 = match index.checked_sub(1) {
+        Some(prev) => unit(&points[prev]),
+        None => slerp(&p2, &first, two),
+    };
+    let p3 = match points.get(index + 2) {
+        Some(next) => unit(next),
+        None => slerp(&unit(&points[points.len() - 2]), &last, two),
+    };
+
+    // Barry and Goldman's pyramidal formulation, with uniform knots t0=0, t1=1, t2=2 and t3=3.
+    let t = T::one() + u;
+    let a1 = slerp(&p0, &p1, t);
+    let a2 = slerp(&p1, &p2, t - T::one());
+    let a3 = slerp(&p2, &p3, t - two);
+    let b1 = slerp(&a1, &a2, t / two);
+    let b2 = slerp(&a2, &a3, (t - T::one()) / two);
+
+    Some(slerp(&b1, &b2, t - T::one()).into())
+}
+
+/// Returns the [spherical linear interpolation](https://en.wikipedia.org/wiki/Slerp) between the
+/// given unit vectors, which extrapolates along the same great circle for t out of \[0, 1\].
+///
+/// If both vectors are the same or antipodal the great circle is undefined, and so the initial
+/// vector is returned.
+fn slerp<T: Float>(from: &Cartesian<T>, to: &Cartesian<T>, t: T) -> Cartesian<T> {
+    let omega = from.dot(to).max(-T::one()).min(T::one()).acos();
+    let sin_omega = omega.sin();
+
+    if sin_omega.abs() <= T::epsilon() {
+        return *from;
+    }
+
+    *from * (((T::one() - t) * omega).sin() / sin_omega) + *to * ((t * omega).sin() / sin_omega)
+}
+
+/// Returns the [subsolar point](https://en.wikipedia.org/wiki/Subsolar_point), where the sun is
+/// directly overhead, given its declination and its Greenwich hour angle.
+///
+/// The hour angle grows westward from the prime meridian, so the longitude of the subsolar point
+/// is its opposite. No ephemerides are computed: both inputs must be provided by the caller.
+pub fn subsolar_point<T>(
+    declination: Latitude<T>,
+    greenwich_hour_angle: Longitude<T>,
+) -> Geographic<T>
+where
+    T: PartialOrd + Signed + Float + FloatConst + Euclid,
+{
+    Geographic::origin()
+        .with_longitude(Longitude::from(-greenwich_hour_angle.into_inner()))
+        .with_latitude(declination)
+}
+
+/// Returns the [convex hull](https://en.wikipedia.org/wiki/Convex_hull) of the given points on
+/// the unit sphere, computed through the [gift wrapping
+/// algorithm](https://en.wikipedia.org/wiki/Gift_wrapping_algorithm).
+///
+/// The hull is only defined if all the points lie within the same hemisphere, otherwise the output
+/// is unspecified. The vertices of the hull are a subset of the given points, sorted
+/// counter-clockwise as seen from outside the globe. Points on an edge of the hull are not
+/// vertices of it.
+///
+/// ## Degenerate cases
+/// An empty slice yields an empty polygon, while a single distinct point yields a polygon with
+/// just that vertex. If all the points lie on the same great circle, the hull is the arc between
+/// the farthest two of them, and so the polygon has only these two vertices.
+pub fn convex_hull<T>(points: &[Geographic<T>]) -> Polygon<T>
+where
+    T: Signed + Float + FloatConst,
+{
+    let normals: Vec<_> = points
+        .iter()
+        .map(|point| point.into_cartesian().normal())
+        .collect();
+
+    // the point farthest from the centroid is always a vertex of the hull.
+    let centroid = normals
+        .iter()
+        .fold(Cartesian::origin(), |centroid, &normal| centroid + normal);
+
+    let Some(start) = (0..normals.len()).min_by(|&a, &b| {
+        centroid
+            .dot(&normals[a])
+            .partial_cmp(&centroid.dot(&normals[b]))
+            .unwrap_or(Ordering::Equal)
+    }) else {
+        return Polygon::new(Vec::new());
+    };
+
+    let coincident = |a: usize, b: usize| normals[a].distance(&normals[b]) <= T::epsilon();
+
+    let mut vertices = vec![points[start]];
+    let mut current = start;
+
+    // the hull cannot have more vertices than points, which bounds the wrapping.
+    for _ in 0..normals.len() {
+        let Some(mut next) = (0..normals.len()).find(|&index| !coincident(index, current)) else {
+            break;
+        };
+
+        (0..normals.len())
+            .filter(|&index| !coincident(index, current))
+            .for_each(|candidate| {
+                let edge = normals[current].cross(&normals[next]);
+
+                // the interior of the hull must be on the left of every edge, so any point on its
+                // right proves the edge wrong. Points on the same great circle extend the edge.
+                let replace = match points[candidate].side_of(&edge) {
+                    Ordering::Less => true,
+                    Ordering::Equal => {
+                        normals[current].dot(&normals[candidate])
+                            < normals[current].dot(&normals[next])
+                    }
+                    Ordering::Greater => false,
+                };
+
+                if replace {
+                    next = candidate;
+                }
+            });
+
+        if coincident(next, start) {
+            break;
+        }
+
+        vertices.push(points[next]);
+        current = next;
+    }
+
+    Polygon::new(vertices)
+}
+
+/// Returns true if, and only if, the three given points lie on a common great circle, within the
+/// given tolerance.
+///
+/// The points are cocircular if their unit vectors are coplanar with the center of the globe,
+/// which is whenever their [scalar triple product](Cartesian::scalar_triple) is zero. Hence the
+/// tolerance bounds the volume of the parallelepiped they define, which is the sine of the
+/// angular distance from any of them to the great circle through the other two, scaled by the
+/// sine of the angle between the latter. Coincident or antipodal points are cocircular with any
+/// other.
+pub fn are_cocircular<T>(a: &Geographic<T>, b: &Geographic<T>, c: &Geographic<T>, tol: T) -> bool
+where
+    T: Signed + Float + FloatConst,
+{
+    let [a, b, c] = [a, b, c].map(|point| point.into_cartesian().normal());
+    a.scalar_triple(&b, &c).abs() <= tol
+}
+
+/// Returns the centroid of the given points on the unit sphere, which is the direction of the sum
+/// of their unit vectors, or [`None`] if it is undefined.
+///
+/// The centroid is undefined if there are no points or if they cancel each other out, like two
+/// antipodal points do.
+pub fn centroid<T>(points: &[Geographic<T>]) -> Option<Geographic<T>>
+where
+    T: PartialOrd + Signed + Float + FloatConst + Euclid,
+{
+    sphere_centroid(points.iter().map(|point| (point, T::one())))
+}
+
+/// Returns the centroid of the given points scaled by their weights, as the [`centroid`] does, or
+/// [`None`] if it is undefined.
+///
+/// Weights must be positive: a negative (or not even finite) one makes the centroid undefined,
+/// while a null one makes the point not count at all.
+pub fn weighted_centroid<T>(points: &[(Geographic<T>, T)]) -> Option<Geographic<T>>
+where
+    T: PartialOrd + Signed + Float + FloatConst + Euclid,
+{
+    if points
+        .iter()
+        .any(|(_, weight)| !weight.is_finite() || weight.is_sign_negative())
+    {
+        return None;
+    }
+
+    sphere_centroid(points.iter().map(|(point, weight)| (point, *weight)))
+}
+
+/// Returns the direction of the sum of the unit vectors of the given points scaled by their
+/// weights, if any.
+fn sphere_centroid<'a, T>(
+    points: impl Iterator<Item = (&'a Geographic<T>, T)>,
+) -> Option<Geographic<T>>
+where
+    T: 'a + PartialOrd + Signed + Float + FloatConst + Euclid,
+{
+    let sum = points.fold(Cartesian::origin(), |sum, (point, weight)| {
+        sum + point.into_cartesian().normal() * weight
+    });
+
+    let magnitude = sum.magnitude();
+    (magnitude > T::epsilon()).then(|| (sum / magnitude).into())
+}
+
+/// Returns the site nearest to the given point along the great circle (regardless of their
+/// altitudes), which is the one whose [Voronoi cell](https://en.wikipedia.org/wiki/Voronoi_diagram)
+/// the point belongs to, or [`None`] if there are no sites.
+///
+/// Points on the boundary between cells, being equidistant to several sites, belong to the first
+/// of them.
+pub fn nearest_site<'a, T>(
+    point: &Geographic<T>,
+    sites: &'a [Geographic<T>],
+) -> Option<&'a Geographic<T>>
+where
+    T: Float,
+{
+    distance::nearest(point, sites)
+}
+
+/// Returns the center and angular radius of the smallest spherical cap containing all the given
+/// points, or [`None`] if there are no points.
+///
+/// The cap is computed through a spherical variant of [Welzl's
+/// algorithm](https://en.wikipedia.org/wiki/Smallest-circle_problem#Welzl's_algorithm), where the
+/// cap through two points is centered at their midpoint and the one through three points is their
+/// circumcircle. The points must lie within the same hemisphere, otherwise the output is
+/// unspecified. A single point yields a null radius.
+pub fn minimum_enclosing_cap<T>(points: &[Geographic<T>]) -> Option<(Geographic<T>, Radian<T>)>
+where
+    T: PartialOrd + Signed + Float + FloatConst + Euclid,
+{
+    let normals: Vec<_> = points
+        .iter()
+        .map(|point| point.into_cartesian().normal())
+        .collect();
+
+    let angle = |a: &Cartesian<T>, b: &Cartesian<T>| Float::atan2(a.cross(b).magnitude(), a.dot(b));
+
+    // points on the boundary of the cap must be contained despite rounding errors.
+    let tolerance = T::epsilon().sqrt();
+    let contains = |(center, radius): &(Cartesian<T>, T), point: &Cartesian<T>| {
+        angle(center, point) <= *radius + tolerance
+    };
+
+    let through_two = |a: &Cartesian<T>, b: &Cartesian<T>| {
+        let two = T::one() + T::one();
+        ((*a + *b).normal(), angle(a, b) / two)
+    };
+
+    let through_three = |a: &Cartesian<T>, b: &Cartesian<T>, c: &Cartesian<T>| {
+        // the circumcenter is orthogonal to the plane through the three points, on their side.
+        let center = (*b - *a).cross(&(*c - *a));
+        let magnitude = center.magnitude();
+        if magnitude <= T::epsilon() {
+            // colinear points are enclosed by the cap through the farthest two of them.
+            return [through_two(a, c), through_two(b, c)].into_iter().fold(
+                through_two(a, b),
+                |widest, cap| {
+                    if cap.1 > widest.1 {
+                        cap
+                    } else {
+                        widest
+                    }
+                },
+            );
+        }
+
+        let mut center = center / magnitude;
+        if center.dot(a) < T::zero() {
+            center = center * -T::one();
+        }
+
+        (center, angle(&center, a))
+    };
+
+    let first = *normals.first()?;
+    let mut cap = (first, T::zero());
+
+    for i in 1..normals.len() {
+        if contains(&cap, &normals[i]) {
+            continue;
+        }
+
+        cap = (normals[i], T::zero());
+        for j in 0..i {
+            if contains(&cap, &normals[j]) {
+                continue;
+            }
+
+            cap = through_two(&normals[i], &normals[j]);
+            for k in 0..j {
+                if !contains(&cap, &normals[k]) {
+                    cap = through_three(&normals[i], &normals[j], &normals[k]);
+                }
+            }
+        }
+    }
+
+    let (center, radius) = cap;
+    Some((center.into(), radius.into()))
+}
+
+/// Returns the [initial bearing](Geographic::initial_bearing) from each point of the grid made of
+/// the given longitudes and latitudes towards the target.
+///
+/// The grid is traversed row by row, one per latitude in the given order, each of them going
+/// through all the longitudes. Points with no defined bearing towards the target (see
+/// [`Geographic::initial_bearing`]) are included with an unspecified one.
+pub fn bearing_field<T>(
+    target: Geographic<T>,
+    lons: &[Longitude<T>],
+    lats: &[Latitude<T>],
+) -> Vec<(Geographic<T>, Radian<T>)>
+where
+    T: Signed + Float + FloatConst + Euclid,
+{
+    lats.iter()
+        .flat_map(|&latitude| {
+            lons.iter().map(move |&longitude| {
+                let point = Geographic::origin()
+                    .with_longitude(longitude)
+                    .with_latitude(latitude);
+
+                (point, point.initial_bearing(&target))
+            })
+        })
+        .collect()
+}
+
+/// Returns the gradient of the altitude at the center point towards the east and the north, as
+/// the rise over the run to the given neighbors on each direction, as required by hillshading.
+///
+/// The run is the great-circle distance between the center and each neighbor over the surface of
+/// a sphere of the given radius, so altitudes must be given in the same unit as the radius. A
+/// neighbor at the same place as the center yields a null gradient in its direction.
+pub fn slope<T>(
+    center: &Geographic<T>,
+    east: &Geographic<T>,
+    north: &Geographic<T>,
+    planet_radius: T,
+) -> (T, T)
+where
+    T: Float,
+{
+    let gradient = |neighbor: &Geographic<T>| {
+        let run = center.distance_meters(neighbor, planet_radius);
+        if run <= T::zero() {
+            return T::zero();
+        }
+
+        (neighbor.altitude.into_inner() - center.altitude.into_inner()) / run
+    };
+
+    (gradient(east), gradient(north))
+}
+
+/// Serialization of [`Geographic`] in decimal degrees, as expected by most GIS tools.
+///
+/// Meant to be used through the `#[serde(with = "geocart::geographic::serde_degrees")]`
+/// attribute, it represents a point as `{"lon": deg, "lat": deg, "alt": m}`. Deserialized values
+/// are normalized the same way as when constructing the coordinates from radians.
+///
+/// ## Example
+/// ```
+/// use geocart::{Geographic, Latitude, Longitude};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Place {
+///     #[serde(with = "geocart::geographic::serde_degrees")]
+///     position: Geographic<f64>,
+/// }
+///
+/// let place = Place {
+///     position: Geographic::origin().with_latitude(Latitude::from(std::f64::consts::FRAC_PI_2)),
+/// };
+///
+/// assert_eq!(
+///     serde_json::to_string(&place).unwrap(),
+///     r#"{"position":{"lon":0.0,"lat":90.0,"alt":0.0}}"#
+/// );
+/// ```
+#[cfg(feature = "serde")]
+pub mod serde_degrees {
+    use num_traits::{Euclid, Float, FloatConst, Signed};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::{Altitude, Geographic, Latitude, Longitude};
+
+    #[derive(Serialize, Deserialize)]
+    struct Degrees<T> {
+        lon: T,
+        lat: T,
+        alt: T,
+    }
+
+    /// Serializes the given point in decimal degrees.
+    pub fn serialize<T, S>(point: &Geographic<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Float + Serialize,
+        S: Serializer,
+    {
+        Degrees {
+            lon: point.longitude.into_inner().to_degrees(),
+            lat: point.latitude.into_inner().to_degrees(),
+            alt: point.altitude.into_inner(),
+        }
+        .serialize(serializer)
+    }
+
+    /// Deserializes a point from decimal degrees.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Geographic<T>, D::Error>
+    where
+        T: PartialOrd + Signed + Float + FloatConst + Euclid + Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        let degrees = Degrees::<T>::deserialize(deserializer)?;
+        Ok(Geographic::new(
+            Longitude::from(degrees.lon.to_radians()),
+            Latitude::from(degrees.lat.to_radians()),
+            Altitude::from(degrees.alt),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        cmp::Ordering,
+        f64::consts::{FRAC_PI_2, FRAC_PI_4, PI, TAU},
+        num::NonZeroUsize,
+    };
+
+    use crate::{
+        cartesian::Cartesian,
+        geographic::{
+            are_cocircular, bearing_field, catmull_rom, centroid, clamp_latitude, convex_hull,
+            longitude_delta, minimum_enclosing_cap, nearest_site, simplify, slope, subsolar_point,
+            triangle_area, unwrap_longitudes, weighted_centroid, wrap_longitude, wrap_longitudes,
+            Altitude, Geographic, Latitude, Longitude,
+        },
+        projection::{Equirectangular, Projection},
+        Tolerance,
+    };
+
+    #[test]
+    fn longitude_must_not_exceed_boundaries() {
+        struct Test {
+            name: &'static str,
+            input: f64,
+            output: f64,
+        }
+
+        vec![
+            Test {
+                name: "positive longitude value must not change",
+                input: 1.,
+                output: 1.,
+            },
+            Test {
+                name: "negative longitude value must not change",
+                input: -3.,
+                output: -3.,
+            },
+            Test {
+                name: "positive overflowing longitude must change",
+                input: PI + 1.,
+                output: -PI + 1.,
+            },
+            Test {
+                name: "negative overflowing longitude must change",
+                input: -PI - 1.,
+                output: PI - 1.,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let longitude = Longitude::from(test.input).into_inner();
+
+            assert_eq!(
+                longitude, test.output,
+                "{}: got longitude = {}, want {}",
+                test.name, longitude, test.output
+            );
+        });
+    }
+
+    #[test]
     fn latitude_must_not_exceed_boundaries() {
         struct Test {
             name: &'static str,
@@ -507,4 +1785,2550 @@ mod tests {
             )
         });
     }
+
+    #[test]
+    fn geographic_cross_track_distance() {
+        struct Test {
+            name: &'static str,
+            point: Geographic<f64>,
+            from: Geographic<f64>,
+            to: Geographic<f64>,
+            distance: f64,
+        }
+
+        vec![
+            Test {
+                name: "point on the great circle must be zero",
+                point: Geographic::origin().with_longitude(Longitude::from(2.)),
+                from: Geographic::origin(),
+                to: Geographic::origin().with_longitude(Longitude::from(1.)),
+                distance: 0.,
+            },
+            Test {
+                name: "north pole from the equator heading east must be positive",
+                point: Geographic::origin().with_latitude(Latitude::from(FRAC_PI_2)),
+                from: Geographic::origin(),
+                to: Geographic::origin().with_longitude(Longitude::from(1.)),
+                distance: FRAC_PI_2,
+            },
+            Test {
+                name: "southern point from the equator heading east must be negative",
+                point: Geographic::origin().with_latitude(Latitude::from(-0.5)),
+                from: Geographic::origin(),
+                to: Geographic::origin().with_longitude(Longitude::from(1.)),
+                distance: -0.5,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let distance = test.point.cross_track_distance(&test.from, &test.to);
+            let tolerance = 1e-09;
+
+            assert!(
+                (distance - test.distance).abs() < tolerance,
+                "{}: got cross-track distance = {}, want {}",
+                test.name,
+                distance,
+                test.distance
+            );
+        });
+    }
+
+    #[test]
+    fn geographic_simplify() {
+        struct Test {
+            name: &'static str,
+            input: Vec<Geographic<f64>>,
+            tolerance: f64,
+            output: Vec<Geographic<f64>>,
+        }
+
+        vec![
+            Test {
+                name: "straight run must collapse to its endpoints",
+                input: vec![
+                    Geographic::origin(),
+                    Geographic::origin().with_longitude(Longitude::from(0.1)),
+                    Geographic::origin().with_longitude(Longitude::from(0.2)),
+                    Geographic::origin().with_longitude(Longitude::from(0.3)),
+                ],
+                tolerance: 1e-06,
+                output: vec![
+                    Geographic::origin(),
+                    Geographic::origin().with_longitude(Longitude::from(0.3)),
+                ],
+            },
+            Test {
+                name: "off-line midpoint must be retained",
+                input: vec![
+                    Geographic::origin(),
+                    Geographic::origin().with_longitude(Longitude::from(0.1)),
+                    Geographic::origin()
+                        .with_longitude(Longitude::from(0.2))
+                        .with_latitude(Latitude::from(0.3)),
+                    Geographic::origin().with_longitude(Longitude::from(0.4)),
+                ],
+                tolerance: 0.2,
+                output: vec![
+                    Geographic::origin(),
+                    Geographic::origin()
+                        .with_longitude(Longitude::from(0.2))
+                        .with_latitude(Latitude::from(0.3)),
+                    Geographic::origin().with_longitude(Longitude::from(0.4)),
+                ],
+            },
+            Test {
+                name: "closed ring must keep its corners",
+                input: vec![
+                    Geographic::origin(),
+                    Geographic::origin().with_longitude(Longitude::from(0.2)),
+                    Geographic::origin()
+                        .with_longitude(Longitude::from(0.2))
+                        .with_latitude(Latitude::from(0.2)),
+                    Geographic::origin().with_latitude(Latitude::from(0.2)),
+                    Geographic::origin(),
+                ],
+                tolerance: 0.01,
+                output: vec![
+                    Geographic::origin(),
+                    Geographic::origin().with_longitude(Longitude::from(0.2)),
+                    Geographic::origin()
+                        .with_longitude(Longitude::from(0.2))
+                        .with_latitude(Latitude::from(0.2)),
+                    Geographic::origin().with_latitude(Latitude::from(0.2)),
+                    Geographic::origin(),
+                ],
+            },
+            Test {
+                name: "closed ring within the tolerance must collapse to its endpoints",
+                input: vec![
+                    Geographic::origin(),
+                    Geographic::origin().with_longitude(Longitude::from(1e-03)),
+                    Geographic::origin().with_latitude(Latitude::from(1e-03)),
+                    Geographic::origin(),
+                ],
+                tolerance: 0.01,
+                output: vec![Geographic::origin(), Geographic::origin()],
+            },
+            Test {
+                name: "less than three points must not change",
+                input: vec![
+                    Geographic::origin(),
+                    Geographic::origin().with_longitude(Longitude::from(0.1)),
+                ],
+                tolerance: 1.,
+                output: vec![
+                    Geographic::origin(),
+                    Geographic::origin().with_longitude(Longitude::from(0.1)),
+                ],
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let simplified = simplify(&test.input, test.tolerance);
+
+            assert_eq!(
+                simplified, test.output,
+                "{}: got simplified = {:#?}, want {:#?}",
+                test.name, simplified, test.output
+            );
+        });
+    }
+
+    #[test]
+    fn wrappers_must_not_exceed_boundaries_f32() {
+        use std::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+        struct Test {
+            name: &'static str,
+            longitude: f32,
+            latitude: f32,
+            output: Geographic<f32>,
+        }
+
+        vec![
+            Test {
+                name: "values within range must not change",
+                longitude: 1.,
+                latitude: -1.,
+                output: Geographic {
+                    longitude: Longitude(1.),
+                    latitude: Latitude(-1.),
+                    altitude: Altitude::from(0.),
+                },
+            },
+            Test {
+                name: "overflowing values must change",
+                longitude: PI + 1.,
+                latitude: 3. * FRAC_PI_4,
+                output: Geographic {
+                    longitude: Longitude(-PI + 1.),
+                    latitude: Latitude(FRAC_PI_4),
+                    altitude: Altitude::from(0.),
+                },
+            },
+            Test {
+                name: "negative overflowing values must change",
+                longitude: -PI - 1.,
+                latitude: -FRAC_PI_2 - FRAC_PI_4,
+                output: Geographic {
+                    longitude: Longitude(PI - 1.),
+                    latitude: Latitude(-FRAC_PI_4),
+                    altitude: Altitude::from(0.),
+                },
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let point = Geographic::origin()
+                .with_longitude(Longitude::from(test.longitude))
+                .with_latitude(Latitude::from(test.latitude));
+
+            let tolerance = 1e-06;
+
+            assert!(
+                (point.longitude.into_inner() - test.output.longitude.into_inner()).abs()
+                    < tolerance
+                    && (point.latitude.into_inner() - test.output.latitude.into_inner()).abs()
+                        < tolerance,
+                "{}: got point = {:?}, want {:?}",
+                test.name,
+                point,
+                test.output
+            );
+        });
+    }
+
+    #[test]
+    fn geographic_from_cartesian_f32() {
+        use std::f32::consts::{FRAC_PI_2, PI};
+
+        struct Test {
+            name: &'static str,
+            input: Cartesian<f32>,
+            output: Geographic<f32>,
+        }
+
+        vec![
+            Test {
+                name: "north point",
+                input: Cartesian::origin().with_z(1.),
+                output: Geographic::origin()
+                    .with_latitude(Latitude::from(FRAC_PI_2))
+                    .with_altitude(Altitude::from(1.)),
+            },
+            Test {
+                name: "weast point",
+                input: Cartesian::origin().with_y(-1.),
+                output: Geographic::origin()
+                    .with_longitude(Longitude::from(-FRAC_PI_2))
+                    .with_altitude(Altitude::from(1.)),
+            },
+            Test {
+                name: "back point",
+                input: Cartesian::origin().with_x(-1.),
+                output: Geographic::origin()
+                    .with_longitude(Longitude::from(PI))
+                    .with_altitude(Altitude::from(1.)),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let point = Geographic::from(test.input);
+
+            assert_eq!(
+                point, test.output,
+                "{}: got geographic point = {:?}, want {:?}",
+                test.name, point, test.output
+            );
+        });
+    }
+
+    #[test]
+    fn geographic_side_of() {
+        struct Test {
+            name: &'static str,
+            point: Geographic<f64>,
+            side: Ordering,
+        }
+
+        let equator_normal = Cartesian::origin().with_z(1.);
+
+        vec![
+            Test {
+                name: "northern point must be on the side of the normal",
+                point: Geographic::origin()
+                    .with_longitude(Longitude::from(2.))
+                    .with_latitude(Latitude::from(0.3)),
+                side: Ordering::Greater,
+            },
+            Test {
+                name: "southern point must be on the opposite side of the normal",
+                point: Geographic::origin()
+                    .with_longitude(Longitude::from(-1.))
+                    .with_latitude(Latitude::from(-FRAC_PI_2)),
+                side: Ordering::Less,
+            },
+            Test {
+                name: "equatorial point must be on the circle",
+                point: Geographic::origin().with_longitude(Longitude::from(PI)),
+                side: Ordering::Equal,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let side = test.point.side_of(&equator_normal);
+
+            assert_eq!(
+                side, test.side,
+                "{}: got side = {:?}, want {:?}",
+                test.name, side, test.side
+            );
+        });
+    }
+
+    #[test]
+    fn geographic_destination() {
+        struct Test {
+            name: &'static str,
+            from: Geographic<f64>,
+            bearing: f64,
+            distance: f64,
+            output: Geographic<f64>,
+        }
+
+        vec![
+            Test {
+                name: "heading east from the equator must stay on the equator",
+                from: Geographic::origin(),
+                bearing: FRAC_PI_2,
+                distance: 0.5,
+                output: Geographic::origin().with_longitude(Longitude::from(0.5)),
+            },
+            Test {
+                name: "heading north must stay on the meridian",
+                from: Geographic::origin().with_longitude(Longitude::from(1.)),
+                bearing: 0.,
+                distance: 0.5,
+                output: Geographic::origin()
+                    .with_longitude(Longitude::from(1.))
+                    .with_latitude(Latitude::from(0.5)),
+            },
+            Test {
+                name: "heading west across the antimeridian must wrap the longitude",
+                from: Geographic::origin().with_longitude(Longitude::from(-PI + 0.1)),
+                bearing: 3. * FRAC_PI_2,
+                distance: 0.3,
+                output: Geographic::origin().with_longitude(Longitude::from(PI - 0.2)),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let destination = test.from.destination(test.bearing.into(), test.distance);
+            let tolerance = 1e-09;
+
+            assert!(
+                (destination.longitude.into_inner() - test.output.longitude.into_inner()).abs()
+                    < tolerance
+                    && (destination.latitude.into_inner() - test.output.latitude.into_inner())
+                        .abs()
+                        < tolerance,
+                "{}: got destination = {:?}, want {:?}",
+                test.name,
+                destination,
+                test.output
+            );
+        });
+    }
+
+    #[test]
+    fn geographic_from_cartesian_with_radius() {
+        struct Test {
+            name: &'static str,
+            input: Cartesian<f64>,
+            radius: f64,
+            output: Geographic<f64>,
+            height: f64,
+        }
+
+        vec![
+            Test {
+                name: "point above the surface must be at its height",
+                input: Cartesian::origin().with_z(6_372_000.),
+                radius: 6_371_000.,
+                output: Geographic::origin()
+                    .with_latitude(Latitude::from(FRAC_PI_2))
+                    .at_height_above(6_371_000., 1_000.),
+                height: 1_000.,
+            },
+            Test {
+                name: "point on the surface must be at no height",
+                input: Cartesian::origin().with_y(-2.),
+                radius: 2.,
+                output: Geographic::origin()
+                    .with_longitude(Longitude::from(-FRAC_PI_2))
+                    .with_altitude(Altitude::from(2.)),
+                height: 0.,
+            },
+            Test {
+                name: "point below the surface must be at a negative height",
+                input: Cartesian::origin().with_x(1.5),
+                radius: 2.,
+                output: Geographic::origin().at_height_above(2., -0.5),
+                height: -0.5,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let (point, height) = Geographic::from_cartesian_with_radius(test.input, test.radius);
+
+            assert_eq!(
+                point, test.output,
+                "{}: got point = {:?}, want {:?}",
+                test.name, point, test.output
+            );
+
+            assert!(
+                (height - test.height).abs() < 1e-06
+                    && (point.height_above(test.radius) - test.height).abs() < 1e-06,
+                "{}: got height = {}, want {}",
+                test.name,
+                height,
+                test.height
+            );
+
+            let round_trip = point.into_cartesian();
+            assert!(
+                round_trip.distance(&test.input) < 1e-06,
+                "{}: got round trip = {:?}, want {:?}",
+                test.name,
+                round_trip,
+                test.input
+            );
+        });
+    }
+
+    #[test]
+    fn geographic_catmull_rom() {
+        struct Test {
+            name: &'static str,
+            points: Vec<Geographic<f64>>,
+        }
+
+        vec![
+            Test {
+                name: "two control points",
+                points: vec![
+                    Geographic::origin(),
+                    Geographic::origin()
+                        .with_longitude(Longitude::from(1.))
+                        .with_latitude(Latitude::from(0.5)),
+                ],
+            },
+            Test {
+                name: "many control points",
+                points: vec![
+                    Geographic::origin(),
+                    Geographic::origin()
+                        .with_longitude(Longitude::from(0.5))
+                        .with_latitude(Latitude::from(0.3)),
+                    Geographic::origin()
+                        .with_longitude(Longitude::from(1.2))
+                        .with_latitude(Latitude::from(-0.2)),
+                    Geographic::origin()
+                        .with_longitude(Longitude::from(2.))
+                        .with_latitude(Latitude::from(0.1)),
+                    Geographic::origin()
+                        .with_longitude(Longitude::from(-3.))
+                        .with_latitude(Latitude::from(0.6)),
+                ],
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let tolerance = 1e-09;
+            let segments = (test.points.len() - 1) as f64;
+
+            test.points.iter().enumerate().for_each(|(index, want)| {
+                let got = catmull_rom(&test.points, index as f64 / segments).unwrap();
+
+                assert!(
+                    (got.longitude.into_inner() - want.longitude.into_inner()).abs() < tolerance
+                        && (got.latitude.into_inner() - want.latitude.into_inner()).abs()
+                            < tolerance,
+                    "{}: got point = {:?} at control point {}, want {:?}",
+                    test.name,
+                    got,
+                    index,
+                    want
+                );
+            });
+        });
+
+        assert_eq!(catmull_rom::<f64>(&[], 0.5), None);
+        assert_eq!(
+            catmull_rom(&[Geographic::origin()], 0.5),
+            Some(Geographic::origin())
+        );
+    }
+
+    #[test]
+    fn geographic_constructors() {
+        let longitude = Longitude::from(1.);
+        let latitude = Latitude::from(-0.5);
+        let altitude = Altitude::from(3.);
+
+        let want = Geographic::origin()
+            .with_longitude(longitude)
+            .with_latitude(latitude)
+            .with_altitude(altitude);
+
+        let got = Geographic::new(longitude, latitude, altitude);
+        assert_eq!(got, want, "got new = {got:?}, want {want:?}");
+
+        let got = Geographic::from((longitude, latitude, altitude));
+        assert_eq!(got, want, "got from triple = {got:?}, want {want:?}");
+
+        let want = want.with_altitude(Altitude::default());
+        let got = Geographic::from((longitude, latitude));
+        assert_eq!(got, want, "got from pair = {got:?}, want {want:?}");
+    }
+
+    #[test]
+    fn geographic_unwrap_longitudes() {
+        let path: Vec<_> = [2.8, 3.0, -3.1, -2.9, -3.1, 3.0]
+            .into_iter()
+            .map(|longitude| Geographic::origin().with_longitude(Longitude::from(longitude)))
+            .collect();
+
+        let unwrapped = unwrap_longitudes(&path);
+        let want = [2.8, 3.0, TAU - 3.1, TAU - 2.9, TAU - 3.1, 3.0];
+        let tolerance = 1e-09;
+
+        unwrapped.iter().zip(want).for_each(|(got, want)| {
+            assert!(
+                (got - want).abs() < tolerance,
+                "got unwrapped = {:?}, want longitudes {:?}",
+                unwrapped,
+                want
+            );
+        });
+
+        let wrapped = wrap_longitudes(&unwrapped);
+        wrapped.iter().zip(&path).for_each(|(got, want)| {
+            assert!(
+                got.approx_eq(&want.longitude, Tolerance::new(tolerance)) && got.is_valid(),
+                "got wrapped = {:?}, want {:?}",
+                wrapped,
+                path
+            );
+        });
+
+        let eastward: Vec<_> = [3.0, -3.1, -2.9]
+            .into_iter()
+            .map(|longitude| Geographic::origin().with_longitude(Longitude::from(longitude)))
+            .collect();
+
+        assert!(
+            unwrap_longitudes(&eastward)
+                .windows(2)
+                .all(|pair| pair[0] < pair[1]),
+            "eastward path crossing the antimeridian must be monotonic"
+        );
+
+        assert!(unwrap_longitudes::<f64>(&[]).is_empty());
+    }
+
+    #[test]
+    fn geographic_distance_meters() {
+        struct Test {
+            name: &'static str,
+            from: Geographic<f64>,
+            to: Geographic<f64>,
+            distance: f64,
+        }
+
+        vec![
+            Test {
+                name: "a quarter of the equator on the Earth",
+                from: Geographic::default(),
+                to: Geographic::default().with_longitude(Longitude::from(FRAC_PI_2)),
+                distance: 10_007_557.,
+            },
+            Test {
+                name: "the altitude must not scale the distance",
+                from: Geographic::default().with_altitude(Altitude::from(2.)),
+                to: Geographic::default()
+                    .with_latitude(Latitude::from(FRAC_PI_2))
+                    .with_altitude(Altitude::from(3.)),
+                distance: 10_007_557.,
+            },
+            Test {
+                name: "same point must be at zero meters",
+                from: Geographic::default(),
+                to: Geographic::default(),
+                distance: 0.,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let distance = test
+                .from
+                .distance_meters(&test.to, Geographic::<f64>::EARTH_MEAN_RADIUS_M);
+
+            assert!(
+                (distance - test.distance).abs() < 1.,
+                "{}: got distance = {}, want {}",
+                test.name,
+                distance,
+                test.distance
+            );
+        });
+    }
+
+    #[test]
+    fn geographic_track() {
+        struct Test {
+            name: &'static str,
+            from: Geographic<f64>,
+            bearing: f64,
+            distance: f64,
+        }
+
+        vec![
+            Test {
+                name: "heading north east from the origin",
+                from: Geographic::origin(),
+                bearing: FRAC_PI_2 / 2.,
+                distance: 1.,
+            },
+            Test {
+                name: "heading south west across the antimeridian",
+                from: Geographic::origin()
+                    .with_longitude(Longitude::from(-PI + 0.1))
+                    .with_latitude(Latitude::from(0.4)),
+                bearing: 4.,
+                distance: 0.8,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let segments = NonZeroUsize::new(4).unwrap();
+            let track = test
+                .from
+                .track(test.bearing.into(), test.distance, segments);
+            let destination = test.from.destination(test.bearing.into(), test.distance);
+            let tolerance = 1e-09;
+
+            assert_eq!(
+                track.to, destination,
+                "{}: got track end = {:?}, want {:?}",
+                test.name, track.to, destination
+            );
+
+            let length = track.from.distance_meters(&track.to, 1.);
+            assert!(
+                (length - test.distance).abs() < tolerance,
+                "{}: got length = {}, want {}",
+                test.name,
+                length,
+                test.distance
+            );
+
+            assert_eq!(
+                track.segments, segments,
+                "{}: got segments = {}, want {}",
+                test.name, track.segments, segments
+            );
+        });
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn geographic_serde_degrees() {
+        #[derive(Debug, serde::Serialize, serde::Deserialize)]
+        struct Wrapper {
+            #[serde(with = "crate::geographic::serde_degrees")]
+            point: Geographic<f64>,
+        }
+
+        struct Test {
+            name: &'static str,
+            json: &'static str,
+            point: Geographic<f64>,
+        }
+
+        vec![
+            Test {
+                name: "point in the southern and western hemispheres",
+                json: r#"{"point":{"lon":-90.0,"lat":-45.0,"alt":10.0}}"#,
+                point: Geographic::origin()
+                    .with_longitude(Longitude::from(-FRAC_PI_2))
+                    .with_latitude(Latitude::from(-FRAC_PI_2 / 2.))
+                    .with_altitude(Altitude::from(10.)),
+            },
+            Test {
+                name: "antimeridian must be normalized",
+                json: r#"{"point":{"lon":180.0,"lat":0.0,"alt":0.0}}"#,
+                point: Geographic::origin().with_longitude(Longitude::from(PI)),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let decoded: Wrapper = serde_json::from_str(test.json).unwrap();
+            let tolerance = 1e-09;
+
+            assert!(
+                (decoded.point.longitude.into_inner() - test.point.longitude.into_inner()).abs()
+                    < tolerance
+                    && (decoded.point.latitude.into_inner() - test.point.latitude.into_inner())
+                        .abs()
+                        < tolerance
+                    && decoded.point.altitude == test.point.altitude,
+                "{}: got point = {:?}, want {:?}",
+                test.name,
+                decoded.point,
+                test.point
+            );
+
+            let encoded = serde_json::to_string(&decoded).unwrap();
+            let round_trip: Wrapper = serde_json::from_str(&encoded).unwrap();
+
+            assert!(
+                (round_trip.point.longitude.into_inner() - decoded.point.longitude.into_inner())
+                    .abs()
+                    < tolerance
+                    && (round_trip.point.latitude.into_inner()
+                        - decoded.point.latitude.into_inner())
+                    .abs()
+                        < tolerance
+                    && round_trip.point.altitude == decoded.point.altitude,
+                "{}: got round trip = {:?}, want {:?}",
+                test.name,
+                round_trip.point,
+                decoded.point
+            );
+        });
+    }
+
+    #[test]
+    fn longitude_antimeridian() {
+        struct Test {
+            name: &'static str,
+            lhs: Longitude<f64>,
+            rhs: Longitude<f64>,
+            tolerance: f64,
+            approx_eq: bool,
+        }
+
+        let below_minus_pi = -f64::from_bits(PI.to_bits() + 1);
+
+        vec![
+            Test {
+                name: "positive and negative pi must be the same",
+                lhs: Longitude::from(PI),
+                rhs: Longitude::from(-PI),
+                tolerance: 0.,
+                approx_eq: true,
+            },
+            Test {
+                name: "value right below negative pi must not land on positive pi",
+                lhs: Longitude::from(below_minus_pi),
+                rhs: Longitude::from(-PI),
+                tolerance: 0.,
+                approx_eq: true,
+            },
+            Test {
+                name: "values at both sides of the antimeridian must be close",
+                lhs: Longitude::from(PI - 0.01),
+                rhs: Longitude::from(-PI + 0.01),
+                tolerance: 0.03,
+                approx_eq: true,
+            },
+            Test {
+                name: "values at both sides of the antimeridian must not be equal",
+                lhs: Longitude::from(PI - 0.01),
+                rhs: Longitude::from(-PI + 0.01),
+                tolerance: 0.01,
+                approx_eq: false,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            [test.lhs, test.rhs].into_iter().for_each(|longitude| {
+                assert!(
+                    longitude.into_inner() < PI,
+                    "{}: got longitude = {:?}, want it below pi",
+                    test.name,
+                    longitude
+                );
+
+                assert_eq!(
+                    longitude.canonical(),
+                    longitude,
+                    "{}: got canonical = {:?}, want {:?}",
+                    test.name,
+                    longitude.canonical(),
+                    longitude
+                );
+            });
+
+            let approx_eq = test
+                .lhs
+                .approx_eq(&test.rhs, Tolerance::new(test.tolerance));
+            assert_eq!(
+                approx_eq, test.approx_eq,
+                "{}: got approx_eq = {}, want {}",
+                test.name, approx_eq, test.approx_eq
+            );
+        });
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn geographic_normalized() {
+        struct Test {
+            name: &'static str,
+            json: &'static str,
+            output: Geographic<f64>,
+        }
+
+        vec![
+            Test {
+                name: "in range point must not change",
+                json: r#"{"longitude":1.0,"latitude":-0.5,"altitude":2.0}"#,
+                output: Geographic::origin()
+                    .with_longitude(Longitude::from(1.))
+                    .with_latitude(Latitude::from(-0.5))
+                    .with_altitude(Altitude::from(2.)),
+            },
+            Test {
+                name: "out of range point must be normalized",
+                json: r#"{"longitude":4.0,"latitude":2.0,"altitude":-3.0}"#,
+                output: Geographic::origin()
+                    .with_longitude(Longitude::from(4. - TAU))
+                    .with_latitude(Latitude::from(PI - 2.))
+                    .with_altitude(Altitude::from(3.)),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let drifted: Geographic<f64> = serde_json::from_str(test.json).unwrap();
+            let normalized = drifted.normalized();
+            let tolerance = 1e-09;
+
+            assert!(
+                (normalized.longitude.into_inner() - test.output.longitude.into_inner()).abs()
+                    < tolerance
+                    && (normalized.latitude.into_inner() - test.output.latitude.into_inner()).abs()
+                        < tolerance
+                    && normalized.altitude == test.output.altitude,
+                "{}: got normalized = {:?}, want {:?}",
+                test.name,
+                normalized,
+                test.output
+            );
+        });
+    }
+
+    #[test]
+    fn clamp_latitude_and_wrap_longitude() {
+        struct Test {
+            name: &'static str,
+            input: f64,
+            latitude: f64,
+            longitude: f64,
+        }
+
+        vec![
+            Test {
+                name: "in range value must not change",
+                input: 0.5,
+                latitude: 0.5,
+                longitude: 0.5,
+            },
+            Test {
+                name: "value beyond the north pole must saturate",
+                input: 2.,
+                latitude: FRAC_PI_2,
+                longitude: 2.,
+            },
+            Test {
+                name: "value beyond the south pole must saturate",
+                input: -4.,
+                latitude: -FRAC_PI_2,
+                longitude: TAU - 4.,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let latitude = clamp_latitude(test.input).into_inner();
+            let longitude = wrap_longitude(test.input).into_inner();
+            let tolerance = 1e-09;
+
+            assert!(
+                (latitude - test.latitude).abs() < tolerance,
+                "{}: got latitude = {}, want {}",
+                test.name,
+                latitude,
+                test.latitude
+            );
+
+            assert!(
+                (longitude - test.longitude).abs() < tolerance,
+                "{}: got longitude = {}, want {}",
+                test.name,
+                longitude,
+                test.longitude
+            );
+        });
+    }
+
+    #[test]
+    fn geographic_triangle_area() {
+        struct Test {
+            name: &'static str,
+            vertices: [Geographic<f64>; 3],
+            radius: f64,
+            area: f64,
+        }
+
+        vec![
+            Test {
+                name: "octant of the unit sphere",
+                vertices: [
+                    Geographic::origin(),
+                    Geographic::origin().with_longitude(Longitude::from(FRAC_PI_2)),
+                    Geographic::origin().with_latitude(Latitude::from(FRAC_PI_2)),
+                ],
+                radius: 1.,
+                area: FRAC_PI_2,
+            },
+            Test {
+                name: "octant of a sphere of radius 3",
+                vertices: [
+                    Geographic::origin().with_longitude(Longitude::from(-FRAC_PI_2)),
+                    Geographic::origin().with_latitude(Latitude::from(-FRAC_PI_2)),
+                    Geographic::origin(),
+                ],
+                radius: 3.,
+                area: FRAC_PI_2 * 9.,
+            },
+            Test {
+                name: "colinear vertices must have no area",
+                vertices: [
+                    Geographic::origin(),
+                    Geographic::origin().with_longitude(Longitude::from(0.5)),
+                    Geographic::origin().with_longitude(Longitude::from(1.)),
+                ],
+                radius: 1.,
+                area: 0.,
+            },
+            Test {
+                name: "coincident vertices must have no area",
+                vertices: [
+                    Geographic::origin(),
+                    Geographic::origin(),
+                    Geographic::origin().with_latitude(Latitude::from(1.)),
+                ],
+                radius: 1.,
+                area: 0.,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let [a, b, c] = test.vertices;
+            let area = triangle_area(&a, &b, &c, test.radius);
+            let tolerance = 1e-06;
+
+            assert!(
+                (area - test.area).abs() < tolerance,
+                "{}: got area = {}, want {}",
+                test.name,
+                area,
+                test.area
+            );
+        });
+    }
+
+    #[test]
+    fn geographic_enu_vectors() {
+        struct Test {
+            name: &'static str,
+            point: Geographic<f64>,
+            east: Cartesian<f64>,
+            north: Cartesian<f64>,
+            up: Cartesian<f64>,
+        }
+
+        vec![
+            Test {
+                name: "equator at the prime meridian",
+                point: Geographic::origin(),
+                east: Cartesian::origin().with_y(1.),
+                north: Cartesian::origin().with_z(1.),
+                up: Cartesian::origin().with_x(1.),
+            },
+            Test {
+                name: "south pole",
+                point: Geographic::origin().with_latitude(Latitude::from(-FRAC_PI_2)),
+                east: Cartesian::origin().with_y(1.),
+                north: Cartesian::origin().with_x(1.),
+                up: Cartesian::origin().with_z(-1.),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let tolerance = 1e-09;
+
+            [
+                ("east", test.point.tangent_east(), test.east),
+                ("north", test.point.tangent_north(), test.north),
+                ("up", test.point.normal_up(), test.up),
+            ]
+            .into_iter()
+            .for_each(|(axis, got, want)| {
+                got.into_iter().zip(want).for_each(|(got_c, want_c)| {
+                    assert!(
+                        (got_c - want_c).abs() < tolerance,
+                        "{}: got {} = {:?}, want {:?}",
+                        test.name,
+                        axis,
+                        got,
+                        want
+                    );
+                });
+            });
+        });
+    }
+
+    #[test]
+    fn geographic_rhumb_midpoint() {
+        struct Test {
+            name: &'static str,
+            from: Geographic<f64>,
+            to: Geographic<f64>,
+            midpoint: Geographic<f64>,
+        }
+
+        // the stretched latitude of the Mercator projection.
+        let psi = |latitude: f64| (FRAC_PI_2 / 2. + latitude / 2.).tan().ln();
+
+        vec![
+            Test {
+                name: "equatorial points must average their longitudes",
+                from: Geographic::origin().with_longitude(Longitude::from(-0.4)),
+                to: Geographic::origin().with_longitude(Longitude::from(1.)),
+                midpoint: Geographic::origin().with_longitude(Longitude::from(0.3)),
+            },
+            Test {
+                name: "equatorial points must cross the antimeridian if shorter",
+                from: Geographic::origin().with_longitude(Longitude::from(PI - 0.1)),
+                to: Geographic::origin().with_longitude(Longitude::from(-PI + 0.3)),
+                midpoint: Geographic::origin().with_longitude(Longitude::from(-PI + 0.1)),
+            },
+            Test {
+                name: "meridian must keep the longitude",
+                from: Geographic::origin()
+                    .with_longitude(Longitude::from(2.))
+                    .with_latitude(Latitude::from(-0.2)),
+                to: Geographic::origin()
+                    .with_longitude(Longitude::from(2.))
+                    .with_latitude(Latitude::from(1.)),
+                midpoint: Geographic::origin()
+                    .with_longitude(Longitude::from(2.))
+                    .with_latitude(Latitude::from(0.4)),
+            },
+            Test {
+                name: "oblique rhumb line must interpolate along the stretched latitude",
+                from: Geographic::origin(),
+                to: Geographic::origin()
+                    .with_longitude(Longitude::from(1.))
+                    .with_latitude(Latitude::from(1.)),
+                midpoint: Geographic::origin()
+                    .with_longitude(Longitude::from(psi(0.5) / psi(1.)))
+                    .with_latitude(Latitude::from(0.5)),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let midpoint = test.from.rhumb_midpoint(&test.to);
+            let tolerance = 1e-09;
+
+            assert!(
+                midpoint
+                    .longitude
+                    .approx_eq(&test.midpoint.longitude, Tolerance::new(tolerance))
+                    && (midpoint.latitude.into_inner() - test.midpoint.latitude.into_inner()).abs()
+                        < tolerance,
+                "{}: got midpoint = {:?}, want {:?}",
+                test.name,
+                midpoint,
+                test.midpoint
+            );
+        });
+    }
+
+    #[test]
+    fn geographic_distance_3d() {
+        struct Test {
+            name: &'static str,
+            from: Geographic<f64>,
+            to: Geographic<f64>,
+            distance: f64,
+        }
+
+        let radius = Geographic::<f64>::EARTH_MEAN_RADIUS_M;
+
+        vec![
+            Test {
+                name: "points above each other must be as far as their altitudes",
+                from: Geographic::origin()
+                    .with_latitude(Latitude::from(0.7))
+                    .with_altitude(Altitude::from(1_000.)),
+                to: Geographic::origin()
+                    .with_latitude(Latitude::from(0.7))
+                    .with_altitude(Altitude::from(10_000.)),
+                distance: 9_000.,
+            },
+            Test {
+                name: "surface points must be as far as their chord",
+                from: Geographic::origin(),
+                to: Geographic::origin().with_longitude(Longitude::from(FRAC_PI_2)),
+                distance: radius * 2_f64.sqrt(),
+            },
+            Test {
+                name: "same point must be at no distance",
+                from: Geographic::origin().with_altitude(Altitude::from(5.)),
+                to: Geographic::origin().with_altitude(Altitude::from(5.)),
+                distance: 0.,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let distance = test.from.distance_3d(&test.to, radius);
+            let tolerance = 1e-06;
+
+            assert!(
+                (distance - test.distance).abs() < tolerance,
+                "{}: got distance = {}, want {}",
+                test.name,
+                distance,
+                test.distance
+            );
+        });
+    }
+
+    #[test]
+    fn coordinates_clamp() {
+        struct Test {
+            name: &'static str,
+            value: f64,
+            min: f64,
+            max: f64,
+            output: f64,
+        }
+
+        let tolerance = 1e-09;
+
+        vec![
+            Test {
+                name: "latitude within the band must not change",
+                value: 0.3,
+                min: -0.5,
+                max: 0.5,
+                output: 0.3,
+            },
+            Test {
+                name: "latitude above the band must be the maximum",
+                value: 1.2,
+                min: -0.5,
+                max: 0.5,
+                output: 0.5,
+            },
+            Test {
+                name: "latitude below the band must be the minimum",
+                value: -1.2,
+                min: -0.5,
+                max: 0.5,
+                output: -0.5,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let latitude = Latitude::from(test.value)
+                .clamp(Latitude::from(test.min), Latitude::from(test.max))
+                .into_inner();
+
+            assert!(
+                (latitude - test.output).abs() < tolerance,
+                "{}: got latitude = {}, want {}",
+                test.name,
+                latitude,
+                test.output
+            );
+
+            let altitude = Altitude::from(test.value.abs())
+                .clamp(Altitude::from(0.4), Altitude::from(1.))
+                .into_inner();
+
+            let want = test.value.abs().clamp(0.4, 1.);
+            assert!(
+                (altitude - want).abs() < tolerance,
+                "{}: got altitude = {}, want {}",
+                test.name,
+                altitude,
+                want
+            );
+        });
+
+        vec![
+            Test {
+                name: "longitude within a range crossing the antimeridian must not change",
+                value: -PI + 0.1,
+                min: PI - 0.5,
+                max: -PI + 0.5,
+                output: -PI + 0.1,
+            },
+            Test {
+                name: "longitude east of a range crossing the antimeridian must be the maximum",
+                value: -PI + 0.7,
+                min: PI - 0.5,
+                max: -PI + 0.5,
+                output: -PI + 0.5,
+            },
+            Test {
+                name: "longitude west of a range crossing the antimeridian must be the minimum",
+                value: PI - 0.8,
+                min: PI - 0.5,
+                max: -PI + 0.5,
+                output: PI - 0.5,
+            },
+            Test {
+                name: "longitude must move to the boundary closest across the antimeridian",
+                value: -PI + 0.2,
+                min: -1.,
+                max: 2.,
+                output: 2.,
+            },
+            Test {
+                name: "longitude within a regular range must not change",
+                value: 1.,
+                min: -1.,
+                max: 2.,
+                output: 1.,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let longitude = Longitude::from(test.value)
+                .clamp(Longitude::from(test.min), Longitude::from(test.max))
+                .into_inner();
+
+            assert!(
+                (longitude - test.output).abs() < tolerance,
+                "{}: got longitude = {}, want {}",
+                test.name,
+                longitude,
+                test.output
+            );
+        });
+    }
+
+    #[test]
+    fn geographic_subsolar_point() {
+        struct Test {
+            name: &'static str,
+            declination: f64,
+            greenwich_hour_angle: f64,
+            output: Geographic<f64>,
+        }
+
+        vec![
+            Test {
+                name: "zero declination and hour angle must be at the equator and prime meridian",
+                declination: 0.,
+                greenwich_hour_angle: 0.,
+                output: Geographic::origin(),
+            },
+            Test {
+                name: "hour angle must move the subsolar point westward",
+                declination: 0.,
+                greenwich_hour_angle: FRAC_PI_2,
+                output: Geographic::origin().with_longitude(Longitude::from(-FRAC_PI_2)),
+            },
+            Test {
+                name: "declination must be the latitude of the subsolar point",
+                declination: 0.4,
+                greenwich_hour_angle: -1.,
+                output: Geographic::origin()
+                    .with_longitude(Longitude::from(1.))
+                    .with_latitude(Latitude::from(0.4)),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let output = subsolar_point(
+                Latitude::from(test.declination),
+                Longitude::from(test.greenwich_hour_angle),
+            );
+
+            assert_eq!(
+                output, test.output,
+                "{}: got subsolar point = {:?}, want {:?}",
+                test.name, output, test.output
+            );
+        });
+    }
+
+    #[test]
+    fn geographic_is_illuminated() {
+        struct Test {
+            name: &'static str,
+            point: Geographic<f64>,
+            illuminated: bool,
+        }
+
+        let subsolar = subsolar_point(Latitude::from(0.3), Longitude::from(1.));
+
+        vec![
+            Test {
+                name: "subsolar point must be illuminated",
+                point: subsolar,
+                illuminated: true,
+            },
+            Test {
+                name: "antipode of the subsolar point must be in darkness",
+                point: Geographic::origin()
+                    .with_longitude(Longitude::from(subsolar.longitude.into_inner() + PI))
+                    .with_latitude(Latitude::from(-subsolar.latitude.into_inner())),
+                illuminated: false,
+            },
+            Test {
+                name: "point close to the terminator on the day side must be illuminated",
+                point: subsolar.destination(1.0.into(), FRAC_PI_2 - 1e-06),
+                illuminated: true,
+            },
+            Test {
+                name: "point close to the terminator on the night side must be in darkness",
+                point: subsolar.destination(1.0.into(), FRAC_PI_2 + 1e-06),
+                illuminated: false,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let illuminated = test.point.is_illuminated(&subsolar);
+            assert_eq!(
+                illuminated, test.illuminated,
+                "{}: got illuminated = {}, want {}",
+                test.name, illuminated, test.illuminated
+            );
+        });
+    }
+
+    #[test]
+    fn geographic_convex_hull() {
+        struct Test {
+            name: &'static str,
+            points: Vec<Geographic<f64>>,
+            hull: Vec<Geographic<f64>>,
+        }
+
+        let center = Geographic::origin()
+            .with_longitude(Longitude::from(PI - 0.2))
+            .with_latitude(Latitude::from(1.2));
+
+        // the boundary of a cap, iterated counter-clockwise.
+        let boundary: Vec<_> = (0..12)
+            .map(|step| center.destination((step as f64 * TAU / 12.).into(), 0.3))
+            .rev()
+            .collect();
+
+        let interior: Vec<_> = (0..7)
+            .map(|step| center.destination((step as f64 * 0.9).into(), 0.02 * step as f64))
+            .collect();
+
+        vec![
+            Test {
+                name: "no points must yield an empty hull",
+                points: vec![],
+                hull: vec![],
+            },
+            Test {
+                name: "single distinct point must be the only vertex",
+                points: vec![center, center],
+                hull: vec![center],
+            },
+            Test {
+                name: "points on the same great circle must yield the farthest ones",
+                points: vec![
+                    Geographic::origin().with_longitude(Longitude::from(0.2)),
+                    Geographic::origin().with_longitude(Longitude::from(-0.4)),
+                    Geographic::origin(),
+                    Geographic::origin().with_longitude(Longitude::from(0.5)),
+                ],
+                hull: vec![
+                    Geographic::origin().with_longitude(Longitude::from(-0.4)),
+                    Geographic::origin().with_longitude(Longitude::from(0.5)),
+                ],
+            },
+            Test {
+                name: "points inside a cap must yield the boundary of the cap",
+                points: interior.iter().chain(&boundary).copied().collect(),
+                hull: boundary.clone(),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let hull = convex_hull(&test.points).vertices;
+            assert_eq!(
+                hull.len(),
+                test.hull.len(),
+                "{}: got hull = {:?}, want {:?}",
+                test.name,
+                hull,
+                test.hull
+            );
+
+            // the hull may start at any of its vertices, but their order must be kept.
+            let offset = test
+                .hull
+                .iter()
+                .position(|vertex| hull.first().is_some_and(|first| first == vertex))
+                .unwrap_or_default();
+
+            hull.iter().enumerate().for_each(|(index, vertex)| {
+                let want = test.hull[(index + offset) % test.hull.len()];
+                assert_eq!(
+                    *vertex, want,
+                    "{}: got hull = {:?}, want {:?}",
+                    test.name, hull, test.hull
+                );
+            });
+        });
+    }
+
+    #[test]
+    fn geographic_mirror() {
+        struct Test {
+            name: &'static str,
+            input: Geographic<f64>,
+            equator: Geographic<f64>,
+            meridian: Geographic<f64>,
+            ninety_meridians: Geographic<f64>,
+        }
+
+        vec![
+            Test {
+                name: "northern point",
+                input: Geographic::origin()
+                    .with_longitude(Longitude::from(0.5))
+                    .with_latitude(Latitude::from(1.))
+                    .with_altitude(Altitude::from(2.)),
+                equator: Geographic::origin()
+                    .with_longitude(Longitude::from(0.5))
+                    .with_latitude(Latitude::from(-1.))
+                    .with_altitude(Altitude::from(2.)),
+                meridian: Geographic::origin()
+                    .with_longitude(Longitude::from(-0.5))
+                    .with_latitude(Latitude::from(1.))
+                    .with_altitude(Altitude::from(2.)),
+                ninety_meridians: Geographic::origin()
+                    .with_longitude(Longitude::from(PI - 0.5))
+                    .with_latitude(Latitude::from(1.))
+                    .with_altitude(Altitude::from(2.)),
+            },
+            Test {
+                name: "point at the antimeridian",
+                input: Geographic::origin()
+                    .with_longitude(Longitude::from(-PI))
+                    .with_latitude(Latitude::from(-0.3)),
+                equator: Geographic::origin()
+                    .with_longitude(Longitude::from(-PI))
+                    .with_latitude(Latitude::from(0.3)),
+                meridian: Geographic::origin()
+                    .with_longitude(Longitude::from(-PI))
+                    .with_latitude(Latitude::from(-0.3)),
+                ninety_meridians: Geographic::origin().with_latitude(Latitude::from(-0.3)),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let approx_eq = |got: &Geographic<f64>, want: &Geographic<f64>| {
+                got.longitude
+                    .approx_eq(&want.longitude, Tolerance::default())
+                    && (got.latitude.into_inner() - want.latitude.into_inner()).abs() < 1e-09
+                    && got.altitude == want.altitude
+            };
+
+            [
+                ("equator", test.input.mirror_equator(), test.equator),
+                ("meridian", test.input.mirror_meridian(), test.meridian),
+                (
+                    "ninety meridians",
+                    test.input.mirror_ninety_meridians(),
+                    test.ninety_meridians,
+                ),
+            ]
+            .into_iter()
+            .for_each(|(mirror, got, want)| {
+                assert!(
+                    approx_eq(&got, &want),
+                    "{}: got mirror {} = {:?}, want {:?}",
+                    test.name,
+                    mirror,
+                    got,
+                    want
+                );
+            });
+
+            [
+                test.input.mirror_equator().mirror_equator(),
+                test.input.mirror_meridian().mirror_meridian(),
+                test.input
+                    .mirror_ninety_meridians()
+                    .mirror_ninety_meridians(),
+            ]
+            .into_iter()
+            .for_each(|got| {
+                assert!(
+                    approx_eq(&got, &test.input),
+                    "{}: got double mirror = {:?}, want {:?}",
+                    test.name,
+                    got,
+                    test.input
+                );
+            });
+        });
+    }
+
+    #[test]
+    fn geographic_altitude_model() {
+        struct Test {
+            name: &'static str,
+            point: Geographic<f64>,
+            magnitude: f64,
+        }
+
+        let point = Geographic::origin()
+            .with_longitude(Longitude::from(-2.))
+            .with_latitude(Latitude::from(0.7))
+            .with_altitude(Altitude::from(3.));
+
+        vec![
+            Test {
+                name: "surface must be the unit sphere",
+                point: point.at_surface(),
+                magnitude: 1.,
+            },
+            Test {
+                name: "null height must be at the surface of the given sphere",
+                point: point.at_height_above(6_371_000., 0.),
+                magnitude: 6_371_000.,
+            },
+            Test {
+                name: "height must be added to the radius",
+                point: point.at_height_above(2., 0.5),
+                magnitude: 2.5,
+            },
+            Test {
+                name: "depth must be subtracted from the radius",
+                point: point.at_height_above(2., -0.5),
+                magnitude: 1.5,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let magnitude = test.point.into_cartesian().magnitude();
+            assert!(
+                (magnitude - test.magnitude).abs() < 1e-09 * test.magnitude,
+                "{}: got magnitude = {}, want {}",
+                test.name,
+                magnitude,
+                test.magnitude
+            );
+        });
+
+        assert_eq!(
+            point.at_surface().into_cartesian(),
+            point.with_altitude(Altitude::from(0.)).into_cartesian(),
+            "surface must be where points with no altitude are"
+        );
+    }
+
+    #[test]
+    fn geographic_initial_bearing() {
+        struct Test {
+            name: &'static str,
+            from: Geographic<f64>,
+            to: Geographic<f64>,
+            bearing: f64,
+        }
+
+        vec![
+            Test {
+                name: "point due north must have a null bearing",
+                from: Geographic::origin(),
+                to: Geographic::origin().with_latitude(Latitude::from(0.5)),
+                bearing: 0.,
+            },
+            Test {
+                name: "point due east on the equator must have an eastward bearing",
+                from: Geographic::origin(),
+                to: Geographic::origin().with_longitude(Longitude::from(1.)),
+                bearing: FRAC_PI_2,
+            },
+            Test {
+                name: "point due south must have a southward bearing",
+                from: Geographic::origin().with_latitude(Latitude::from(0.5)),
+                to: Geographic::origin(),
+                bearing: PI,
+            },
+            Test {
+                name: "bearing must be the inverse of the destination",
+                from: Geographic::origin()
+                    .with_longitude(Longitude::from(-2.))
+                    .with_latitude(Latitude::from(0.4)),
+                to: Geographic::origin()
+                    .with_longitude(Longitude::from(-2.))
+                    .with_latitude(Latitude::from(0.4))
+                    .destination(4.0.into(), 0.7),
+                bearing: 4.,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let bearing = test.from.initial_bearing(&test.to).into_inner();
+            assert!(
+                (bearing - test.bearing).abs() < 1e-09,
+                "{}: got bearing = {}, want {}",
+                test.name,
+                bearing,
+                test.bearing
+            );
+        });
+    }
+
+    #[test]
+    fn geographic_bearing_field() {
+        let target = Geographic::origin()
+            .with_longitude(Longitude::from(1.))
+            .with_latitude(Latitude::from(0.3));
+
+        let lons = [-0.5, 0., 0.5].map(Longitude::from);
+        let lats = [0.3, -0.2].map(Latitude::from);
+
+        let field = bearing_field(target, &lons, &lats);
+        assert_eq!(field.len(), 6, "got field = {field:?}, want 6 points");
+
+        lats.iter()
+            .flat_map(|latitude| lons.iter().map(move |longitude| (latitude, longitude)))
+            .zip(&field)
+            .for_each(|((&latitude, &longitude), (point, bearing))| {
+                let want = Geographic::origin()
+                    .with_longitude(longitude)
+                    .with_latitude(latitude);
+
+                assert_eq!(*point, want, "got point = {point:?}, want {want:?}");
+                assert_eq!(
+                    *bearing,
+                    want.initial_bearing(&target),
+                    "got bearing of {point:?} = {bearing:?}"
+                );
+            });
+
+        // points due west of the target on its parallel must head east, northing on the way since
+        // the great circle bulges towards the pole.
+        field.iter().take(3).for_each(|(point, bearing)| {
+            let bearing = bearing.into_inner();
+            assert!(
+                bearing > 0. && bearing < FRAC_PI_2,
+                "got bearing of {point:?} = {bearing}, want eastward"
+            );
+        });
+    }
+
+    #[test]
+    fn geographic_longitude_delta() {
+        struct Test {
+            name: &'static str,
+            from: f64,
+            to: f64,
+            delta: f64,
+        }
+
+        vec![
+            Test {
+                name: "eastward difference within the range",
+                from: -0.5,
+                to: 1.,
+                delta: 1.5,
+            },
+            Test {
+                name: "westward difference within the range",
+                from: 1.,
+                to: -0.5,
+                delta: -1.5,
+            },
+            Test {
+                name: "eastward crossing of the antimeridian",
+                from: 170_f64.to_radians(),
+                to: -170_f64.to_radians(),
+                delta: 20_f64.to_radians(),
+            },
+            Test {
+                name: "westward crossing of the antimeridian",
+                from: -170_f64.to_radians(),
+                to: 170_f64.to_radians(),
+                delta: -20_f64.to_radians(),
+            },
+            Test {
+                name: "half turn must be eastward",
+                from: 0.,
+                to: -PI,
+                delta: PI,
+            },
+            Test {
+                name: "same longitude must have no difference",
+                from: 2.,
+                to: 2.,
+                delta: 0.,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let delta = longitude_delta(Longitude::from(test.from), Longitude::from(test.to));
+            assert!(
+                (delta - test.delta).abs() < 1e-09,
+                "{}: got delta = {}, want {}",
+                test.name,
+                delta,
+                test.delta
+            );
+        });
+    }
+
+    #[test]
+    fn geographic_are_cocircular() {
+        struct Test {
+            name: &'static str,
+            points: [Geographic<f64>; 3],
+            cocircular: bool,
+        }
+
+        let equatorial = |lon: f64| Geographic::origin().with_longitude(Longitude::from(lon));
+
+        vec![
+            Test {
+                name: "equatorial points must be cocircular",
+                points: [equatorial(-2.), equatorial(0.3), equatorial(2.5)],
+                cocircular: true,
+            },
+            Test {
+                name: "point off the equator must break cocircularity",
+                points: [
+                    equatorial(-2.),
+                    equatorial(0.3).with_latitude(Latitude::from(0.01)),
+                    equatorial(2.5),
+                ],
+                cocircular: false,
+            },
+            Test {
+                name: "points on a meridian must be cocircular",
+                points: [
+                    equatorial(1.).with_latitude(Latitude::from(-1.)),
+                    equatorial(1.).with_latitude(Latitude::from(FRAC_PI_2)),
+                    equatorial(1. - PI).with_latitude(Latitude::from(0.5)),
+                ],
+                cocircular: true,
+            },
+            Test {
+                name: "coincident points must be cocircular",
+                points: [
+                    equatorial(1.).with_latitude(Latitude::from(0.4)),
+                    equatorial(1.).with_latitude(Latitude::from(0.4)),
+                    equatorial(-2.).with_latitude(Latitude::from(-0.9)),
+                ],
+                cocircular: true,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let [a, b, c] = test.points;
+            let cocircular = are_cocircular(&a, &b, &c, 1e-09);
+
+            assert_eq!(
+                cocircular, test.cocircular,
+                "{}: got cocircular = {}, want {}",
+                test.name, cocircular, test.cocircular
+            );
+        });
+    }
+
+    #[test]
+    fn geographic_weighted_centroid() {
+        let points = [
+            Geographic::origin()
+                .with_longitude(Longitude::from(0.2))
+                .with_latitude(Latitude::from(0.4)),
+            Geographic::origin()
+                .with_longitude(Longitude::from(-0.3))
+                .with_latitude(Latitude::from(0.1)),
+            Geographic::origin()
+                .with_longitude(Longitude::from(0.1))
+                .with_latitude(Latitude::from(-0.5)),
+        ];
+
+        let weighted = |weights: [f64; 3]| {
+            let points: Vec<_> = points.into_iter().zip(weights).collect();
+            weighted_centroid(&points)
+        };
+
+        let unweighted = centroid(&points).unwrap();
+        let got = weighted([2.5; 3]).unwrap();
+        assert!(
+            got.into_cartesian()
+                .normal()
+                .distance(&unweighted.into_cartesian().normal())
+                < 1e-09,
+            "equal weights must match the centroid: got {got:?}, want {unweighted:?}"
+        );
+
+        let got = weighted([100., 1., 1.]).unwrap();
+        assert!(
+            got.distance_meters(&points[0], 1.) < unweighted.distance_meters(&points[0], 1.) / 10.,
+            "dominant weight must pull the centroid towards its point: got {got:?}"
+        );
+
+        let got = weighted([1., 0., 0.]).unwrap();
+        assert!(
+            got.into_cartesian()
+                .normal()
+                .distance(&points[0].into_cartesian().normal())
+                < 1e-09,
+            "null weights must not count: got {got:?}, want {:?}",
+            points[0]
+        );
+
+        assert_eq!(
+            weighted([1., -1., 1.]),
+            None,
+            "negative weights must be rejected"
+        );
+        assert_eq!(
+            weighted([0.; 3]),
+            None,
+            "null weights must have no centroid"
+        );
+        assert_eq!(
+            centroid::<f64>(&[]),
+            None,
+            "no points must have no centroid"
+        );
+        assert_eq!(
+            centroid(&[
+                Geographic::origin(),
+                Geographic::origin().with_longitude(Longitude::from(PI))
+            ]),
+            None,
+            "antipodal points must have no centroid"
+        );
+    }
+
+    #[test]
+    fn geographic_nearest_site() {
+        struct Test {
+            name: &'static str,
+            point: Geographic<f64>,
+            sites: Vec<Geographic<f64>>,
+            nearest: Option<usize>,
+        }
+
+        let sites = vec![
+            Geographic::origin()
+                .with_longitude(Longitude::from(-1.))
+                .with_latitude(Latitude::from(0.5)),
+            Geographic::origin().with_longitude(Longitude::from(1.)),
+            Geographic::origin()
+                .with_longitude(Longitude::from(PI - 0.1))
+                .with_latitude(Latitude::from(-0.3))
+                .with_altitude(Altitude::from(10.)),
+        ];
+
+        vec![
+            Test {
+                name: "point clearly closest to a site must belong to it",
+                point: Geographic::origin()
+                    .with_longitude(Longitude::from(0.8))
+                    .with_latitude(Latitude::from(0.1)),
+                sites: sites.clone(),
+                nearest: Some(1),
+            },
+            Test {
+                name: "site across the antimeridian must be the nearest",
+                point: Geographic::origin()
+                    .with_longitude(Longitude::from(-PI + 0.1))
+                    .with_latitude(Latitude::from(-0.2)),
+                sites,
+                nearest: Some(2),
+            },
+            Test {
+                name: "point equidistant to two sites must belong to the first one",
+                point: Geographic::origin().with_latitude(Latitude::from(0.3)),
+                sites: vec![
+                    Geographic::origin().with_longitude(Longitude::from(0.5)),
+                    Geographic::origin().with_longitude(Longitude::from(-0.5)),
+                ],
+                nearest: Some(0),
+            },
+            Test {
+                name: "no sites must have no nearest",
+                point: Geographic::origin(),
+                sites: vec![],
+                nearest: None,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let nearest = nearest_site(&test.point, &test.sites);
+            let want = test.nearest.map(|index| &test.sites[index]);
+
+            assert_eq!(
+                nearest, want,
+                "{}: got nearest = {:?}, want {:?}",
+                test.name, nearest, want
+            );
+        });
+    }
+
+    #[test]
+    fn geographic_minimum_enclosing_cap() {
+        struct Test {
+            name: &'static str,
+            points: Vec<Geographic<f64>>,
+            cap: Option<(Geographic<f64>, f64)>,
+        }
+
+        let point = |lon: f64, lat: f64| {
+            Geographic::origin()
+                .with_longitude(Longitude::from(lon))
+                .with_latitude(Latitude::from(lat))
+        };
+
+        let (a, b) = (point(-0.4, 0.2), point(0.6, 0.5));
+        let midpoint =
+            Geographic::from((a.into_cartesian().normal() + b.into_cartesian().normal()).normal());
+
+        let cap = Geographic::origin().with_latitude(Latitude::from(0.9));
+        let boundary = |bearing: f64| cap.destination(bearing.into(), 0.3);
+
+        vec![
+            Test {
+                name: "no points must have no cap",
+                points: vec![],
+                cap: None,
+            },
+            Test {
+                name: "single point must have a null radius",
+                points: vec![a],
+                cap: Some((a, 0.)),
+            },
+            Test {
+                name: "two points must be centered at their midpoint",
+                points: vec![a, b],
+                cap: Some((midpoint, a.distance_meters(&b, 1.) / 2.)),
+            },
+            Test {
+                name: "points within the cap of two must not change it",
+                points: vec![a, point(0.1, 0.35), b, point(0.05, 0.3)],
+                cap: Some((midpoint, a.distance_meters(&b, 1.) / 2.)),
+            },
+            Test {
+                name: "boundary points of a cap must yield that cap",
+                points: vec![
+                    cap.destination(1.0.into(), 0.1),
+                    boundary(0.),
+                    cap,
+                    boundary(2.),
+                    boundary(4.2),
+                    cap.destination(3.0.into(), 0.25),
+                ],
+                cap: Some((cap, 0.3)),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let got = minimum_enclosing_cap(&test.points);
+
+            match (got, test.cap) {
+                (Some((center, radius)), Some((want_center, want_radius))) => {
+                    let distance = center
+                        .into_cartesian()
+                        .normal()
+                        .distance(&want_center.into_cartesian().normal());
+
+                    assert!(
+                        distance < 1e-09 && (radius.into_inner() - want_radius).abs() < 1e-09,
+                        "{}: got cap = ({:?}, {:?}), want ({:?}, {})",
+                        test.name,
+                        center,
+                        radius,
+                        want_center,
+                        want_radius
+                    );
+                }
+                (None, None) => {}
+                (got, want) => panic!("{}: got cap = {:?}, want {:?}", test.name, got, want),
+            }
+        });
+    }
+
+    #[test]
+    fn geographic_into_cartesian() {
+        vec![
+            Geographic::origin(),
+            Geographic::origin()
+                .with_longitude(Longitude::from(-2.))
+                .with_latitude(Latitude::from(0.7)),
+            Geographic::origin()
+                .with_longitude(Longitude::from(1.))
+                .with_latitude(Latitude::from(-FRAC_PI_2))
+                .with_altitude(Altitude::from(3.)),
+        ]
+        .into_iter()
+        .for_each(|point| {
+            let want = Cartesian::from(point);
+
+            assert_eq!(
+                point.into_cartesian(),
+                want,
+                "got into cartesian of {point:?} = {:?}, want {want:?}",
+                point.into_cartesian()
+            );
+
+            assert_eq!(
+                point.to_cartesian(),
+                want,
+                "got to cartesian of {point:?} = {:?}, want {want:?}",
+                point.to_cartesian()
+            );
+        });
+    }
+
+    #[test]
+    fn geographic_origin() {
+        let origin = Geographic::<f64>::origin();
+
+        assert_eq!(
+            origin.longitude.into_inner(),
+            0.,
+            "got longitude = {:?}, want 0",
+            origin.longitude
+        );
+
+        assert_eq!(
+            origin.latitude.into_inner(),
+            0.,
+            "got latitude = {:?}, want 0",
+            origin.latitude
+        );
+
+        assert_eq!(
+            origin.altitude.into_inner(),
+            0.,
+            "got altitude = {:?}, want 0",
+            origin.altitude
+        );
+
+        let want = Cartesian::origin().with_x(1.);
+        assert_eq!(
+            origin.into_cartesian(),
+            want,
+            "got cartesian = {:?}, want {want:?}",
+            origin.into_cartesian()
+        );
+    }
+
+    #[test]
+    fn altitude_arithmetic() {
+        struct Test {
+            name: &'static str,
+            got: Altitude<f64>,
+            want: Altitude<f64>,
+        }
+
+        vec![
+            Test {
+                name: "sum of altitudes",
+                got: Altitude::from(100.) + Altitude::from(50.),
+                want: Altitude::from(150.),
+            },
+            Test {
+                name: "subtracting a lower altitude",
+                got: Altitude::from(100.) - Altitude::from(30.),
+                want: Altitude::from(70.),
+            },
+            Test {
+                name: "subtracting a greater altitude saturates at zero",
+                got: Altitude::from(50.) - Altitude::from(100.),
+                want: Altitude::from(0.),
+            },
+            Test {
+                name: "subtracting an equal altitude",
+                got: Altitude::from(50.) - Altitude::from(50.),
+                want: Altitude::from(0.),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            assert_eq!(
+                test.got, test.want,
+                "{}: got altitude = {:?}, want {:?}",
+                test.name, test.got, test.want
+            );
+        });
+    }
+
+    #[test]
+    fn geographic_is_valid() {
+        struct Test {
+            name: &'static str,
+            input: Geographic<f64>,
+            longitude: bool,
+            latitude: bool,
+        }
+
+        vec![
+            Test {
+                name: "in range point must be valid",
+                input: Geographic {
+                    longitude: Longitude(1.),
+                    latitude: Latitude(-0.5),
+                    altitude: Altitude::from(2.),
+                },
+                longitude: true,
+                latitude: true,
+            },
+            Test {
+                name: "antimeridian as positive bound must be invalid",
+                input: Geographic {
+                    longitude: Longitude(PI),
+                    latitude: Latitude(0.),
+                    altitude: Altitude::from(1.),
+                },
+                longitude: false,
+                latitude: true,
+            },
+            Test {
+                name: "out of range point must be invalid",
+                input: Geographic {
+                    longitude: Longitude(100.),
+                    latitude: Latitude(2.),
+                    altitude: Altitude::from(1.),
+                },
+                longitude: false,
+                latitude: false,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let longitude = test.input.longitude.is_valid();
+            assert_eq!(
+                longitude, test.longitude,
+                "{}: got longitude is_valid = {}, want {}",
+                test.name, longitude, test.longitude
+            );
+
+            let latitude = test.input.latitude.is_valid();
+            assert_eq!(
+                latitude, test.latitude,
+                "{}: got latitude is_valid = {}, want {}",
+                test.name, latitude, test.latitude
+            );
+
+            let normalized = test.input.normalized();
+            assert!(
+                normalized.longitude.is_valid() && normalized.latitude.is_valid(),
+                "{}: got normalized = {:?}, want a valid point",
+                test.name,
+                normalized
+            );
+        });
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn geographic_deserialize_must_normalize() {
+        struct Test {
+            name: &'static str,
+            json: &'static str,
+            output: Geographic<f64>,
+        }
+
+        vec![
+            Test {
+                name: "in range point must not change",
+                json: r#"{"longitude":1.0,"latitude":-0.5,"altitude":2.0}"#,
+                output: Geographic::origin()
+                    .with_longitude(Longitude::from(1.))
+                    .with_latitude(Latitude::from(-0.5))
+                    .with_altitude(Altitude::from(2.)),
+            },
+            Test {
+                name: "out of range longitude must be wrapped",
+                json: r#"{"longitude":100.0,"latitude":0.0,"altitude":1.0}"#,
+                output: Geographic::origin()
+                    .with_longitude(Longitude::from(100.))
+                    .with_altitude(Altitude::from(1.)),
+            },
+            Test {
+                name: "antimeridian as positive bound must be canonical",
+                json: r#"{"longitude":3.141592653589793,"latitude":0.0,"altitude":1.0}"#,
+                output: Geographic::origin()
+                    .with_longitude(Longitude::from(-PI))
+                    .with_altitude(Altitude::from(1.)),
+            },
+            Test {
+                name: "out of range latitude and negative altitude must be normalized",
+                json: r#"{"longitude":0.0,"latitude":2.0,"altitude":-3.0}"#,
+                output: Geographic::origin()
+                    .with_latitude(Latitude::from(2.))
+                    .with_altitude(Altitude::from(3.)),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let point: Geographic<f64> = serde_json::from_str(test.json).unwrap();
+
+            assert!(
+                (-PI..PI).contains(&point.longitude.into_inner()),
+                "{}: got longitude = {:?}, want in range [-π, π)",
+                test.name,
+                point.longitude
+            );
+
+            assert_eq!(
+                point, test.output,
+                "{}: got deserialized point = {:?}, want {:?}",
+                test.name, point, test.output
+            );
+        });
+    }
+
+    #[test]
+    fn geographic_slope() {
+        struct Test {
+            name: &'static str,
+            center: Geographic<f64>,
+            east: Geographic<f64>,
+            north: Geographic<f64>,
+            slope: (f64, f64),
+        }
+
+        let radius = 1000.;
+        let step = 0.001;
+        let point = |longitude: f64, latitude: f64, altitude: f64| {
+            Geographic::origin()
+                .with_longitude(Longitude::from(longitude))
+                .with_latitude(Latitude::from(latitude))
+                .with_altitude(Altitude::from(altitude))
+        };
+
+        vec![
+            Test {
+                name: "flat region must have no gradient",
+                center: point(0., 0., 10.),
+                east: point(step, 0., 10.),
+                north: point(0., step, 10.),
+                slope: (0., 0.),
+            },
+            Test {
+                name: "uniformly east-rising region must rise eastwards",
+                center: point(0., 0., 10.),
+                east: point(step, 0., 12.),
+                north: point(0., step, 10.),
+                slope: (2. / (step * radius), 0.),
+            },
+            Test {
+                name: "north-sinking region must sink northwards",
+                center: point(0., 0., 10.),
+                east: point(step, 0., 10.),
+                north: point(0., step, 9.),
+                slope: (0., -1. / (step * radius)),
+            },
+            Test {
+                name: "coincident neighbor must have no gradient",
+                center: point(0., 0., 10.),
+                east: point(0., 0., 12.),
+                north: point(0., step, 10.),
+                slope: (0., 0.),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let slope = slope(&test.center, &test.east, &test.north, radius);
+            let tolerance = 1e-06;
+
+            assert!(
+                (slope.0 - test.slope.0).abs() < tolerance
+                    && (slope.1 - test.slope.1).abs() < tolerance,
+                "{}: got slope = {:?}, want {:?}",
+                test.name,
+                slope,
+                test.slope
+            );
+        });
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn geographic_random() {
+        use rand::{rngs::SmallRng, SeedableRng};
+
+        let mut rng = SmallRng::seed_from_u64(42);
+        let samples = 20_000;
+        let points: Vec<Geographic<f64>> =
+            (0..samples).map(|_| Geographic::random(&mut rng)).collect();
+
+        // the fraction of the sphere between two latitudes is half the difference of their sines,
+        // as given by the integral of the cos(lat) density.
+        let bands = 6;
+        (0..bands).for_each(|band| {
+            let from = -FRAC_PI_2 + PI * band as f64 / bands as f64;
+            let to = from + PI / bands as f64;
+
+            let want = (to.sin() - from.sin()) / 2.;
+            let got = points
+                .iter()
+                .filter(|point| (from..to).contains(&point.latitude.into_inner()))
+                .count() as f64
+                / samples as f64;
+
+            assert!(
+                (got - want).abs() < 0.01,
+                "got fraction of points between latitudes {from} and {to} = {got}, want {want}"
+            );
+        });
+
+        (0..bands).for_each(|band| {
+            let from = -PI + TAU * band as f64 / bands as f64;
+            let to = from + TAU / bands as f64;
+
+            let want = 1. / bands as f64;
+            let got = points
+                .iter()
+                .filter(|point| (from..to).contains(&point.longitude.into_inner()))
+                .count() as f64
+                / samples as f64;
+
+            assert!(
+                (got - want).abs() < 0.01,
+                "got fraction of points between longitudes {from} and {to} = {got}, want {want}"
+            );
+        });
+    }
+
+    #[test]
+    fn geographic_rhumb_destination() {
+        struct Test {
+            name: &'static str,
+            from: Geographic<f64>,
+            bearing: f64,
+            distance: f64,
+            output: Geographic<f64>,
+        }
+
+        vec![
+            Test {
+                name: "heading east along a parallel must keep the latitude",
+                from: Geographic::origin()
+                    .with_longitude(Longitude::from(0.2))
+                    .with_latitude(Latitude::from(0.7)),
+                bearing: FRAC_PI_2,
+                distance: 0.3,
+                output: Geographic::origin()
+                    .with_longitude(Longitude::from(0.2 + 0.3 / 0.7_f64.cos()))
+                    .with_latitude(Latitude::from(0.7)),
+            },
+            Test {
+                name: "heading west across the antimeridian must wrap the longitude",
+                from: Geographic::origin()
+                    .with_longitude(Longitude::from(-PI + 0.1))
+                    .with_latitude(Latitude::from(-0.5)),
+                bearing: 3. * FRAC_PI_2,
+                distance: 0.2,
+                output: Geographic::origin()
+                    .with_longitude(Longitude::from(PI + 0.1 - 0.2 / 0.5_f64.cos()))
+                    .with_latitude(Latitude::from(-0.5)),
+            },
+            Test {
+                name: "heading north must stay on the meridian",
+                from: Geographic::origin().with_longitude(Longitude::from(1.)),
+                bearing: 0.,
+                distance: 0.5,
+                output: Geographic::origin()
+                    .with_longitude(Longitude::from(1.))
+                    .with_latitude(Latitude::from(0.5)),
+            },
+            Test {
+                name: "oblique bearing must reach the rhumb midpoint halfway",
+                from: Geographic::origin()
+                    .with_longitude(Longitude::from(-0.3))
+                    .with_latitude(Latitude::from(0.1)),
+                bearing: FRAC_PI_4,
+                distance: 0.4,
+                output: {
+                    let from = Geographic::origin()
+                        .with_longitude(Longitude::from(-0.3))
+                        .with_latitude(Latitude::from(0.1));
+
+                    from.rhumb_midpoint(&from.rhumb_destination(FRAC_PI_4.into(), 0.8))
+                },
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let destination = test
+                .from
+                .rhumb_destination(test.bearing.into(), test.distance);
+
+            assert!(
+                destination
+                    .longitude
+                    .approx_eq(&test.output.longitude, Tolerance::default())
+                    && (destination.latitude.into_inner() - test.output.latitude.into_inner())
+                        .abs()
+                        < 1e-09,
+                "{}: got rhumb destination = {:?}, want {:?}",
+                test.name,
+                destination,
+                test.output
+            );
+        });
+
+        let overshoot = Geographic::origin()
+            .with_longitude(Longitude::from(0.5))
+            .with_latitude(Latitude::from(1.4))
+            .rhumb_destination(FRAC_PI_4.into(), 1.);
+
+        assert!(
+            (overshoot.latitude.into_inner() - FRAC_PI_2).abs() < 1e-09
+                && overshoot.longitude.into_inner().is_finite(),
+            "heading north east beyond the pole must end at it: got {overshoot:?}"
+        );
+    }
+
+    #[test]
+    fn geographic_from_cartesian_many() {
+        let points: Vec<Cartesian<f64>> = [
+            (0., 0., 0.),
+            (1., 0., 0.),
+            (-0.5, 0.25, 0.8),
+            (0., 0., -2.),
+            (3., -4., 1.),
+        ]
+        .into_iter()
+        .map(|(x, y, z)| Cartesian::origin().with_x(x).with_y(y).with_z(z))
+        .collect();
+
+        let want: Vec<Geographic<f64>> = points.iter().copied().map(Geographic::from).collect();
+
+        let got = Geographic::from_cartesian_many(&points);
+        assert_eq!(got, want, "got batch = {got:?}, want {want:?}");
+
+        let mut buffer = vec![Geographic::origin(); 8];
+        Geographic::from_cartesian_many_into(&points, &mut buffer);
+        assert_eq!(buffer, want, "got buffer = {buffer:?}, want {want:?}");
+
+        let got = Geographic::<f64>::from_cartesian_many(&[]);
+        assert!(
+            got.is_empty(),
+            "got batch of no points = {got:?}, want empty"
+        );
+    }
+
+    #[test]
+    fn geographic_altitude_model_chaining() {
+        let radius = Geographic::<f64>::EARTH_MEAN_RADIUS_M;
+        let point = Geographic::origin()
+            .with_longitude(Longitude::from(0.3))
+            .with_latitude(Latitude::from(-0.8));
+
+        let (surface, above) = (
+            point.at_height_above(radius, 0.),
+            point.at_height_above(radius, 1_000.),
+        );
+
+        let distance = above.into_cartesian().distance(&surface.into_cartesian());
+        assert!(
+            (distance - 1_000.).abs() < 1e-06,
+            "got distance = {distance}, want the height of 1000"
+        );
+
+        let by_height = point
+            .with_altitude(Altitude::from(1_000.))
+            .distance_3d(&point, radius);
+        assert!(
+            (by_height - distance).abs() < 1e-06,
+            "got distance_3d by height = {by_height}, want {distance}"
+        );
+
+        let (_, height) = Geographic::from_cartesian_with_radius(above.into_cartesian(), radius);
+        assert!(
+            (height - 1_000.).abs() < 1e-06,
+            "got height from cartesian = {height}, want 1000"
+        );
+
+        let projected = Equirectangular::default()
+            .with_radius(radius.into())
+            .forward_3d(&above, radius);
+        assert!(
+            (projected.z - 1_000.).abs() < 1e-06,
+            "got projected z = {}, want 1000",
+            projected.z
+        );
+    }
+
+    #[test]
+    fn longitude_canonical() {
+        struct Test {
+            name: &'static str,
+            input: Longitude<f64>,
+            output: f64,
+        }
+
+        vec![
+            Test {
+                name: "positive pi must be the antimeridian",
+                input: Longitude(PI),
+                output: -PI,
+            },
+            Test {
+                name: "value in range must not change",
+                input: Longitude(1.),
+                output: 1.,
+            },
+            Test {
+                name: "value above pi must wrap around the antimeridian",
+                input: Longitude(4.),
+                output: 4. - TAU,
+            },
+            Test {
+                name: "value below negative pi must wrap around the antimeridian",
+                input: Longitude(-4.),
+                output: TAU - 4.,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let canonical = test.input.canonical();
+            assert!(
+                (canonical.into_inner() - test.output).abs() < 1e-09 && canonical.is_valid(),
+                "{}: got canonical = {:?}, want {}",
+                test.name,
+                canonical,
+                test.output
+            );
+        });
+    }
 }
@@ -0,0 +1,300 @@
+//! KD-tree spatial index.
+
+use alloc::vec::Vec;
+
+use num_traits::{Float, FloatConst, Signed};
+
+use crate::{Cartesian, Geographic};
+
+/// A [k-d tree](https://en.wikipedia.org/wiki/K-d_tree) over geographic points, for fast nearest
+/// neighbor and range queries.
+///
+/// ## Definition
+/// Points are indexed by their position on the unit sphere, regardless of their altitude. Since
+/// the length of the chord between two points of the sphere grows monotonically with the angle
+/// between them, the closest point in the space is also the closest one along the great circle.
+///
+/// The tree is not rebalanced on insertion, hence inserting points in a sorted order degrades its
+/// performance.
+#[derive(Debug, Clone)]
+pub struct KdTree<T> {
+    nodes: Vec<Node<T>>,
+}
+
+#[derive(Debug, Clone)]
+struct Node<T> {
+    point: Geographic<T>,
+    position: Cartesian<T>,
+    left: Option<usize>,
+    right: Option<usize>,
+}
+
+impl<T> Default for KdTree<T> {
+    fn default() -> Self {
+        Self { nodes: Vec::new() }
+    }
+}
+
+impl<T> FromIterator<Geographic<T>> for KdTree<T>
+where
+    T: Signed + Float + FloatConst,
+{
+    fn from_iter<I: IntoIterator<Item = Geographic<T>>>(iter: I) -> Self {
+        let mut tree = Self::default();
+        iter.into_iter().for_each(|point| tree.insert(point));
+        tree
+    }
+}
+
+impl<T> KdTree<T>
+where
+    T: Signed + Float + FloatConst,
+{
+    /// Adds the given point to the index.
+    pub fn insert(&mut self, point: Geographic<T>) {
+        let position = point.into_cartesian().normal();
+        let index = self.nodes.len();
+
+        let mut parent = None;
+        let mut current = (!self.nodes.is_empty()).then_some(0);
+        let mut depth = 0;
+
+        while let Some(node) = current {
+            let axis = depth % 3;
+            let goes_left =
+                coordinate(&position, axis) < coordinate(&self.nodes[node].position, axis);
+
+            parent = Some((node, goes_left));
+            current = if goes_left {
+                self.nodes[node].left
+            } else {
+                self.nodes[node].right
+            };
+
+            depth += 1;
+        }
+
+        match parent {
+            Some((node, true)) => self.nodes[node].left = Some(index),
+            Some((node, false)) => self.nodes[node].right = Some(index),
+            None => {}
+        }
+
+        self.nodes.push(Node {
+            point,
+            position,
+            left: None,
+            right: None,
+        });
+    }
+
+    /// Returns the indexed point closest to the given query along the great circle, or [`None`]
+    /// if the index is empty.
+    pub fn nearest(&self, query: &Geographic<T>) -> Option<Geographic<T>> {
+        let position = query.into_cartesian().normal();
+
+        let mut nearest = None;
+        self.search_nearest(0, 0, &position, &mut nearest);
+        nearest.map(|(index, _)| self.nodes[index].point)
+    }
+
+    /// Returns all the indexed points whose angular distance (in radians) to the given query is
+    /// not greater than the given radius.
+    pub fn within(&self, query: &Geographic<T>, angular_radius: T) -> Vec<Geographic<T>> {
+        let position = query.into_cartesian().normal();
+
+        // the chord subtending an angle θ in the unit circle has a length of 2·sin(θ/2).
+        let two = T::one() + T::one();
+        let chord = if angular_radius >= T::PI() {
+            two
+        } else {
+            two * (angular_radius / two).sin()
+        };
+
+        let mut found = Vec::new();
+        self.search_within(0, 0, &position, chord * chord, &mut found);
+        found
+    }
+
+    fn search_nearest(
+        &self,
+        node: usize,
+        depth: usize,
+        position: &Cartesian<T>,
+        nearest: &mut Option<(usize, T)>,
+    ) {
+        let Some(current) = self.nodes.get(node) else {
+            return;
+        };
+
+        let distance = squared_distance(&current.position, position);
+        if nearest.is_none_or(|(_, min)| distance < min) {
+            *nearest = Some((node, distance));
+        }
+
+        let axis = depth % 3;
+        let diff = coordinate(position, axis) - coordinate(&current.position, axis);
+        let (near, far) = if diff < T::zero() {
+            (current.left, current.right)
+        } else {
+            (current.right, current.left)
+        };
+
+        if let Some(near) = near {
+            self.search_nearest(near, depth + 1, position, nearest);
+        }
+
+        // the far side may only hold a closer point if the splitting plane is closer than the
+        // nearest point found so far.
+        if let Some(far) = far {
+            if nearest.is_none_or(|(_, min)| diff * diff < min) {
+                self.search_nearest(far, depth + 1, position, nearest);
+            }
+        }
+    }
+
+    fn search_within(
+        &self,
+        node: usize,
+        depth: usize,
+        position: &Cartesian<T>,
+        squared_radius: T,
+        found: &mut Vec<Geographic<T>>,
+    ) {
+        let Some(current) = self.nodes.get(node) else {
+            return;
+        };
+
+        if squared_distance(&current.position, position) <= squared_radius {
+            found.push(current.point);
+        }
+
+        let axis = depth % 3;
+        let diff = coordinate(position, axis) - coordinate(&current.position, axis);
+        let (near, far) = if diff < T::zero() {
+            (current.left, current.right)
+        } else {
+            (current.right, current.left)
+        };
+
+        if let Some(near) = near {
+            self.search_within(near, depth + 1, position, squared_radius, found);
+        }
+
+        if let Some(far) = far {
+            if diff * diff <= squared_radius {
+                self.search_within(far, depth + 1, position, squared_radius, found);
+            }
+        }
+    }
+}
+
+impl<T> KdTree<T> {
+    /// Returns the amount of indexed points.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns true if, and only if, there are no indexed points.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+}
+
+/// Returns the coordinate of the given point along the given axis.
+fn coordinate<T: Copy>(point: &Cartesian<T>, axis: usize) -> T {
+    match axis {
+        0 => point.x,
+        1 => point.y,
+        _ => point.z,
+    }
+}
+
+fn squared_distance<T: Float>(lhs: &Cartesian<T>, rhs: &Cartesian<T>) -> T {
+    let diff = *lhs - *rhs;
+    diff.dot(&diff)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{PI, TAU};
+
+    use crate::{
+        distance::{nearest, Distance},
+        index::KdTree,
+        Geographic, Latitude, Longitude,
+    };
+
+    /// Returns a deterministic sequence of pseudo-random points.
+    fn random_points(seed: u64, count: usize) -> Vec<Geographic<f64>> {
+        let mut state = seed;
+        let mut next = move || {
+            // linear congruential generator from Knuth's MMIX.
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state >> 11) as f64 / (1u64 << 53) as f64
+        };
+
+        (0..count)
+            .map(|_| {
+                Geographic::origin()
+                    .with_longitude(Longitude::from(next() * TAU - PI))
+                    .with_latitude(Latitude::from((next() * 2. - 1.).asin()))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn kdtree_nearest_must_match_brute_force() {
+        let points = random_points(7, 1000);
+        let tree: KdTree<f64> = points.iter().copied().collect();
+
+        assert_eq!(tree.len(), points.len());
+        assert_eq!(
+            KdTree::<f64>::default().nearest(&Geographic::origin()),
+            None
+        );
+
+        random_points(42, 200).into_iter().for_each(|query| {
+            let got = tree.nearest(&query);
+            let want = nearest(&query, &points).copied();
+
+            assert_eq!(
+                got, want,
+                "got nearest = {got:?}, want {want:?} for query {query:?}"
+            );
+        });
+    }
+
+    #[test]
+    fn kdtree_within_must_match_brute_force() {
+        let points = random_points(11, 1000);
+        let tree: KdTree<f64> = points.iter().copied().collect();
+
+        [0., 0.05, 0.3, 1., PI].into_iter().for_each(|radius| {
+            random_points(3, 50).into_iter().for_each(|query| {
+                let mut got = tree.within(&query, radius);
+                let mut want: Vec<_> = points
+                    .iter()
+                    .filter(|point| Distance::distance(&query, *point) <= radius)
+                    .copied()
+                    .collect();
+
+                let by_position = |a: &Geographic<f64>, b: &Geographic<f64>| {
+                    (a.longitude, a.latitude)
+                        .partial_cmp(&(b.longitude, b.latitude))
+                        .expect("points must be comparable")
+                };
+
+                got.sort_by(by_position);
+                want.sort_by(by_position);
+
+                assert_eq!(
+                    got, want,
+                    "got within = {got:?}, want {want:?} for query {query:?} and radius {radius}"
+                );
+            });
+        });
+    }
+}
@@ -0,0 +1,5 @@
+//! Spatial index implementations.
+
+mod kdtree;
+
+pub use self::kdtree::KdTree;
@@ -1,10 +1,29 @@
-mod cartesian;
-mod geographic;
+#![cfg_attr(all(not(feature = "std"), not(test)), no_std)]
+
+#[cfg(not(any(feature = "std", feature = "no_std")))]
+compile_error!("either the `std` or the `no_std` feature must be enabled");
+
+extern crate alloc;
+
+mod ellipsoid;
 mod positive;
 mod radian;
+mod tolerance;
+mod vector;
 
+pub mod cartesian;
+pub mod distance;
+pub mod geographic;
+pub mod index;
+pub mod projection;
+pub mod render;
 pub mod shape;
 pub mod transform;
 
 pub use cartesian::Cartesian;
+pub use ellipsoid::Ellipsoid;
 pub use geographic::{Altitude, Geographic, Latitude, Longitude};
+pub use positive::Positive;
+pub use radian::Radian;
+pub use tolerance::Tolerance;
+pub use vector::Vector;
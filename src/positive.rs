@@ -1,10 +1,16 @@
 //! Positive definition.
 
+use core::ops::Add;
+
 use num_traits::Signed;
 
 /// A value that is always positive.
 #[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(from = "T", bound(deserialize = "T: serde::Deserialize<'de> + Signed"))
+)]
 pub struct Positive<T>(T);
 
 impl<T> AsRef<T> for Positive<T> {
@@ -22,6 +28,18 @@ where
     }
 }
 
+impl<T> Add for Positive<T>
+where
+    T: Add<Output = T>,
+{
+    type Output = Self;
+
+    /// The sum of two positive values is always positive, hence no normalization is required.
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
 impl<T> Eq for Positive<T> where T: PartialEq {}
 
 impl<T> Positive<T> {
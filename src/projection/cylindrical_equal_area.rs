@@ -0,0 +1,291 @@
+//! Cylindrical equal-area projection.
+
+use alloc::{format, string::String};
+use core::fmt::Display;
+
+use num_traits::{Euclid, Float, FloatConst, Signed};
+
+use crate::{positive::Positive, Cartesian, Geographic, Latitude};
+
+use super::Projection;
+
+/// Implements the [cylindrical equal-area projection](https://en.wikipedia.org/wiki/Cylindrical_equal-area_projection),
+/// whose parallels are stretched or compressed so that areas are preserved, being undistorted
+/// along the standard parallels.
+///
+/// By default the standard parallels are at 45°, which is the [Gall–Peters
+/// projection](https://en.wikipedia.org/wiki/Gall%E2%80%93Peters_projection). The standard
+/// parallel only changes the aspect ratio of the projected world, which is π·cos²(φs).
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(deserialize = "T: serde::Deserialize<'de> + Signed + Float + FloatConst"))
+)]
+pub struct CylindricalEqualArea<T> {
+    /// The radius of the projected sphere.
+    pub radius: Positive<T>,
+    /// The latitude (north or south) along which the projection has no distortion.
+    pub standard_parallel: Latitude<T>,
+    /// The value added to the x coordinate of every projected point.
+    #[cfg_attr(feature = "serde", serde(default = "num_traits::Zero::zero"))]
+    pub false_easting: T,
+    /// The value added to the y coordinate of every projected point.
+    #[cfg_attr(feature = "serde", serde(default = "num_traits::Zero::zero"))]
+    pub false_northing: T,
+}
+
+impl<T> Default for CylindricalEqualArea<T>
+where
+    T: Signed + Float + FloatConst,
+{
+    fn default() -> Self {
+        Self {
+            radius: T::one().into(),
+            standard_parallel: T::FRAC_PI_4().into(),
+            false_easting: T::zero(),
+            false_northing: T::zero(),
+        }
+    }
+}
+
+impl<T> Projection<T> for CylindricalEqualArea<T>
+where
+    T: PartialOrd + Signed + Float + FloatConst + Euclid,
+{
+    fn forward(&self, coords: &Geographic<T>) -> Cartesian<T> {
+        let cos_standard = self.standard_parallel.into_inner().cos();
+
+        Cartesian::origin()
+            .with_x(
+                self.radius.into_inner() * coords.longitude.into_inner() * cos_standard
+                    + self.false_easting,
+            )
+            .with_y(
+                self.radius.into_inner() * coords.latitude.into_inner().sin() / cos_standard
+                    + self.false_northing,
+            )
+    }
+
+    fn reverse(&self, coords: &Cartesian<T>) -> Geographic<T> {
+        let cos_standard = self.standard_parallel.into_inner().cos();
+        let x = coords.x - self.false_easting;
+        let y = coords.y - self.false_northing;
+
+        Geographic::origin()
+            .with_longitude((x / (self.radius.into_inner() * cos_standard)).into())
+            .with_latitude(
+                (y * cos_standard / self.radius.into_inner())
+                    .max(-T::one())
+                    .min(T::one())
+                    .asin()
+                    .into(),
+            )
+    }
+}
+
+impl<T> CylindricalEqualArea<T> {
+    pub fn with_radius(self, radius: Positive<T>) -> Self {
+        Self { radius, ..self }
+    }
+
+    pub fn with_standard_parallel(self, standard_parallel: Latitude<T>) -> Self {
+        Self {
+            standard_parallel,
+            ..self
+        }
+    }
+
+    pub fn with_false_easting(self, false_easting: T) -> Self {
+        Self {
+            false_easting,
+            ..self
+        }
+    }
+
+    pub fn with_false_northing(self, false_northing: T) -> Self {
+        Self {
+            false_northing,
+            ..self
+        }
+    }
+}
+
+impl<T> CylindricalEqualArea<T>
+where
+    T: Float + Display,
+{
+    /// Returns the [PROJ](https://proj.org/) string describing self, as parsed by
+    /// [`from_proj_string`](super::from_proj_string).
+    pub fn to_proj_string(&self) -> String {
+        format!(
+            "+proj=cea +R={} +lat_ts={} +x_0={} +y_0={}",
+            self.radius.into_inner(),
+            self.standard_parallel.into_inner().to_degrees(),
+            self.false_easting,
+            self.false_northing
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI, SQRT_2};
+
+    use crate::{
+        projection::{CylindricalEqualArea, Projection},
+        Cartesian, Geographic, Latitude, Longitude, Tolerance,
+    };
+
+    #[test]
+    fn cylindrical_equal_area_projection() {
+        struct Test {
+            name: &'static str,
+            projection: CylindricalEqualArea<f64>,
+            geographic: Geographic<f64>,
+            cartesian: Cartesian<f64>,
+        }
+
+        vec![
+            Test {
+                name: "origin must be projected to the cartesian origin",
+                projection: CylindricalEqualArea::default(),
+                geographic: Geographic::origin(),
+                cartesian: Cartesian::origin(),
+            },
+            Test {
+                name: "gall-peters must scale both axes by the standard parallel",
+                projection: CylindricalEqualArea::default().with_radius(2.0.into()),
+                geographic: Geographic::origin()
+                    .with_longitude(Longitude::from(-1.))
+                    .with_latitude(Latitude::from(FRAC_PI_2)),
+                cartesian: Cartesian::origin().with_x(-SQRT_2).with_y(2. * SQRT_2),
+            },
+            Test {
+                name: "equatorial standard parallel must be the lambert projection",
+                projection: CylindricalEqualArea::default()
+                    .with_standard_parallel(Latitude::from(0.)),
+                geographic: Geographic::origin()
+                    .with_longitude(Longitude::from(2.))
+                    .with_latitude(Latitude::from(0.5)),
+                cartesian: Cartesian::origin().with_x(2.).with_y(0.5_f64.sin()),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let tolerance = 1e-09;
+
+            let forward = test.projection.forward(&test.geographic);
+            forward
+                .into_iter()
+                .zip(test.cartesian)
+                .for_each(|(got, want)| {
+                    assert!(
+                        (got - want).abs() < tolerance,
+                        "{}: got forward = {:?}, want {:?}",
+                        test.name,
+                        forward,
+                        test.cartesian
+                    );
+                });
+
+            let reverse = test.projection.reverse(&test.cartesian);
+            assert!(
+                (reverse.longitude.into_inner() - test.geographic.longitude.into_inner()).abs()
+                    < tolerance
+                    && (reverse.latitude.into_inner() - test.geographic.latitude.into_inner())
+                        .abs()
+                        < tolerance,
+                "{}: got reverse = {:?}, want {:?}",
+                test.name,
+                reverse,
+                test.geographic
+            );
+        });
+    }
+
+    #[test]
+    fn cylindrical_equal_area_must_preserve_areas() {
+        struct Test {
+            name: &'static str,
+            standard_parallel: f64,
+            aspect_ratio: f64,
+        }
+
+        vec![
+            Test {
+                name: "gall-peters",
+                standard_parallel: FRAC_PI_4,
+                aspect_ratio: PI / 2.,
+            },
+            Test {
+                name: "lambert",
+                standard_parallel: 0.,
+                aspect_ratio: PI,
+            },
+            Test {
+                name: "behrmann",
+                standard_parallel: 30_f64.to_radians(),
+                aspect_ratio: PI * 0.75,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let radius = 3.;
+            let projection = CylindricalEqualArea::default()
+                .with_radius(radius.into())
+                .with_standard_parallel(Latitude::from(test.standard_parallel));
+
+            // the area of a cell between two meridians and two parallels on the sphere.
+            let (west, east, south, north): (f64, f64, f64, f64) = (-0.3, 0.5, 0.2, 1.1);
+            let want = radius * radius * (east - west) * (north.sin() - south.sin());
+
+            let corner = |lon: f64, lat: f64| {
+                projection.forward(
+                    &Geographic::origin()
+                        .with_longitude(Longitude::from(lon))
+                        .with_latitude(Latitude::from(lat)),
+                )
+            };
+
+            let (bottom_left, top_right) = (corner(west, south), corner(east, north));
+            let got = (top_right.x - bottom_left.x) * (top_right.y - bottom_left.y);
+
+            assert!(
+                (got - want).abs() < 1e-09,
+                "{}: got area = {}, want {}",
+                test.name,
+                got,
+                want
+            );
+
+            let (min, max) = (corner(-PI, -FRAC_PI_2), corner(PI - 1e-12, FRAC_PI_2));
+            let aspect_ratio = (max.x - min.x) / (max.y - min.y);
+
+            assert!(
+                (aspect_ratio - test.aspect_ratio).abs() < 1e-09,
+                "{}: got aspect ratio = {}, want {}",
+                test.name,
+                aspect_ratio,
+                test.aspect_ratio
+            );
+
+            (-4..=4).for_each(|lat| {
+                let point = Geographic::origin()
+                    .with_longitude(Longitude::from(lat as f64 * 0.7))
+                    .with_latitude(Latitude::from(lat as f64 * 0.35));
+
+                let got = projection.reverse(&projection.forward(&point));
+                assert!(
+                    got.longitude
+                        .approx_eq(&point.longitude, Tolerance::default())
+                        && (got.latitude.into_inner() - point.latitude.into_inner()).abs() < 1e-09,
+                    "{}: got round trip = {:?}, want {:?}",
+                    test.name,
+                    got,
+                    point
+                );
+            });
+        });
+    }
+}
@@ -0,0 +1,423 @@
+//! Equirectangular projection.
+
+use alloc::{format, string::String};
+use core::fmt::Display;
+
+use num_traits::{Euclid, Float, FloatConst, Signed};
+
+use crate::{positive::Positive, Cartesian, Geographic, Latitude};
+
+use super::{OutOfDomain, Projection};
+
+/// Implements the [equirectangular projection](https://en.wikipedia.org/wiki/Equirectangular_projection),
+/// which maps meridians and parallels to equally spaced vertical and horizontal straight lines.
+///
+/// ## Standard parallel
+/// Meridians are spaced by the cosine of the standard parallel, along which the projection has no
+/// distortion. By default that is the equator, which makes it the [Plate
+/// Carrée](https://en.wikipedia.org/wiki/Equirectangular_projection#Plate_carr%C3%A9e) projection,
+/// with no scaling at all.
+///
+/// ## Domain
+/// The projected world is the rectangle of width 2π·cos(φs) and height π times the radius,
+/// centered at the false origin. The lenient [`Projection::reverse`] accepts any point,
+/// normalizing its coordinates as [`Longitude`](crate::Longitude) and
+/// [`Latitude`](crate::Latitude) do: longitudes wrap around the antimeridian, while latitudes
+/// beyond a pole are folded back as if travelling over it (so a point just above the top of the
+/// map goes back below it, instead of being clamped at the pole). Use [`Projection::try_reverse`]
+/// to reject the points out of the rectangle instead.
+///
+/// ## Example
+/// ```
+/// use std::f64::consts::FRAC_PI_2;
+///
+/// use geocart::{
+///     projection::{Equirectangular, Projection},
+///     Cartesian, Geographic, Latitude,
+/// };
+///
+/// let projected = Equirectangular::default()
+///     .forward(&Geographic::origin().with_latitude(Latitude::from(FRAC_PI_2)));
+///
+/// assert_eq!(projected, Cartesian::origin().with_y(FRAC_PI_2));
+/// ```
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        deserialize = "T: serde::Deserialize<'de> + Default + Signed + Float + FloatConst"
+    ))
+)]
+pub struct Equirectangular<T> {
+    /// The radius of the projected sphere.
+    pub radius: Positive<T>,
+    /// The latitude (north or south) along which the projection has no distortion.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub standard_parallel: Latitude<T>,
+    /// The value added to the x coordinate of every projected point.
+    #[cfg_attr(feature = "serde", serde(default = "num_traits::Zero::zero"))]
+    pub false_easting: T,
+    /// The value added to the y coordinate of every projected point.
+    #[cfg_attr(feature = "serde", serde(default = "num_traits::Zero::zero"))]
+    pub false_northing: T,
+}
+
+/// The [Plate Carrée](https://en.wikipedia.org/wiki/Equirectangular_projection#Plate_carr%C3%A9e)
+/// projection, which is the [`Equirectangular`] one with its default standard parallel: the
+/// equator.
+pub type PlateCarree<T> = Equirectangular<T>;
+
+impl<T> Default for Equirectangular<T>
+where
+    T: Signed,
+{
+    fn default() -> Self {
+        Self {
+            radius: T::one().into(),
+            standard_parallel: Latitude::equator(),
+            false_easting: T::zero(),
+            false_northing: T::zero(),
+        }
+    }
+}
+
+impl<T> Projection<T> for Equirectangular<T>
+where
+    T: PartialOrd + Signed + Float + FloatConst + Euclid,
+{
+    fn forward(&self, coords: &Geographic<T>) -> Cartesian<T> {
+        Cartesian::origin()
+            .with_x(
+                self.radius.into_inner()
+                    * coords.longitude.into_inner()
+                    * self.standard_parallel.into_inner().cos()
+                    + self.false_easting,
+            )
+            .with_y(self.radius.into_inner() * coords.latitude.into_inner() + self.false_northing)
+    }
+
+    fn reverse(&self, coords: &Cartesian<T>) -> Geographic<T> {
+        let x = coords.x - self.false_easting;
+        let y = coords.y - self.false_northing;
+
+        Geographic::origin()
+            .with_longitude(
+                (x / (self.radius.into_inner() * self.standard_parallel.into_inner().cos())).into(),
+            )
+            .with_latitude((y / self.radius.into_inner()).into())
+    }
+
+    fn try_reverse(&self, coords: &Cartesian<T>) -> Result<Geographic<T>, OutOfDomain> {
+        let x = coords.x - self.false_easting;
+        let y = coords.y - self.false_northing;
+
+        if x.abs() > self.radius.into_inner() * T::PI() * self.standard_parallel.into_inner().cos()
+            || y.abs() > self.radius.into_inner() * T::FRAC_PI_2()
+        {
+            return Err(OutOfDomain);
+        }
+
+        Ok(self.reverse(coords))
+    }
+}
+
+impl<T> Equirectangular<T> {
+    pub fn with_radius(self, radius: Positive<T>) -> Self {
+        Self { radius, ..self }
+    }
+
+    pub fn with_standard_parallel(self, standard_parallel: Latitude<T>) -> Self {
+        Self {
+            standard_parallel,
+            ..self
+        }
+    }
+
+    pub fn with_false_easting(self, false_easting: T) -> Self {
+        Self {
+            false_easting,
+            ..self
+        }
+    }
+
+    pub fn with_false_northing(self, false_northing: T) -> Self {
+        Self {
+            false_northing,
+            ..self
+        }
+    }
+}
+
+impl<T> Equirectangular<T>
+where
+    T: Float + Display,
+{
+    /// Returns the [PROJ](https://proj.org/) string describing self, as parsed by
+    /// [`from_proj_string`](super::from_proj_string).
+    pub fn to_proj_string(&self) -> String {
+        format!(
+            "+proj=eqc +R={} +lat_ts={} +x_0={} +y_0={}",
+            self.radius.into_inner(),
+            self.standard_parallel.into_inner().to_degrees(),
+            self.false_easting,
+            self.false_northing
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    use crate::{
+        projection::{Equirectangular, OutOfDomain, Projection},
+        Altitude, Cartesian, Geographic, Latitude, Longitude, Tolerance,
+    };
+
+    #[test]
+    fn equirectangular_projection() {
+        struct Test {
+            name: &'static str,
+            projection: Equirectangular<f64>,
+            geographic: Geographic<f64>,
+            cartesian: Cartesian<f64>,
+        }
+
+        vec![
+            Test {
+                name: "origin must be projected to the cartesian origin",
+                projection: Equirectangular::default(),
+                geographic: Geographic::origin(),
+                cartesian: Cartesian::origin(),
+            },
+            Test {
+                name: "north pole must be projected to the top boundary",
+                projection: Equirectangular::default(),
+                geographic: Geographic::origin().with_latitude(Latitude::from(FRAC_PI_2)),
+                cartesian: Cartesian::origin().with_y(FRAC_PI_2),
+            },
+            Test {
+                name: "antimeridian must be projected to the left boundary",
+                projection: Equirectangular::default(),
+                geographic: Geographic::origin().with_longitude(Longitude::from(-PI)),
+                cartesian: Cartesian::origin().with_x(-PI),
+            },
+            Test {
+                name: "radius must scale the projected point",
+                projection: Equirectangular::default().with_radius(2.0.into()),
+                geographic: Geographic::origin()
+                    .with_longitude(Longitude::from(1.))
+                    .with_latitude(Latitude::from(-1.)),
+                cartesian: Cartesian::origin().with_x(2.).with_y(-2.),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let forward = test.projection.forward(&test.geographic);
+            assert_eq!(
+                forward, test.cartesian,
+                "{}: got forward = {:?}, want {:?}",
+                test.name, forward, test.cartesian
+            );
+
+            let reverse = test.projection.reverse(&test.cartesian);
+            assert_eq!(
+                reverse, test.geographic,
+                "{}: got reverse = {:?}, want {:?}",
+                test.name, reverse, test.geographic
+            );
+        });
+    }
+
+    #[test]
+    fn equirectangular_projection_f32() {
+        use std::f32::consts::FRAC_PI_2;
+
+        let projection = Equirectangular::default().with_radius(2_f32.into());
+        let geographic = Geographic::origin()
+            .with_longitude(Longitude::from(-1.))
+            .with_latitude(Latitude::from(FRAC_PI_2));
+
+        let forward = projection.forward(&geographic);
+        let want = Cartesian::origin().with_x(-2.).with_y(2. * FRAC_PI_2);
+        assert_eq!(forward, want, "got forward = {forward:?}, want {want:?}");
+
+        let reverse = projection.reverse(&forward);
+        assert_eq!(
+            reverse, geographic,
+            "got reverse = {reverse:?}, want {geographic:?}"
+        );
+    }
+
+    #[test]
+    fn equirectangular_forward_3d() {
+        struct Test {
+            name: &'static str,
+            input: Geographic<f64>,
+            surface_radius: f64,
+            output: Cartesian<f64>,
+        }
+
+        vec![
+            Test {
+                name: "point with no altitude must stay on the unit plane",
+                input: Geographic::origin().with_longitude(Longitude::from(1.)),
+                surface_radius: 1.,
+                output: Cartesian::origin().with_x(2.),
+            },
+            Test {
+                name: "point at the surface must stay on the plane",
+                input: Geographic::origin()
+                    .with_longitude(Longitude::from(1.))
+                    .at_height_above(2., 0.),
+                surface_radius: 2.,
+                output: Cartesian::origin().with_x(2.),
+            },
+            Test {
+                name: "point above the surface must have a positive z",
+                input: Geographic::origin()
+                    .with_latitude(Latitude::from(-0.5))
+                    .at_height_above(2., 0.25),
+                surface_radius: 2.,
+                output: Cartesian::origin().with_y(-1.).with_z(0.25),
+            },
+            Test {
+                name: "point below the surface must have a negative z",
+                input: Geographic::origin().with_altitude(Altitude::from(1.5)),
+                surface_radius: 2.,
+                output: Cartesian::origin().with_z(-0.5),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let projection = Equirectangular::default().with_radius(2.0.into());
+            let output = projection.forward_3d(&test.input, test.surface_radius);
+
+            assert_eq!(
+                output, test.output,
+                "{}: got cartesian point = {:?}, want {:?}",
+                test.name, output, test.output
+            );
+        });
+    }
+
+    #[test]
+    fn equirectangular_try_reverse() {
+        struct Test {
+            name: &'static str,
+            cartesian: Cartesian<f64>,
+            output: Result<Geographic<f64>, OutOfDomain>,
+        }
+
+        vec![
+            Test {
+                name: "point inside the map must be reversed",
+                cartesian: Cartesian::origin().with_x(-2.).with_y(1.),
+                output: Ok(Geographic::origin()
+                    .with_longitude(Longitude::from(-1.))
+                    .with_latitude(Latitude::from(0.5))),
+            },
+            Test {
+                name: "pole must be reversed",
+                cartesian: Cartesian::origin().with_y(-PI),
+                output: Ok(Geographic::origin().with_latitude(Latitude::from(-FRAC_PI_2))),
+            },
+            Test {
+                name: "point beyond the north pole must be out of domain",
+                cartesian: Cartesian::origin().with_y(PI + 0.2),
+                output: Err(OutOfDomain),
+            },
+            Test {
+                name: "point beyond the antimeridian must be out of domain",
+                cartesian: Cartesian::origin().with_x(-2. * PI - 0.2),
+                output: Err(OutOfDomain),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let output = Equirectangular::default()
+                .with_radius(2.0.into())
+                .try_reverse(&test.cartesian);
+
+            assert_eq!(
+                output, test.output,
+                "{}: got try reverse = {:?}, want {:?}",
+                test.name, output, test.output
+            );
+        });
+
+        let folded = Equirectangular::default()
+            .with_radius(2.0.into())
+            .reverse(&Cartesian::origin().with_y(PI + 0.2));
+
+        assert!(
+            (folded.latitude.into_inner() - (FRAC_PI_2 - 0.1)).abs() < 1e-09,
+            "lenient reverse must fold latitudes beyond the pole: got {folded:?}"
+        );
+    }
+
+    #[test]
+    fn equirectangular_standard_parallel() {
+        struct Test {
+            name: &'static str,
+            standard_parallel: f64,
+            spacing: f64,
+        }
+
+        vec![
+            Test {
+                name: "default standard parallel must be the plate carree",
+                standard_parallel: 0.,
+                spacing: 2.,
+            },
+            Test {
+                name: "northern standard parallel must compress meridians",
+                standard_parallel: 1.,
+                spacing: 2. * 1_f64.cos(),
+            },
+            Test {
+                name: "southern standard parallel must compress meridians the same",
+                standard_parallel: -1.,
+                spacing: 2. * 1_f64.cos(),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let projection = Equirectangular::default()
+                .with_radius(2.0.into())
+                .with_standard_parallel(Latitude::from(test.standard_parallel));
+
+            let point = |lon: f64| {
+                Geographic::origin()
+                    .with_longitude(Longitude::from(lon))
+                    .with_latitude(Latitude::from(0.4))
+            };
+
+            let (west, east) = (
+                projection.forward(&point(0.5)),
+                projection.forward(&point(1.5)),
+            );
+            let spacing = east.x - west.x;
+
+            assert!(
+                (spacing - test.spacing).abs() < 1e-09 && (east.y - 0.8).abs() < 1e-09,
+                "{}: got meridian spacing = {}, want {}",
+                test.name,
+                spacing,
+                test.spacing
+            );
+
+            let reverse = projection.reverse(&east);
+            assert!(
+                reverse
+                    .longitude
+                    .approx_eq(&Longitude::from(1.5), Tolerance::default()),
+                "{}: got reverse = {:?}, want {:?}",
+                test.name,
+                reverse,
+                point(1.5)
+            );
+        });
+    }
+}
@@ -0,0 +1,362 @@
+//! Interrupted projection.
+
+use alloc::vec::Vec;
+
+use num_traits::{Euclid, Float, FloatConst, Signed};
+
+use crate::{Cartesian, Geographic, Longitude};
+
+use super::Projection;
+
+/// A portion of an [`Interrupted`] projection, going eastwards from its western bound to its
+/// eastern one, and projected around its own central meridian.
+///
+/// Since both boundaries of the longitude range are consecutive, a lobe may cross the
+/// antimeridian (when its western bound is greater than its eastern one). A lobe whose bounds are
+/// the same covers the whole globe.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        deserialize = "T: serde::Deserialize<'de> + PartialOrd + Signed + FloatConst + Euclid"
+    ))
+)]
+pub struct Lobe<T> {
+    /// The meridian the lobe is projected around.
+    pub central_meridian: Longitude<T>,
+    /// The western bound of the lobe.
+    pub west: Longitude<T>,
+    /// The eastern bound of the lobe.
+    pub east: Longitude<T>,
+}
+
+impl<T> Lobe<T> {
+    pub fn new(central_meridian: Longitude<T>, west: Longitude<T>, east: Longitude<T>) -> Self {
+        Self {
+            central_meridian,
+            west,
+            east,
+        }
+    }
+}
+
+impl<T> Lobe<T>
+where
+    T: Float + FloatConst,
+{
+    /// Returns true if, and only if, the given longitude lies within the bounds of the lobe,
+    /// both of them included.
+    pub fn contains(&self, longitude: &Longitude<T>) -> bool {
+        let eastwards = |from: T, to: T| {
+            let diff = (to - from) % T::TAU();
+            if diff < T::zero() {
+                diff + T::TAU()
+            } else {
+                diff
+            }
+        };
+
+        let span = eastwards(self.west.into_inner(), self.east.into_inner());
+        let span = if span == T::zero() { T::TAU() } else { span };
+
+        eastwards(self.west.into_inner(), longitude.into_inner()) <= span
+    }
+}
+
+/// Splits the map of the inner projection into [lobes](Lobe), each of them projected around its
+/// own central meridian, as in the [Goode
+/// homolosine](https://en.wikipedia.org/wiki/Goode_homolosine_projection) projection.
+///
+/// Each lobe is placed on the plane where its central meridian would be in the uninterrupted map,
+/// so the projection is discontinuous at the bounds of the lobes.
+///
+/// ## Lobe boundaries
+/// Points exactly on the boundary between two lobes belong to the first of them in the list, which
+/// is the lower (western) one as long as lobes are sorted from west to east. Points not belonging
+/// to any lobe are projected as in the uninterrupted map.
+///
+/// ## Example
+/// ```
+/// use std::f64::consts::{FRAC_PI_2, PI};
+///
+/// use geocart::{
+///     projection::{Interrupted, Lobe, Mollweide, Projection},
+///     Geographic, Latitude, Longitude,
+/// };
+///
+/// let projection = Interrupted::new(Mollweide::default())
+///     .with_lobe(Lobe::new((-FRAC_PI_2).into(), (-PI).into(), 0.0.into()))
+///     .with_lobe(Lobe::new(FRAC_PI_2.into(), 0.0.into(), PI.into()));
+///
+/// let boundary = Geographic::origin().with_latitude(Latitude::from(1.));
+/// let east = boundary.with_longitude(Longitude::from(1e-09));
+///
+/// let gap = projection.forward(&east).x - projection.forward(&boundary).x;
+/// assert!(gap > 0.5);
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        deserialize = "P: serde::Deserialize<'de>, T: serde::Deserialize<'de> + PartialOrd + Signed + FloatConst + Euclid"
+    ))
+)]
+pub struct Interrupted<P, T> {
+    /// The projection of each lobe.
+    pub inner: P,
+    /// The lobes the map is split into.
+    pub lobes: Vec<Lobe<T>>,
+}
+
+impl<P, T> Interrupted<P, T> {
+    pub fn new(inner: P) -> Self {
+        Self {
+            inner,
+            lobes: Vec::new(),
+        }
+    }
+
+    pub fn with_lobe(mut self, lobe: Lobe<T>) -> Self {
+        self.lobes.push(lobe);
+        self
+    }
+}
+
+impl<P, T> Interrupted<P, T>
+where
+    P: Projection<T>,
+    T: PartialOrd + Signed + Float + FloatConst + Euclid,
+{
+    /// Returns the position of the central meridian of the given lobe in the uninterrupted map,
+    /// relative to the one of the origin.
+    ///
+    /// The inner projection already applies its false origin (if any) to every lobe, so it must
+    /// not be part of the offset.
+    fn offset(&self, lobe: &Lobe<T>) -> Cartesian<T> {
+        let center = Geographic::origin().with_longitude(lobe.central_meridian);
+        Cartesian::origin()
+            .with_x(self.inner.forward(&center).x - self.inner.forward(&Geographic::origin()).x)
+    }
+}
+
+impl<P, T> Projection<T> for Interrupted<P, T>
+where
+    P: Projection<T>,
+    T: PartialOrd + Signed + Float + FloatConst + Euclid,
+{
+    fn forward(&self, coords: &Geographic<T>) -> Cartesian<T> {
+        let Some(lobe) = self
+            .lobes
+            .iter()
+            .find(|lobe| lobe.contains(&coords.longitude))
+        else {
+            return self.inner.forward(coords);
+        };
+
+        let local = coords.with_longitude(Longitude::from(
+            coords.longitude.into_inner() - lobe.central_meridian.into_inner(),
+        ));
+
+        self.inner.forward(&local) + self.offset(lobe)
+    }
+
+    fn reverse(&self, coords: &Cartesian<T>) -> Geographic<T> {
+        self.lobes
+            .iter()
+            .find_map(|lobe| {
+                let local = self.inner.reverse(&(*coords - self.offset(lobe)));
+                let point = local.with_longitude(Longitude::from(
+                    local.longitude.into_inner() + lobe.central_meridian.into_inner(),
+                ));
+
+                // the local point must be the one the lobe projects into the given coordinates.
+                let roundtrip = self.forward(&point);
+                let tolerance = T::epsilon().sqrt() * (T::one() + coords.magnitude());
+
+                (lobe.contains(&point.longitude) && roundtrip.distance(coords) <= tolerance)
+                    .then_some(point)
+            })
+            .unwrap_or_else(|| self.inner.reverse(coords))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    use crate::{
+        projection::{Interrupted, Lobe, Mollweide, Projection},
+        Geographic, Latitude, Longitude, Tolerance,
+    };
+
+    fn two_lobes() -> Interrupted<Mollweide<f64>, f64> {
+        Interrupted::new(Mollweide::default())
+            .with_lobe(Lobe::new(
+                Longitude::from(-FRAC_PI_2),
+                Longitude::from(-PI),
+                Longitude::from(0.),
+            ))
+            .with_lobe(Lobe::new(
+                Longitude::from(FRAC_PI_2),
+                Longitude::from(0.),
+                Longitude::from(PI),
+            ))
+    }
+
+    #[test]
+    fn interrupted_continuity() {
+        struct Test {
+            name: &'static str,
+            from: Geographic<f64>,
+            to: Geographic<f64>,
+            continuous: bool,
+        }
+
+        let step = 1e-09;
+        let point = |longitude: f64, latitude: f64| {
+            Geographic::origin()
+                .with_longitude(Longitude::from(longitude))
+                .with_latitude(Latitude::from(latitude))
+        };
+
+        vec![
+            Test {
+                name: "boundary between lobes must be discontinuous",
+                from: point(0., 1.),
+                to: point(step, 1.),
+                continuous: false,
+            },
+            Test {
+                name: "western lobe must be continuous",
+                from: point(-1., 1.),
+                to: point(-1. + step, 1.),
+                continuous: true,
+            },
+            Test {
+                name: "eastern lobe must be continuous",
+                from: point(2., -0.5),
+                to: point(2. + step, -0.5),
+                continuous: true,
+            },
+            Test {
+                name: "boundary must belong to the lower lobe",
+                from: point(0., 1.),
+                to: point(-step, 1.),
+                continuous: true,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let projection = two_lobes();
+            let from = projection.forward(&test.from);
+            let to = projection.forward(&test.to);
+
+            let continuous = from.distance(&to) < 1e-06;
+            assert_eq!(
+                continuous, test.continuous,
+                "{}: got continuous = {}, want {} (from {:?} to {:?})",
+                test.name, continuous, test.continuous, from, to
+            );
+        });
+    }
+
+    #[test]
+    fn interrupted_round_trip() {
+        let projection = two_lobes();
+
+        [(-2.5, 0.3), (-0.2, -1.), (0.4, 1.2), (3., -0.7)]
+            .into_iter()
+            .for_each(|(longitude, latitude)| {
+                let point = Geographic::origin()
+                    .with_longitude(Longitude::from(longitude))
+                    .with_latitude(Latitude::from(latitude));
+
+                let got = projection.reverse(&projection.forward(&point));
+                let tolerance = 1e-09;
+
+                assert!(
+                    got.longitude
+                        .approx_eq(&point.longitude, Tolerance::new(tolerance))
+                        && (got.latitude.into_inner() - latitude).abs() < tolerance,
+                    "got round trip = {got:?}, want {point:?}"
+                );
+            });
+    }
+
+    #[test]
+    fn interrupted_serde_round_trip() {
+        let projection = two_lobes();
+
+        let encoded = serde_json::to_string(&projection).unwrap();
+        let decoded: Interrupted<Mollweide<f64>, f64> = serde_json::from_str(&encoded).unwrap();
+
+        assert_eq!(
+            decoded.lobes, projection.lobes,
+            "got lobes = {:?}, want {:?}",
+            decoded.lobes, projection.lobes
+        );
+
+        let point = Geographic::origin()
+            .with_longitude(Longitude::from(2.))
+            .with_latitude(Latitude::from(0.5));
+
+        let (got, want) = (decoded.forward(&point), projection.forward(&point));
+        assert_eq!(got, want, "got forward = {got:?}, want {want:?}");
+    }
+
+    #[test]
+    fn interrupted_false_origin() {
+        let shifted = Mollweide::default()
+            .with_false_easting(10.)
+            .with_false_northing(-3.);
+
+        // a single lobe centered on the origin must match the bare projection.
+        let whole = Interrupted::new(shifted).with_lobe(Lobe::new(
+            Longitude::from(0.),
+            Longitude::from(-PI),
+            Longitude::from(PI),
+        ));
+
+        let point = Geographic::origin()
+            .with_longitude(Longitude::from(0.3))
+            .with_latitude(Latitude::from(0.2));
+
+        let got = whole.forward(&point);
+        let want = shifted.forward(&point);
+        assert!(
+            got.distance(&want) < 1e-09,
+            "got whole globe forward = {got:?}, want {want:?}"
+        );
+
+        let projection = Interrupted {
+            inner: shifted,
+            ..two_lobes()
+        };
+
+        [(-2.5, 0.3), (0.4, 1.2)]
+            .into_iter()
+            .for_each(|(longitude, latitude)| {
+                let point = Geographic::origin()
+                    .with_longitude(Longitude::from(longitude))
+                    .with_latitude(Latitude::from(latitude));
+
+                let got = projection.forward(&point);
+                let want = two_lobes().forward(&point);
+                assert!(
+                    (got.x - want.x - 10.).abs() < 1e-09 && (got.y - want.y + 3.).abs() < 1e-09,
+                    "got forward = {got:?}, want {want:?} shifted by (10, -3)"
+                );
+
+                let reverse = projection.reverse(&got);
+                assert!(
+                    reverse
+                        .longitude
+                        .approx_eq(&point.longitude, Tolerance::new(1e-09))
+                        && (reverse.latitude.into_inner() - latitude).abs() < 1e-09,
+                    "got reverse = {reverse:?}, want {point:?}"
+                );
+            });
+    }
+}
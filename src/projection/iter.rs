@@ -0,0 +1,120 @@
+//! Lazy projection of iterators.
+
+use crate::{Cartesian, Geographic};
+
+use super::Projection;
+
+/// Extends any iterator with methods to project its items lazily.
+pub trait ProjectExt: Iterator + Sized {
+    /// Returns an iterator that projects each [`Geographic`] point through the given projection.
+    fn project<T, P>(self, projection: P) -> ProjectIter<Self, P>
+    where
+        Self: Iterator<Item = Geographic<T>>,
+        P: Projection<T>,
+    {
+        ProjectIter {
+            iter: self,
+            projection,
+        }
+    }
+
+    /// Returns an iterator that reverses each projected [`Cartesian`] point through the given
+    /// projection.
+    fn unproject<T, P>(self, projection: P) -> UnprojectIter<Self, P>
+    where
+        Self: Iterator<Item = Cartesian<T>>,
+        P: Projection<T>,
+    {
+        UnprojectIter {
+            iter: self,
+            projection,
+        }
+    }
+}
+
+impl<I> ProjectExt for I where I: Iterator {}
+
+/// Iterator yielding the forward projection of each item of the inner iterator.
+#[derive(Debug, Clone)]
+pub struct ProjectIter<I, P> {
+    iter: I,
+    projection: P,
+}
+
+impl<T, I, P> Iterator for ProjectIter<I, P>
+where
+    I: Iterator<Item = Geographic<T>>,
+    P: Projection<T>,
+{
+    type Item = Cartesian<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|coords| self.projection.forward(&coords))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// Iterator yielding the reverse projection of each item of the inner iterator.
+#[derive(Debug, Clone)]
+pub struct UnprojectIter<I, P> {
+    iter: I,
+    projection: P,
+}
+
+impl<T, I, P> Iterator for UnprojectIter<I, P>
+where
+    I: Iterator<Item = Cartesian<T>>,
+    P: Projection<T>,
+{
+    type Item = Geographic<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|coords| self.projection.reverse(&coords))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{f64::consts::FRAC_PI_2, num::NonZeroUsize};
+
+    use crate::{
+        projection::{Equirectangular, ProjectExt, Projection},
+        shape::Arc,
+        Geographic, Latitude, Longitude,
+    };
+
+    #[test]
+    fn project_iterator() {
+        let projection = Equirectangular::default().with_radius(2.0.into());
+        let arc = Arc::new(NonZeroUsize::new(8).unwrap())
+            .with_from(Geographic::origin().with_longitude(Longitude::from(-1.)))
+            .with_to(Geographic::origin().with_latitude(Latitude::from(FRAC_PI_2 / 2.)));
+
+        let want: Vec<_> = arc
+            .into_iter()
+            .map(|coords| projection.forward(&coords))
+            .collect();
+
+        let got: Vec<_> = arc.into_iter().project(projection).collect();
+        assert_eq!(got, want, "got projected = {got:?}, want {want:?}");
+
+        let want: Vec<_> = want
+            .into_iter()
+            .map(|coords| projection.reverse(&coords))
+            .collect();
+
+        let got: Vec<_> = got.into_iter().unproject(projection).collect();
+        assert_eq!(got, want, "got unprojected = {got:?}, want {want:?}");
+    }
+}
@@ -0,0 +1,271 @@
+//! Mercator projection.
+
+use alloc::{format, string::String};
+use core::fmt::Display;
+
+use num_traits::{Euclid, Float, FloatConst, Signed};
+
+use crate::{positive::Positive, Cartesian, Geographic, Latitude};
+
+use super::Projection;
+
+/// Implements the [Mercator projection](https://en.wikipedia.org/wiki/Mercator_projection), a
+/// conformal cylindrical projection.
+///
+/// ## Poles
+/// Since the poles are projected to infinity, latitudes are clamped to the range given by the
+/// maximum latitude before being projected. By default that is ≈85.05°, the latitude at which the
+/// projected world becomes a square (as in the Web Mercator).
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(deserialize = "T: serde::Deserialize<'de> + Signed + Float + FloatConst"))
+)]
+pub struct Mercator<T> {
+    /// The radius of the projected sphere.
+    pub radius: Positive<T>,
+    max_latitude: Latitude<T>,
+    /// The value added to the x coordinate of every projected point.
+    #[cfg_attr(feature = "serde", serde(default = "num_traits::Zero::zero"))]
+    pub false_easting: T,
+    /// The value added to the y coordinate of every projected point.
+    #[cfg_attr(feature = "serde", serde(default = "num_traits::Zero::zero"))]
+    pub false_northing: T,
+}
+
+impl<T> Default for Mercator<T>
+where
+    T: Signed + Float + FloatConst,
+{
+    fn default() -> Self {
+        Self {
+            radius: T::one().into(),
+            max_latitude: T::PI().sinh().atan().into(),
+            false_easting: T::zero(),
+            false_northing: T::zero(),
+        }
+    }
+}
+
+impl<T> Projection<T> for Mercator<T>
+where
+    T: PartialOrd + Signed + Float + FloatConst + Euclid,
+{
+    fn forward(&self, coords: &Geographic<T>) -> Cartesian<T> {
+        let max_latitude = self.max_latitude().into_inner();
+        let latitude = coords
+            .latitude
+            .into_inner()
+            .max(-max_latitude)
+            .min(max_latitude);
+
+        Cartesian::origin()
+            .with_x(self.radius.into_inner() * coords.longitude.into_inner() + self.false_easting)
+            .with_y(
+                self.radius.into_inner()
+                    * (T::FRAC_PI_4() + latitude / (T::one() + T::one()))
+                        .tan()
+                        .ln()
+                    + self.false_northing,
+            )
+    }
+
+    fn reverse(&self, coords: &Cartesian<T>) -> Geographic<T> {
+        let x = (coords.x - self.false_easting) / self.radius.into_inner();
+        let y = (coords.y - self.false_northing) / self.radius.into_inner();
+
+        Geographic::origin()
+            .with_longitude(x.into())
+            .with_latitude(((T::one() + T::one()) * y.exp().atan() - T::FRAC_PI_2()).into())
+    }
+}
+
+impl<T> Mercator<T>
+where
+    T: Float + FloatConst,
+{
+    /// Returns the latitude beyond which points are clamped, which is never greater than the
+    /// greatest latitude below the pole.
+    pub fn max_latitude(&self) -> Latitude<T> {
+        // the pole itself cannot be projected, so the limit must stay strictly below it.
+        let below_pole = T::FRAC_PI_2() - T::FRAC_PI_2() * T::epsilon();
+        self.max_latitude.into_inner().abs().min(below_pole).into()
+    }
+}
+
+impl<T> Mercator<T> {
+    pub fn with_radius(self, radius: Positive<T>) -> Self {
+        Self { radius, ..self }
+    }
+
+    pub fn with_max_latitude(self, max_latitude: Latitude<T>) -> Self {
+        Self {
+            max_latitude,
+            ..self
+        }
+    }
+
+    pub fn with_false_easting(self, false_easting: T) -> Self {
+        Self {
+            false_easting,
+            ..self
+        }
+    }
+
+    pub fn with_false_northing(self, false_northing: T) -> Self {
+        Self {
+            false_northing,
+            ..self
+        }
+    }
+}
+
+impl<T> Mercator<T>
+where
+    T: Float + Display,
+{
+    /// Returns the [PROJ](https://proj.org/) string describing self, as parsed by
+    /// [`from_proj_string`](super::from_proj_string).
+    ///
+    /// The latitude beyond which points are clamped has no PROJ counterpart, so it is not
+    /// included.
+    pub fn to_proj_string(&self) -> String {
+        format!(
+            "+proj=merc +R={} +x_0={} +y_0={}",
+            self.radius.into_inner(),
+            self.false_easting,
+            self.false_northing
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    use crate::{
+        projection::{Mercator, Projection},
+        Cartesian, Geographic, Latitude, Longitude,
+    };
+
+    #[test]
+    fn mercator_projection() {
+        struct Test {
+            name: &'static str,
+            projection: Mercator<f64>,
+            geographic: Geographic<f64>,
+            cartesian: Cartesian<f64>,
+        }
+
+        vec![
+            Test {
+                name: "origin must be projected to the cartesian origin",
+                projection: Mercator::default(),
+                geographic: Geographic::origin(),
+                cartesian: Cartesian::origin(),
+            },
+            Test {
+                name: "default maximum latitude must make the projection square",
+                projection: Mercator::default(),
+                geographic: Geographic::origin()
+                    .with_longitude(Longitude::from(-PI))
+                    .with_latitude(Latitude::from(PI.sinh().atan())),
+                cartesian: Cartesian::origin().with_x(-PI).with_y(PI),
+            },
+            Test {
+                name: "radius must scale the projected point",
+                projection: Mercator::default().with_radius(2.0.into()),
+                geographic: Geographic::origin()
+                    .with_longitude(Longitude::from(1.))
+                    .with_latitude(Latitude::from(1_f64.sinh().atan())),
+                cartesian: Cartesian::origin().with_x(2.).with_y(2.),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let tolerance = 1e-09;
+
+            let forward = test.projection.forward(&test.geographic);
+            forward
+                .into_iter()
+                .zip(test.cartesian)
+                .for_each(|(got, want)| {
+                    assert!(
+                        (got - want).abs() < tolerance,
+                        "{}: got forward = {:?}, want {:?}",
+                        test.name,
+                        forward,
+                        test.cartesian
+                    );
+                });
+
+            let reverse = test.projection.reverse(&test.cartesian);
+            assert!(
+                (reverse.longitude.into_inner() - test.geographic.longitude.into_inner()).abs()
+                    < tolerance
+                    && (reverse.latitude.into_inner() - test.geographic.latitude.into_inner())
+                        .abs()
+                        < tolerance,
+                "{}: got reverse = {:?}, want {:?}",
+                test.name,
+                reverse,
+                test.geographic
+            );
+        });
+    }
+
+    #[test]
+    fn mercator_max_latitude() {
+        struct Test {
+            name: &'static str,
+            projection: Mercator<f64>,
+            max_latitude: f64,
+        }
+
+        vec![
+            Test {
+                name: "default limit must be the square web mercator one",
+                projection: Mercator::default(),
+                max_latitude: 85.051_128_779_806_59_f64.to_radians(),
+            },
+            Test {
+                name: "custom limit must be kept",
+                projection: Mercator::default().with_max_latitude(Latitude::from(-1.)),
+                max_latitude: 1.,
+            },
+            Test {
+                name: "pole limit must stay below the pole",
+                projection: Mercator::default().with_max_latitude(Latitude::from(FRAC_PI_2)),
+                max_latitude: FRAC_PI_2 - FRAC_PI_2 * f64::EPSILON,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let max_latitude = test.projection.max_latitude().into_inner();
+
+            assert!(
+                (max_latitude - test.max_latitude).abs() < 1e-09,
+                "{}: got max latitude = {}, want {}",
+                test.name,
+                max_latitude,
+                test.max_latitude
+            );
+
+            let limit = test
+                .projection
+                .forward(&Geographic::origin().with_latitude(Latitude::from(test.max_latitude)));
+            let beyond = test
+                .projection
+                .forward(&Geographic::origin().with_latitude(Latitude::from(FRAC_PI_2)));
+
+            assert!(
+                limit.y.is_finite() && limit == beyond,
+                "{}: got beyond = {:?}, want saturated at {:?}",
+                test.name,
+                beyond,
+                limit
+            );
+        });
+    }
+}
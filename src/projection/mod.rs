@@ -0,0 +1,644 @@
+//! Projection definitions and implementations.
+
+mod cylindrical_equal_area;
+mod equirectangular;
+mod interrupted;
+mod iter;
+mod mercator;
+mod mollweide;
+mod proj_string;
+mod transverse_mercator;
+
+pub use self::cylindrical_equal_area::CylindricalEqualArea;
+pub use self::equirectangular::{Equirectangular, PlateCarree};
+pub use self::interrupted::{Interrupted, Lobe};
+pub use self::iter::{ProjectExt, ProjectIter, UnprojectIter};
+pub use self::mercator::Mercator;
+pub use self::mollweide::Mollweide;
+pub use self::proj_string::{from_proj_string, ProjStringError};
+pub use self::transverse_mercator::TransverseMercator;
+
+use alloc::{boxed::Box, vec::Vec};
+
+use num_traits::{Euclid, Float, FloatConst, Signed};
+
+use crate::{shape::Polygon, Cartesian, Geographic, Latitude, Positive};
+
+/// A map projection between the geographic and the cartesian systems of coordinates.
+///
+/// ## Object safety
+/// The trait is object safe, so heterogeneous projections can be stored together as
+/// `Box<dyn Projection<T>>` (see [`by_name`]). Any method added to it must keep it that way: no
+/// generic methods nor signatures involving `Self`, unless they are bounded by `Self: Sized`.
+pub trait Projection<T> {
+    /// Projects the given geographic point into the cartesian plane.
+    fn forward(&self, coords: &Geographic<T>) -> Cartesian<T>;
+
+    /// Returns the geographic point corresponding to the given projected one.
+    fn reverse(&self, coords: &Cartesian<T>) -> Geographic<T>;
+
+    /// Returns the geographic point corresponding to the given projected one, or [`OutOfDomain`]
+    /// if the latter lies outside the domain of the projection.
+    ///
+    /// By default the whole plane is considered to be in the domain.
+    fn try_reverse(&self, coords: &Cartesian<T>) -> Result<Geographic<T>, OutOfDomain> {
+        Ok(self.reverse(coords))
+    }
+
+    /// Returns the reverse projection of each of the given points, or [`None`] for those outside
+    /// the domain of the projection (see [`Projection::try_reverse`]).
+    ///
+    /// The output is index-aligned with the input.
+    fn reverse_filtered(&self, coords: &[Cartesian<T>]) -> Vec<Option<Geographic<T>>> {
+        coords
+            .iter()
+            .map(|coords| self.try_reverse(coords).ok())
+            .collect()
+    }
+
+    /// Projects the given geographic point into the cartesian plane, keeping its height above the
+    /// surface of a sphere of the given radius as the z coordinate.
+    ///
+    /// The height is the radial distance of the point (as in [`Geographic::into_cartesian`]) minus
+    /// the radius of the surface. Hence, points on the surface stay on the plane, while points
+    /// below it get a negative z.
+    fn forward_3d(&self, coords: &Geographic<T>, surface_radius: T) -> Cartesian<T>
+    where
+        T: Signed + Float + FloatConst,
+    {
+        self.forward(coords)
+            .with_z(coords.radial_distance() - surface_radius)
+    }
+
+    /// Returns the meridian convergence at the given point, which is the angle (in radians) from
+    /// true north to the projected grid north, measured clockwise. The angle is signed, lying in
+    /// the range __(-π, +π]__, so an anticlockwise convergence is negative.
+    ///
+    /// The direction of true north on the plane is approximated by projecting a small northward
+    /// step from the given point (or towards it, if the point is too close to the north pole).
+    fn convergence(&self, coords: &Geographic<T>) -> T
+    where
+        T: Copy + Signed + Float + FloatConst,
+    {
+        let step = T::epsilon().sqrt();
+        let latitude = coords.latitude.into_inner();
+
+        let (from, to) = if latitude + step < T::FRAC_PI_2() {
+            (
+                *coords,
+                coords.with_latitude(Latitude::from(latitude + step)),
+            )
+        } else {
+            (
+                coords.with_latitude(Latitude::from(latitude - step)),
+                *coords,
+            )
+        };
+
+        let (from, to) = (self.forward(&from), self.forward(&to));
+        let convergence = (from.x - to.x).atan2(to.y - from.y);
+
+        // atan2 may return -π, which is the same angle as +π.
+        if convergence == -T::PI() {
+            T::PI()
+        } else {
+            convergence
+        }
+    }
+
+    /// Returns the area enclosed by the given polygon once projected into the plane, as the
+    /// [shoelace](https://en.wikipedia.org/wiki/Shoelace_formula) area of its projected vertices.
+    ///
+    /// Compared to the [area of the polygon on the sphere](Polygon::area), it quantifies the area
+    /// distortion of the projection. The edges are taken as straight lines on the plane, so the
+    /// polygon must be dense enough for the projected edges to be so.
+    fn projected_area(&self, polygon: &Polygon<T>) -> T
+    where
+        T: Float,
+    {
+        let vertices: Vec<Cartesian<T>> = polygon
+            .vertices
+            .iter()
+            .map(|vertex| self.forward(vertex))
+            .collect();
+
+        let (Some(first), Some(last)) = (vertices.first(), vertices.last()) else {
+            return T::zero();
+        };
+
+        let twice_area = vertices
+            .windows(2)
+            .map(|pair| (pair[0], pair[1]))
+            .chain([(*last, *first)])
+            .fold(T::zero(), |area, (from, to)| {
+                area + from.x * to.y - to.x * from.y
+            });
+
+        (twice_area / (T::one() + T::one())).abs()
+    }
+}
+
+/// The error of reversing a projected point that lies outside the domain of the projection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutOfDomain;
+
+impl core::fmt::Display for OutOfDomain {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "the point is out of the domain of the projection")
+    }
+}
+
+impl core::error::Error for OutOfDomain {}
+
+/// Returns the projection with the given name and radius, or [`None`] if there is no projection
+/// with such a name.
+///
+/// The known names are `cylindrical-equal-area`, `equirectangular`, `mercator`, `mollweide` and
+/// `transverse-mercator`, in lowercase. Any other setting of the projection is left to its default
+/// value.
+///
+/// ## Example
+/// ```
+/// use geocart::{projection::by_name, Geographic};
+///
+/// let projection = by_name::<f64>("mercator", 2.0.into()).unwrap();
+/// assert_eq!(projection.forward(&Geographic::origin()).x, 0.);
+///
+/// assert!(by_name::<f64>("unknown", 2.0.into()).is_none());
+/// ```
+pub fn by_name<T>(name: &str, radius: Positive<T>) -> Option<Box<dyn Projection<T>>>
+where
+    T: 'static + PartialOrd + Signed + Float + FloatConst + Euclid,
+{
+    match name {
+        "cylindrical-equal-area" => Some(Box::new(
+            CylindricalEqualArea::default().with_radius(radius),
+        )),
+        "equirectangular" => Some(Box::new(Equirectangular::default().with_radius(radius))),
+        "mercator" => Some(Box::new(Mercator::default().with_radius(radius))),
+        "mollweide" => Some(Box::new(Mollweide::default().with_radius(radius))),
+        "transverse-mercator" => Some(Box::new(TransverseMercator::default().with_radius(radius))),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, TAU};
+
+    use crate::{
+        projection::{
+            by_name, CylindricalEqualArea, Equirectangular, Mercator, Mollweide, Projection,
+            TransverseMercator,
+        },
+        shape::Polygon,
+        Cartesian, Geographic, Latitude, Longitude, Radian,
+    };
+
+    #[test]
+    fn projection_by_name() {
+        struct Test {
+            name: &'static str,
+            projection: Option<Box<dyn Projection<f64>>>,
+        }
+
+        vec![
+            Test {
+                name: "cylindrical-equal-area",
+                projection: Some(Box::new(
+                    CylindricalEqualArea::default().with_radius(2.0.into()),
+                )),
+            },
+            Test {
+                name: "equirectangular",
+                projection: Some(Box::new(Equirectangular::default().with_radius(2.0.into()))),
+            },
+            Test {
+                name: "mercator",
+                projection: Some(Box::new(Mercator::default().with_radius(2.0.into()))),
+            },
+            Test {
+                name: "mollweide",
+                projection: Some(Box::new(Mollweide::default().with_radius(2.0.into()))),
+            },
+            Test {
+                name: "transverse-mercator",
+                projection: Some(Box::new(
+                    TransverseMercator::default().with_radius(2.0.into()),
+                )),
+            },
+            Test {
+                name: "Mercator",
+                projection: None,
+            },
+            Test {
+                name: "unknown",
+                projection: None,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let projection = by_name(test.name, 2.0.into());
+            let point = Geographic::origin()
+                .with_longitude(Longitude::from(1.))
+                .with_latitude(Latitude::from(FRAC_PI_2 / 3.));
+
+            match (projection, test.projection) {
+                (Some(got), Some(want)) => {
+                    let (got, want) = (got.forward(&point), want.forward(&point));
+                    assert_eq!(
+                        got, want,
+                        "{}: got forward = {:?}, want {:?}",
+                        test.name, got, want
+                    );
+                }
+                (None, None) => {}
+                (got, want) => panic!(
+                    "{}: got projection = {}, want {}",
+                    test.name,
+                    got.is_some(),
+                    want.is_some()
+                ),
+            }
+        });
+    }
+
+    #[test]
+    fn projection_must_be_object_safe() {
+        fn round_trip(
+            projection: &dyn Projection<f64>,
+            point: &Geographic<f64>,
+        ) -> Geographic<f64> {
+            projection.reverse(&projection.forward(point))
+        }
+
+        let projections: Vec<Box<dyn Projection<f64>>> = vec![
+            Box::new(Equirectangular::default()),
+            Box::new(Mercator::default().with_radius(3.0.into())),
+        ];
+
+        let point = Geographic::origin()
+            .with_longitude(Longitude::from(-2.))
+            .with_latitude(Latitude::from(0.4));
+
+        projections.iter().for_each(|projection| {
+            let got = round_trip(projection.as_ref(), &point);
+            let tolerance = 1e-09;
+
+            assert!(
+                (got.longitude.into_inner() - point.longitude.into_inner()).abs() < tolerance
+                    && (got.latitude.into_inner() - point.latitude.into_inner()).abs() < tolerance,
+                "got round trip = {got:?}, want {point:?}"
+            );
+        });
+    }
+
+    #[test]
+    fn projection_convergence() {
+        struct Test {
+            name: &'static str,
+            projection: Box<dyn Projection<f64>>,
+            geographic: Geographic<f64>,
+            convergence: f64,
+        }
+
+        let transverse_mercator =
+            TransverseMercator::default().with_central_meridian(Longitude::from(0.5));
+
+        vec![
+            Test {
+                name: "transverse mercator central meridian must have no convergence",
+                projection: Box::new(transverse_mercator),
+                geographic: Geographic::origin()
+                    .with_longitude(Longitude::from(0.5))
+                    .with_latitude(Latitude::from(FRAC_PI_4)),
+                convergence: 0.,
+            },
+            Test {
+                name: "transverse mercator east of the central meridian must converge clockwise",
+                projection: Box::new(transverse_mercator),
+                geographic: Geographic::origin()
+                    .with_longitude(Longitude::from(0.8))
+                    .with_latitude(Latitude::from(FRAC_PI_4)),
+                convergence: (0.3_f64.tan() * FRAC_PI_4.sin()).atan(),
+            },
+            Test {
+                name:
+                    "transverse mercator west of the central meridian must converge anticlockwise",
+                projection: Box::new(transverse_mercator),
+                geographic: Geographic::origin()
+                    .with_longitude(Longitude::from(0.2))
+                    .with_latitude(Latitude::from(FRAC_PI_4)),
+                convergence: (-0.3_f64.tan() * FRAC_PI_4.sin()).atan(),
+            },
+            Test {
+                name: "transverse mercator south west of the central meridian must turn clockwise",
+                projection: Box::new(transverse_mercator),
+                geographic: Geographic::origin()
+                    .with_longitude(Longitude::from(0.2))
+                    .with_latitude(Latitude::from(-FRAC_PI_4)),
+                convergence: (-0.3_f64.tan() * -FRAC_PI_4.sin()).atan(),
+            },
+            Test {
+                name: "transverse mercator pole must be reached from the south",
+                projection: Box::new(transverse_mercator),
+                geographic: Geographic::origin()
+                    .with_longitude(Longitude::from(0.8))
+                    .with_latitude(Latitude::from(FRAC_PI_2)),
+                convergence: 0.3,
+            },
+            Test {
+                name: "mercator must have no convergence",
+                projection: Box::new(Mercator::default()),
+                geographic: Geographic::origin()
+                    .with_longitude(Longitude::from(2.))
+                    .with_latitude(Latitude::from(1.)),
+                convergence: 0.,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let convergence = test.projection.convergence(&test.geographic);
+            assert!(
+                (convergence - test.convergence).abs() < 1e-06,
+                "{}: got convergence = {}, want {}",
+                test.name,
+                convergence,
+                test.convergence
+            );
+        });
+    }
+
+    #[test]
+    fn projection_reverse_filtered() {
+        struct Test {
+            name: &'static str,
+            projection: Box<dyn Projection<f64>>,
+            inside: Vec<bool>,
+        }
+
+        // a 5x3 raster grid covering the bounding box of the default mollweide ellipse.
+        let (width, height) = (2. * 2_f64.sqrt(), 2_f64.sqrt());
+        let grid: Vec<_> = (-1..=1)
+            .flat_map(|row| {
+                (-2..=2).map(move |col| {
+                    Cartesian::origin()
+                        .with_x(col as f64 * width / 2.)
+                        .with_y(row as f64 * height)
+                })
+            })
+            .collect();
+
+        vec![
+            Test {
+                name: "mollweide corners must be outside the domain",
+                projection: Box::new(Mollweide::default()),
+                inside: vec![
+                    false, false, true, false, false, //
+                    true, true, true, true, true, //
+                    false, false, true, false, false,
+                ],
+            },
+            Test {
+                name: "equirectangular must have no points outside the domain",
+                projection: Box::new(Equirectangular::default()),
+                inside: vec![true; 15],
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let got = test.projection.reverse_filtered(&grid);
+            assert_eq!(
+                got.len(),
+                grid.len(),
+                "{}: got {} points, want {}",
+                test.name,
+                got.len(),
+                grid.len()
+            );
+
+            got.iter()
+                .zip(&grid)
+                .zip(test.inside)
+                .for_each(|((got, cartesian), inside)| {
+                    let want = inside.then(|| test.projection.reverse(cartesian));
+                    assert_eq!(
+                        *got, want,
+                        "{}: got reverse of {:?} = {:?}, want {:?}",
+                        test.name, cartesian, got, want
+                    );
+                });
+        });
+    }
+
+    #[test]
+    fn projection_projected_area() {
+        struct Test {
+            name: &'static str,
+            projection: Box<dyn Projection<f64>>,
+            constant: bool,
+        }
+
+        // the same small polygon placed at different points of the globe.
+        let polygons: Vec<Polygon<f64>> = [(0., 0.), (1., 0.7), (-2., -1.2)]
+            .into_iter()
+            .map(|(longitude, latitude)| {
+                let center = Geographic::origin()
+                    .with_longitude(Longitude::from(longitude))
+                    .with_latitude(Latitude::from(latitude));
+
+                Polygon::new(
+                    (0..64)
+                        .map(|index| {
+                            center.destination(Radian::from(TAU * index as f64 / 64.), 0.01)
+                        })
+                        .collect(),
+                )
+            })
+            .collect();
+
+        vec![
+            Test {
+                name: "cylindrical equal area must keep the ratio constant",
+                projection: Box::new(CylindricalEqualArea::default()),
+                constant: true,
+            },
+            Test {
+                name: "mollweide must keep the ratio constant",
+                projection: Box::new(Mollweide::default().with_radius(2.0.into())),
+                constant: true,
+            },
+            Test {
+                name: "mercator must not keep the ratio constant",
+                projection: Box::new(Mercator::default()),
+                constant: false,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let ratios: Vec<f64> = polygons
+                .iter()
+                .map(|polygon| test.projection.projected_area(polygon) / polygon.area())
+                .collect();
+
+            let constant = ratios
+                .iter()
+                .all(|ratio| (ratio / ratios[0] - 1.).abs() < 1e-04);
+
+            assert_eq!(
+                constant, test.constant,
+                "{}: got ratios = {:?}, want constant = {}",
+                test.name, ratios, test.constant
+            );
+        });
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn projection_random_round_trip() {
+        use rand::{rngs::SmallRng, SeedableRng};
+
+        struct Test {
+            name: &'static str,
+            projection: Box<dyn Projection<f64>>,
+            domain: fn(&Geographic<f64>) -> bool,
+        }
+
+        vec![
+            Test {
+                name: "cylindrical equal area",
+                projection: Box::new(CylindricalEqualArea::default()),
+                domain: |_| true,
+            },
+            Test {
+                name: "equirectangular",
+                projection: Box::new(Equirectangular::default().with_radius(2.0.into())),
+                domain: |_| true,
+            },
+            Test {
+                name: "mercator",
+                projection: Box::new(Mercator::default()),
+                domain: |point| {
+                    point.latitude.into_inner().abs()
+                        < Mercator::<f64>::default().max_latitude().into_inner()
+                },
+            },
+            Test {
+                name: "mollweide",
+                projection: Box::new(Mollweide::default()),
+                domain: |_| true,
+            },
+            Test {
+                name: "transverse mercator",
+                projection: Box::new(TransverseMercator::default()),
+                // away from the singularities at the equator, 90° from the central meridian.
+                domain: |point| {
+                    (point.latitude.into_inner().cos() * point.longitude.into_inner().sin()).abs()
+                        < 0.99
+                },
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let mut rng = SmallRng::seed_from_u64(7);
+
+            (0..1_000)
+                .map(|_| Geographic::random(&mut rng))
+                .filter(test.domain)
+                .for_each(|point| {
+                    let got = test.projection.reverse(&test.projection.forward(&point));
+                    let distance = got.into_cartesian().distance(&point.into_cartesian());
+
+                    assert!(
+                        distance < 1e-06,
+                        "{}: got round trip of {:?} = {:?}",
+                        test.name,
+                        point,
+                        got
+                    );
+                });
+        });
+    }
+
+    #[test]
+    fn projection_false_origin() {
+        struct Test {
+            name: &'static str,
+            projection: Box<dyn Projection<f64>>,
+            shifted: Box<dyn Projection<f64>>,
+        }
+
+        let (false_easting, false_northing) = (5., -2.);
+
+        vec![
+            Test {
+                name: "cylindrical equal area",
+                projection: Box::new(CylindricalEqualArea::default()),
+                shifted: Box::new(
+                    CylindricalEqualArea::default()
+                        .with_false_easting(false_easting)
+                        .with_false_northing(false_northing),
+                ),
+            },
+            Test {
+                name: "equirectangular",
+                projection: Box::new(Equirectangular::default()),
+                shifted: Box::new(
+                    Equirectangular::default()
+                        .with_false_easting(false_easting)
+                        .with_false_northing(false_northing),
+                ),
+            },
+            Test {
+                name: "mercator",
+                projection: Box::new(Mercator::default()),
+                shifted: Box::new(
+                    Mercator::default()
+                        .with_false_easting(false_easting)
+                        .with_false_northing(false_northing),
+                ),
+            },
+            Test {
+                name: "mollweide",
+                projection: Box::new(Mollweide::default()),
+                shifted: Box::new(
+                    Mollweide::default()
+                        .with_false_easting(false_easting)
+                        .with_false_northing(false_northing),
+                ),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let point = Geographic::origin()
+                .with_longitude(Longitude::from(-2.))
+                .with_latitude(Latitude::from(0.4));
+
+            let want = test.projection.forward(&point);
+            let got = test.shifted.forward(&point);
+            assert!(
+                ((got.x - want.x) - false_easting).abs() < 1e-09
+                    && ((got.y - want.y) - false_northing).abs() < 1e-09,
+                "{}: got forward = {:?}, want {:?} shifted by ({}, {})",
+                test.name,
+                got,
+                want,
+                false_easting,
+                false_northing
+            );
+
+            let reverse = test.shifted.try_reverse(&got);
+            assert!(
+                reverse.is_ok_and(|reverse| {
+                    (reverse.longitude.into_inner() - point.longitude.into_inner()).abs() < 1e-09
+                        && (reverse.latitude.into_inner() - point.latitude.into_inner()).abs()
+                            < 1e-09
+                }),
+                "{}: got reverse = {:?}, want {:?}",
+                test.name,
+                reverse,
+                point
+            );
+        });
+    }
+}
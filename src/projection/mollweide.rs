@@ -0,0 +1,268 @@
+//! Mollweide projection.
+
+use alloc::{format, string::String};
+use core::fmt::Display;
+
+use num_traits::{Euclid, Float, FloatConst, Signed};
+
+use crate::{positive::Positive, Cartesian, Geographic};
+
+use super::{OutOfDomain, Projection};
+
+/// The maximum amount of Newton iterations when solving for the auxiliary angle.
+const MAX_ITERATIONS: usize = 32;
+
+/// Implements the [Mollweide projection](https://en.wikipedia.org/wiki/Mollweide_projection), an
+/// equal-area pseudocylindrical projection that maps the whole sphere into an ellipse twice as wide
+/// as it is tall.
+///
+/// ## Domain
+/// Only the points inside the ellipse centered at the false origin, whose semi-axes are 2√2 and √2
+/// times the radius, have a geographic counterpart. Hence [`Projection::try_reverse`] returns
+/// [`OutOfDomain`] for any point outside of it.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(deserialize = "T: serde::Deserialize<'de> + Signed"))
+)]
+pub struct Mollweide<T> {
+    /// The radius of the projected sphere.
+    pub radius: Positive<T>,
+    /// The value added to the x coordinate of every projected point.
+    #[cfg_attr(feature = "serde", serde(default = "num_traits::Zero::zero"))]
+    pub false_easting: T,
+    /// The value added to the y coordinate of every projected point.
+    #[cfg_attr(feature = "serde", serde(default = "num_traits::Zero::zero"))]
+    pub false_northing: T,
+}
+
+impl<T> Default for Mollweide<T>
+where
+    T: Signed,
+{
+    fn default() -> Self {
+        Self {
+            radius: T::one().into(),
+            false_easting: T::zero(),
+            false_northing: T::zero(),
+        }
+    }
+}
+
+impl<T> Projection<T> for Mollweide<T>
+where
+    T: PartialOrd + Signed + Float + FloatConst + Euclid,
+{
+    fn forward(&self, coords: &Geographic<T>) -> Cartesian<T> {
+        let two = T::one() + T::one();
+        let theta = auxiliary_angle(coords.latitude.into_inner());
+
+        Cartesian::origin()
+            .with_x(
+                self.radius.into_inner() * two * two.sqrt() / T::PI()
+                    * coords.longitude.into_inner()
+                    * theta.cos()
+                    + self.false_easting,
+            )
+            .with_y(self.radius.into_inner() * two.sqrt() * theta.sin() + self.false_northing)
+    }
+
+    fn reverse(&self, coords: &Cartesian<T>) -> Geographic<T> {
+        let two = T::one() + T::one();
+        let x = coords.x - self.false_easting;
+        let y = coords.y - self.false_northing;
+
+        let theta = (y / (self.radius.into_inner() * two.sqrt()))
+            .max(-T::one())
+            .min(T::one())
+            .asin();
+
+        let longitude = if theta.cos() > T::zero() {
+            T::PI() * x / (two * two.sqrt() * self.radius.into_inner() * theta.cos())
+        } else {
+            // at the poles every longitude collapses into the same point.
+            T::zero()
+        };
+
+        Geographic::origin()
+            .with_longitude(longitude.into())
+            .with_latitude(
+                ((two * theta + (two * theta).sin()) / T::PI())
+                    .max(-T::one())
+                    .min(T::one())
+                    .asin()
+                    .into(),
+            )
+    }
+
+    fn try_reverse(&self, coords: &Cartesian<T>) -> Result<Geographic<T>, OutOfDomain> {
+        let two = T::one() + T::one();
+        let a = two * two.sqrt() * self.radius.into_inner();
+        let b = two.sqrt() * self.radius.into_inner();
+
+        let x = coords.x - self.false_easting;
+        let y = coords.y - self.false_northing;
+
+        if (x / a).powi(2) + (y / b).powi(2) > T::one() {
+            return Err(OutOfDomain);
+        }
+
+        Ok(self.reverse(coords))
+    }
+}
+
+impl<T> Mollweide<T> {
+    pub fn with_radius(self, radius: Positive<T>) -> Self {
+        Self { radius, ..self }
+    }
+
+    pub fn with_false_easting(self, false_easting: T) -> Self {
+        Self {
+            false_easting,
+            ..self
+        }
+    }
+
+    pub fn with_false_northing(self, false_northing: T) -> Self {
+        Self {
+            false_northing,
+            ..self
+        }
+    }
+}
+
+impl<T> Mollweide<T>
+where
+    T: Float + Display,
+{
+    /// Returns the [PROJ](https://proj.org/) string describing self, as parsed by
+    /// [`from_proj_string`](super::from_proj_string).
+    pub fn to_proj_string(&self) -> String {
+        format!(
+            "+proj=moll +R={} +x_0={} +y_0={}",
+            self.radius.into_inner(),
+            self.false_easting,
+            self.false_northing
+        )
+    }
+}
+
+/// Returns the auxiliary angle θ satisfying 2θ + sin(2θ) = π·sin(φ) for the given latitude φ.
+fn auxiliary_angle<T>(latitude: T) -> T
+where
+    T: Float + FloatConst,
+{
+    if latitude.abs() >= T::FRAC_PI_2() {
+        return latitude;
+    }
+
+    let two = T::one() + T::one();
+    let target = T::PI() * latitude.sin();
+
+    let mut theta = latitude;
+    for _ in 0..MAX_ITERATIONS {
+        let delta =
+            (two * theta + (two * theta).sin() - target) / (two + two * (two * theta).cos());
+        theta = theta - delta;
+
+        if delta.abs() <= T::epsilon() {
+            break;
+        }
+    }
+
+    theta
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2, PI, SQRT_2};
+
+    use crate::{
+        projection::{Mollweide, Projection},
+        Cartesian, Geographic, Latitude, Longitude, Tolerance,
+    };
+
+    #[test]
+    fn mollweide_projection() {
+        struct Test {
+            name: &'static str,
+            projection: Mollweide<f64>,
+            geographic: Geographic<f64>,
+            cartesian: Cartesian<f64>,
+        }
+
+        vec![
+            Test {
+                name: "origin must be projected to the cartesian origin",
+                projection: Mollweide::default(),
+                geographic: Geographic::origin(),
+                cartesian: Cartesian::origin(),
+            },
+            Test {
+                name: "north pole must be projected to the top of the ellipse",
+                projection: Mollweide::default(),
+                geographic: Geographic::origin().with_latitude(Latitude::from(FRAC_PI_2)),
+                cartesian: Cartesian::origin().with_y(SQRT_2),
+            },
+            Test {
+                name: "antimeridian at the equator must be projected to the end of the ellipse",
+                projection: Mollweide::default().with_radius(2.0.into()),
+                geographic: Geographic::origin().with_longitude(Longitude::from(-PI)),
+                cartesian: Cartesian::origin().with_x(-4. * SQRT_2),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let tolerance = 1e-09;
+
+            let forward = test.projection.forward(&test.geographic);
+            forward
+                .into_iter()
+                .zip(test.cartesian)
+                .for_each(|(got, want)| {
+                    assert!(
+                        (got - want).abs() < tolerance,
+                        "{}: got forward = {:?}, want {:?}",
+                        test.name,
+                        forward,
+                        test.cartesian
+                    );
+                });
+
+            let reverse = test.projection.reverse(&test.cartesian);
+            assert!(
+                (reverse.longitude.into_inner() - test.geographic.longitude.into_inner()).abs()
+                    < tolerance
+                    && (reverse.latitude.into_inner() - test.geographic.latitude.into_inner())
+                        .abs()
+                        < tolerance,
+                "{}: got reverse = {:?}, want {:?}",
+                test.name,
+                reverse,
+                test.geographic
+            );
+        });
+    }
+
+    #[test]
+    fn mollweide_round_trip() {
+        let projection = Mollweide::default();
+
+        (-8..=8).for_each(|lat| {
+            (-15..15).for_each(|lon| {
+                let point = Geographic::origin()
+                    .with_longitude(Longitude::from(lon as f64 * PI / 15.))
+                    .with_latitude(Latitude::from(lat as f64 * FRAC_PI_2 / 9.));
+
+                let got = projection.reverse(&projection.forward(&point));
+                assert!(
+                    got.longitude
+                        .approx_eq(&point.longitude, Tolerance::default())
+                        && (got.latitude.into_inner() - point.latitude.into_inner()).abs() < 1e-09,
+                    "got round trip = {got:?}, want {point:?}"
+                );
+            });
+        });
+    }
+}
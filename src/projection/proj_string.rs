@@ -0,0 +1,327 @@
+//! PROJ strings of the built-in projections.
+
+use alloc::{boxed::Box, vec::Vec};
+
+use num_traits::{Euclid, Float, FloatConst, Signed};
+
+use super::{
+    CylindricalEqualArea, Equirectangular, Mercator, Mollweide, Projection, TransverseMercator,
+};
+
+/// The reason why a PROJ string cannot be turned into a projection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProjStringError {
+    /// The string is not a sequence of `+key=value` (or `+flag`) tokens naming a projection, or
+    /// any of the values is not valid.
+    Malformed,
+    /// The projection named by the string is not implemented.
+    UnsupportedProjection,
+    /// The string has a parameter the projection does not support.
+    UnsupportedParameter,
+}
+
+impl core::fmt::Display for ProjStringError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Malformed => write!(f, "the proj string is malformed"),
+            Self::UnsupportedProjection => write!(f, "the projection is not supported"),
+            Self::UnsupportedParameter => {
+                write!(f, "a parameter of the projection is not supported")
+            }
+        }
+    }
+}
+
+impl core::error::Error for ProjStringError {}
+
+/// Returns the projection described by the given [PROJ](https://proj.org/) string, like the ones
+/// returned by the `to_proj_string` method of each projection.
+///
+/// The supported projections are `cea`, `eqc`, `merc`, `moll` and `tmerc`, with their radius
+/// (`+R`) and false origin (`+x_0` and `+y_0`), plus their standard parallel (`+lat_ts`) or central
+/// meridian (`+lon_0`), as long as the projection has them. Angles are given in degrees. A missing
+/// radius is taken as 1, while any other missing parameter is taken as zero, as PROJ does. The
+/// `+no_defs`, `+type=crs` and `+units=m` tokens are accepted and ignored.
+///
+/// ## Example
+/// ```
+/// use geocart::{
+///     projection::{from_proj_string, ProjStringError},
+///     Geographic,
+/// };
+///
+/// let projection = from_proj_string::<f64>("+proj=merc +R=2").unwrap();
+/// assert_eq!(projection.forward(&Geographic::origin()).x, 0.);
+///
+/// assert_eq!(
+///     from_proj_string::<f64>("+proj=lcc").err(),
+///     Some(ProjStringError::UnsupportedProjection)
+/// );
+/// ```
+pub fn from_proj_string<T>(s: &str) -> Result<Box<dyn Projection<T>>, ProjStringError>
+where
+    T: 'static + PartialOrd + Signed + Float + FloatConst + Euclid,
+{
+    let mut name = None;
+    let mut params: Vec<(&str, &str)> = Vec::new();
+
+    for token in s.split_whitespace() {
+        let token = token.strip_prefix('+').ok_or(ProjStringError::Malformed)?;
+        match token.split_once('=') {
+            None if token == "no_defs" => {}
+            Some(("type", "crs")) | Some(("units", "m")) => {}
+            Some(("proj", value)) if name.is_none() => name = Some(value),
+            Some((key, value))
+                if key != "proj" && params.iter().all(|(other, _)| *other != key) =>
+            {
+                params.push((key, value))
+            }
+            _ => return Err(ProjStringError::Malformed),
+        }
+    }
+
+    let name = name.ok_or(ProjStringError::Malformed)?;
+    let supported: &[&str] = match name {
+        "cea" | "eqc" => &["R", "lat_ts", "x_0", "y_0"],
+        "merc" | "moll" => &["R", "x_0", "y_0"],
+        "tmerc" => &["R", "lon_0", "x_0", "y_0"],
+        _ => return Err(ProjStringError::UnsupportedProjection),
+    };
+
+    if params.iter().any(|(key, _)| !supported.contains(key)) {
+        return Err(ProjStringError::UnsupportedParameter);
+    }
+
+    let values = params
+        .into_iter()
+        .map(|(key, value)| {
+            T::from_str_radix(value, 10)
+                .ok()
+                .filter(|value| value.is_finite())
+                .map(|value| (key, value))
+                .ok_or(ProjStringError::Malformed)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let param = |key: &str| {
+        values
+            .iter()
+            .find(|(other, _)| *other == key)
+            .map(|(_, value)| *value)
+    };
+
+    let radius = match param("R") {
+        Some(radius) if radius > T::zero() => radius.into(),
+        Some(_) => return Err(ProjStringError::Malformed),
+        None => T::one().into(),
+    };
+
+    let zero_or = |key: &str| param(key).unwrap_or_else(T::zero);
+    let degrees = |key: &str| zero_or(key).to_radians();
+
+    Ok(match name {
+        "cea" => Box::new(
+            CylindricalEqualArea::default()
+                .with_radius(radius)
+                .with_standard_parallel(degrees("lat_ts").into())
+                .with_false_easting(zero_or("x_0"))
+                .with_false_northing(zero_or("y_0")),
+        ),
+        "eqc" => Box::new(
+            Equirectangular::default()
+                .with_radius(radius)
+                .with_standard_parallel(degrees("lat_ts").into())
+                .with_false_easting(zero_or("x_0"))
+                .with_false_northing(zero_or("y_0")),
+        ),
+        "merc" => Box::new(
+            Mercator::default()
+                .with_radius(radius)
+                .with_false_easting(zero_or("x_0"))
+                .with_false_northing(zero_or("y_0")),
+        ),
+        "moll" => Box::new(
+            Mollweide::default()
+                .with_radius(radius)
+                .with_false_easting(zero_or("x_0"))
+                .with_false_northing(zero_or("y_0")),
+        ),
+        _ => Box::new(
+            TransverseMercator::default()
+                .with_radius(radius)
+                .with_central_meridian(degrees("lon_0").into())
+                .with_false_easting(zero_or("x_0"))
+                .with_false_northing(zero_or("y_0")),
+        ),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::FRAC_PI_4;
+
+    use crate::{
+        projection::{
+            from_proj_string, CylindricalEqualArea, Equirectangular, Mercator, Mollweide,
+            ProjStringError, Projection, TransverseMercator,
+        },
+        Geographic, Latitude, Longitude,
+    };
+
+    #[test]
+    fn proj_string_round_trip() {
+        struct Test {
+            name: &'static str,
+            projection: Box<dyn Projection<f64>>,
+            proj_string: String,
+            want: &'static str,
+        }
+
+        let cylindrical_equal_area = CylindricalEqualArea::default()
+            .with_radius(2.0.into())
+            .with_standard_parallel(Latitude::from(-FRAC_PI_4))
+            .with_false_easting(-1.)
+            .with_false_northing(3.);
+        let equirectangular = Equirectangular::default()
+            .with_radius(3.0.into())
+            .with_false_easting(7.);
+        let mercator = Mercator::default().with_radius(6_378_137.0.into());
+        let mollweide = Mollweide::default()
+            .with_radius(4.0.into())
+            .with_false_northing(-5.);
+        let transverse_mercator = TransverseMercator::default()
+            .with_radius(6_371_000.0.into())
+            .with_central_meridian(Longitude::from(-FRAC_PI_4))
+            .with_false_easting(500_000.)
+            .with_false_northing(10_000_000.);
+
+        vec![
+            Test {
+                name: "cylindrical equal area",
+                projection: Box::new(cylindrical_equal_area),
+                proj_string: cylindrical_equal_area.to_proj_string(),
+                want: "+proj=cea +R=2 +lat_ts=-45 +x_0=-1 +y_0=3",
+            },
+            Test {
+                name: "equirectangular",
+                projection: Box::new(equirectangular),
+                proj_string: equirectangular.to_proj_string(),
+                want: "+proj=eqc +R=3 +lat_ts=0 +x_0=7 +y_0=0",
+            },
+            Test {
+                name: "mercator",
+                projection: Box::new(mercator),
+                proj_string: mercator.to_proj_string(),
+                want: "+proj=merc +R=6378137 +x_0=0 +y_0=0",
+            },
+            Test {
+                name: "mollweide",
+                projection: Box::new(mollweide),
+                proj_string: mollweide.to_proj_string(),
+                want: "+proj=moll +R=4 +x_0=0 +y_0=-5",
+            },
+            Test {
+                name: "transverse mercator",
+                projection: Box::new(transverse_mercator),
+                proj_string: transverse_mercator.to_proj_string(),
+                want: "+proj=tmerc +R=6371000 +lon_0=-45 +x_0=500000 +y_0=10000000",
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            assert_eq!(
+                test.proj_string, test.want,
+                "{}: got proj string = {}, want {}",
+                test.name, test.proj_string, test.want
+            );
+
+            let parsed = from_proj_string::<f64>(&test.proj_string).unwrap();
+            [(0., 0.), (-1., 0.5), (0.3, -1.2), (2., 0.1)]
+                .into_iter()
+                .map(|(longitude, latitude)| {
+                    Geographic::origin()
+                        .with_longitude(Longitude::from(longitude))
+                        .with_latitude(Latitude::from(latitude))
+                })
+                .for_each(|point| {
+                    let got = parsed.forward(&point);
+                    let want = test.projection.forward(&point);
+
+                    assert!(
+                        got.distance(&want) < 1e-06,
+                        "{}: got forward of {:?} = {:?}, want {:?}",
+                        test.name,
+                        point,
+                        got,
+                        want
+                    );
+                });
+        });
+    }
+
+    #[test]
+    fn proj_string_errors() {
+        struct Test {
+            name: &'static str,
+            input: &'static str,
+            error: Option<ProjStringError>,
+        }
+
+        vec![
+            Test {
+                name: "ignored tokens must be accepted",
+                input: "+proj=moll +R=2 +units=m +no_defs +type=crs",
+                error: None,
+            },
+            Test {
+                name: "missing projection must be malformed",
+                input: "+R=2",
+                error: Some(ProjStringError::Malformed),
+            },
+            Test {
+                name: "token without plus sign must be malformed",
+                input: "+proj=merc R=2",
+                error: Some(ProjStringError::Malformed),
+            },
+            Test {
+                name: "non numeric value must be malformed",
+                input: "+proj=merc +R=earth",
+                error: Some(ProjStringError::Malformed),
+            },
+            Test {
+                name: "non positive radius must be malformed",
+                input: "+proj=merc +R=0",
+                error: Some(ProjStringError::Malformed),
+            },
+            Test {
+                name: "repeated parameter must be malformed",
+                input: "+proj=tmerc +lon_0=3 +lon_0=9",
+                error: Some(ProjStringError::Malformed),
+            },
+            Test {
+                name: "unknown projection must be unsupported",
+                input: "+proj=lcc +lat_1=33 +lat_2=45",
+                error: Some(ProjStringError::UnsupportedProjection),
+            },
+            Test {
+                name: "parameter of another projection must be unsupported",
+                input: "+proj=merc +lon_0=3",
+                error: Some(ProjStringError::UnsupportedParameter),
+            },
+            Test {
+                name: "non metric units must be unsupported",
+                input: "+proj=eqc +units=ft",
+                error: Some(ProjStringError::UnsupportedParameter),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let error = from_proj_string::<f64>(test.input).err();
+            assert_eq!(
+                error, test.error,
+                "{}: got error = {:?}, want {:?}",
+                test.name, error, test.error
+            );
+        });
+    }
+}
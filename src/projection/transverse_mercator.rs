@@ -0,0 +1,282 @@
+//! Transverse Mercator projection.
+
+use alloc::{format, string::String};
+use core::fmt::Display;
+
+use num_traits::{Euclid, Float, FloatConst, Signed};
+
+use crate::{positive::Positive, Cartesian, Geographic, Longitude};
+
+use super::Projection;
+
+/// Implements the spherical [Transverse Mercator projection](https://en.wikipedia.org/wiki/Transverse_Mercator_projection),
+/// a conformal projection of the sphere onto a cylinder tangent along the central meridian.
+///
+/// ## False origin
+/// The false easting and northing are added to every projected point (and subtracted before
+/// reversing it), so that the coordinates of the area of interest stay positive, as in official
+/// grids like the UTM one.
+///
+/// ## Singularities
+/// The two points on the equator 90° away from the central meridian are projected to infinity.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        deserialize = "T: serde::Deserialize<'de> + PartialOrd + Signed + Float + FloatConst + Euclid"
+    ))
+)]
+pub struct TransverseMercator<T> {
+    /// The radius of the projected sphere.
+    pub radius: Positive<T>,
+    /// The meridian along which the cylinder is tangent to the sphere.
+    pub central_meridian: Longitude<T>,
+    /// The value added to the x coordinate of every projected point.
+    #[cfg_attr(feature = "serde", serde(default = "num_traits::Zero::zero"))]
+    pub false_easting: T,
+    /// The value added to the y coordinate of every projected point.
+    #[cfg_attr(feature = "serde", serde(default = "num_traits::Zero::zero"))]
+    pub false_northing: T,
+}
+
+impl<T> Default for TransverseMercator<T>
+where
+    T: PartialOrd + Signed + Float + FloatConst + Euclid,
+{
+    fn default() -> Self {
+        Self {
+            radius: T::one().into(),
+            central_meridian: T::zero().into(),
+            false_easting: T::zero(),
+            false_northing: T::zero(),
+        }
+    }
+}
+
+impl<T> Projection<T> for TransverseMercator<T>
+where
+    T: PartialOrd + Signed + Float + FloatConst + Euclid,
+{
+    fn forward(&self, coords: &Geographic<T>) -> Cartesian<T> {
+        let longitude =
+            Longitude::from(coords.longitude.into_inner() - self.central_meridian.into_inner())
+                .into_inner();
+
+        let (sin_lat, cos_lat) = coords.latitude.into_inner().sin_cos();
+        let (sin_lon, cos_lon) = longitude.sin_cos();
+
+        Cartesian::origin()
+            .with_x(self.radius.into_inner() * (cos_lat * sin_lon).atanh() + self.false_easting)
+            .with_y(
+                self.radius.into_inner() * sin_lat.atan2(cos_lat * cos_lon) + self.false_northing,
+            )
+    }
+
+    fn reverse(&self, coords: &Cartesian<T>) -> Geographic<T> {
+        let x = (coords.x - self.false_easting) / self.radius.into_inner();
+        let y = (coords.y - self.false_northing) / self.radius.into_inner();
+
+        Geographic::origin()
+            .with_longitude((self.central_meridian.into_inner() + x.sinh().atan2(y.cos())).into())
+            .with_latitude((y.sin() / x.cosh()).asin().into())
+    }
+}
+
+impl<T> TransverseMercator<T> {
+    pub fn with_radius(self, radius: Positive<T>) -> Self {
+        Self { radius, ..self }
+    }
+
+    pub fn with_central_meridian(self, central_meridian: Longitude<T>) -> Self {
+        Self {
+            central_meridian,
+            ..self
+        }
+    }
+
+    pub fn with_false_easting(self, false_easting: T) -> Self {
+        Self {
+            false_easting,
+            ..self
+        }
+    }
+
+    pub fn with_false_northing(self, false_northing: T) -> Self {
+        Self {
+            false_northing,
+            ..self
+        }
+    }
+}
+
+impl<T> TransverseMercator<T>
+where
+    T: Float + Display,
+{
+    /// Returns the [PROJ](https://proj.org/) string describing self, as parsed by
+    /// [`from_proj_string`](super::from_proj_string).
+    pub fn to_proj_string(&self) -> String {
+        format!(
+            "+proj=tmerc +R={} +lon_0={} +x_0={} +y_0={}",
+            self.radius.into_inner(),
+            self.central_meridian.into_inner().to_degrees(),
+            self.false_easting,
+            self.false_northing
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::FRAC_PI_4;
+
+    use crate::{
+        projection::{Projection, TransverseMercator},
+        Cartesian, Geographic, Latitude, Longitude, Tolerance,
+    };
+
+    #[test]
+    fn transverse_mercator_projection() {
+        struct Test {
+            name: &'static str,
+            projection: TransverseMercator<f64>,
+            geographic: Geographic<f64>,
+            cartesian: Cartesian<f64>,
+        }
+
+        vec![
+            Test {
+                name: "origin must be projected to the cartesian origin",
+                projection: TransverseMercator::default(),
+                geographic: Geographic::origin(),
+                cartesian: Cartesian::origin(),
+            },
+            Test {
+                name: "central meridian must be projected to the y axis at scale",
+                projection: TransverseMercator::default()
+                    .with_radius(2.0.into())
+                    .with_central_meridian(Longitude::from(1.)),
+                geographic: Geographic::origin()
+                    .with_longitude(Longitude::from(1.))
+                    .with_latitude(Latitude::from(FRAC_PI_4)),
+                cartesian: Cartesian::origin().with_y(2. * FRAC_PI_4),
+            },
+            Test {
+                name: "equator must be projected as in the mercator",
+                projection: TransverseMercator::default(),
+                geographic: Geographic::origin().with_longitude(Longitude::from(-FRAC_PI_4)),
+                cartesian: Cartesian::origin().with_x(-(FRAC_PI_4 / 2. + FRAC_PI_4).tan().ln()),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let tolerance = 1e-09;
+
+            let forward = test.projection.forward(&test.geographic);
+            forward
+                .into_iter()
+                .zip(test.cartesian)
+                .for_each(|(got, want)| {
+                    assert!(
+                        (got - want).abs() < tolerance,
+                        "{}: got forward = {:?}, want {:?}",
+                        test.name,
+                        forward,
+                        test.cartesian
+                    );
+                });
+
+            let reverse = test.projection.reverse(&test.cartesian);
+            assert!(
+                (reverse.longitude.into_inner() - test.geographic.longitude.into_inner()).abs()
+                    < tolerance
+                    && (reverse.latitude.into_inner() - test.geographic.latitude.into_inner())
+                        .abs()
+                        < tolerance,
+                "{}: got reverse = {:?}, want {:?}",
+                test.name,
+                reverse,
+                test.geographic
+            );
+        });
+    }
+
+    #[test]
+    fn transverse_mercator_false_origin() {
+        struct Test {
+            name: &'static str,
+            false_easting: f64,
+            false_northing: f64,
+        }
+
+        vec![
+            Test {
+                name: "false easting must shift x",
+                false_easting: 500_000.,
+                false_northing: 0.,
+            },
+            Test {
+                name: "false northing must shift y",
+                false_easting: 0.,
+                false_northing: 10_000_000.,
+            },
+            Test {
+                name: "both offsets must shift both axes",
+                false_easting: -3.,
+                false_northing: 7.,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let projection = TransverseMercator::default()
+                .with_radius(6_371_000.0.into())
+                .with_central_meridian(Longitude::from(-0.05));
+
+            let shifted = projection
+                .with_false_easting(test.false_easting)
+                .with_false_northing(test.false_northing);
+
+            let point = Geographic::origin()
+                .with_longitude(Longitude::from(0.03))
+                .with_latitude(Latitude::from(0.8));
+
+            let (want, got) = (projection.forward(&point), shifted.forward(&point));
+            assert!(
+                ((got.x - want.x) - test.false_easting).abs() < 1e-06
+                    && ((got.y - want.y) - test.false_northing).abs() < 1e-06,
+                "{}: got forward = {:?}, want {:?} shifted by ({}, {})",
+                test.name,
+                got,
+                want,
+                test.false_easting,
+                test.false_northing
+            );
+
+            let reverse = shifted.reverse(&got);
+            assert!(
+                reverse
+                    .longitude
+                    .approx_eq(&point.longitude, Tolerance::default())
+                    && (reverse.latitude.into_inner() - point.latitude.into_inner()).abs() < 1e-09,
+                "{}: got reverse = {:?}, want {:?}",
+                test.name,
+                reverse,
+                point
+            );
+        });
+    }
+
+    #[test]
+    fn transverse_mercator_serde_default_false_origin() {
+        let projection: TransverseMercator<f64> =
+            serde_json::from_str(r#"{"radius":2.0,"central_meridian":0.5}"#).unwrap();
+
+        assert!(
+            projection.false_easting == 0. && projection.false_northing == 0.,
+            "got false origin = ({}, {}), want (0, 0)",
+            projection.false_easting,
+            projection.false_northing
+        );
+    }
+}
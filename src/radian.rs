@@ -1,13 +1,24 @@
 //! Radian unit.
 
-use std::ops::Mul;
+use core::ops::Mul;
 
 use num_traits::{Float, FloatConst, Signed};
 
 /// The [radian](https://en.wikipedia.org/wiki/Radian) unit, which is always a positive number
 /// within the range of [0, 2π).
+///
+/// ## Non-finite values
+/// Neither NaN nor infinite values describe any angle, hence any of them is normalized into zero
+/// instead of propagating through the range checks.
 #[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(
+        from = "T",
+        bound(deserialize = "T: serde::Deserialize<'de> + Signed + Float + FloatConst")
+    )
+)]
 pub struct Radian<T>(T);
 
 impl<T> From<T> for Radian<T>
@@ -19,12 +30,19 @@ where
             return Self(value);
         }
 
+        if !value.is_finite() {
+            return Self(T::zero());
+        }
+
         let mut modulus = value % T::TAU();
         if value.is_sign_negative() {
             modulus = (modulus + T::TAU()) % T::TAU();
         }
 
-        Self(modulus)
+        let radian = Self(modulus);
+        debug_assert!(radian.is_valid(), "normalized radian out of range");
+
+        radian
     }
 }
 
@@ -46,6 +64,19 @@ impl<T> Radian<T> {
     }
 }
 
+impl<T> Radian<T>
+where
+    T: Float + FloatConst,
+{
+    /// Returns true if, and only if, the inner value lies in the range [0, 2π).
+    ///
+    /// Values built through the normalizing constructor, deserialization included, always satisfy
+    /// this invariant.
+    pub fn is_valid(&self) -> bool {
+        (T::zero()..T::TAU()).contains(&self.0)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::f64::consts::{FRAC_PI_2, PI, TAU};
@@ -81,6 +112,21 @@ mod tests {
                 input: TAU + FRAC_PI_2,
                 output: FRAC_PI_2,
             },
+            Test {
+                name: "nan radiant must be zero",
+                input: f64::NAN,
+                output: 0.,
+            },
+            Test {
+                name: "positive infinite radiant must be zero",
+                input: f64::INFINITY,
+                output: 0.,
+            },
+            Test {
+                name: "negative infinite radiant must be zero",
+                input: f64::NEG_INFINITY,
+                output: 0.,
+            },
         ]
         .into_iter()
         .for_each(|test| {
@@ -93,4 +139,103 @@ mod tests {
             );
         });
     }
+
+    #[test]
+    fn radiant_must_not_exceed_boundaries_f32() {
+        use std::f32::consts::{FRAC_PI_2, PI, TAU};
+
+        struct Test {
+            name: &'static str,
+            input: f32,
+            output: f32,
+        }
+
+        vec![
+            Test {
+                name: "radiant within range must not change",
+                input: PI,
+                output: PI,
+            },
+            Test {
+                name: "negative radiant must change",
+                input: -FRAC_PI_2,
+                output: TAU - FRAC_PI_2,
+            },
+            Test {
+                name: "overflowing radiant must change",
+                input: TAU + FRAC_PI_2,
+                output: FRAC_PI_2,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let radiant = Radian::from(test.input).into_inner();
+            let tolerance = 1e-06;
+
+            assert!(
+                (radiant - test.output).abs() < tolerance,
+                "{}: got radiant = {}, want {}",
+                test.name,
+                radiant,
+                test.output
+            );
+        });
+    }
+
+    #[test]
+    fn radiant_is_valid() {
+        struct Test {
+            name: &'static str,
+            input: Radian<f64>,
+            is_valid: bool,
+        }
+
+        vec![
+            Test {
+                name: "radiant within range must be valid",
+                input: Radian(3.),
+                is_valid: true,
+            },
+            Test {
+                name: "2π radiants must be invalid",
+                input: Radian(TAU),
+                is_valid: false,
+            },
+            Test {
+                name: "negative radiant must be invalid",
+                input: Radian(-1.),
+                is_valid: false,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let is_valid = test.input.is_valid();
+            assert_eq!(
+                is_valid, test.is_valid,
+                "{}: got is_valid = {}, want {}",
+                test.name, is_valid, test.is_valid
+            );
+
+            let normalized = Radian::from(test.input.into_inner());
+            assert!(
+                normalized.is_valid(),
+                "{}: got normalized radiant = {:?}, want a valid one",
+                test.name,
+                normalized
+            );
+        });
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn radiant_deserialize_must_normalize() {
+        let radiant: Radian<f64> = serde_json::from_str("-1.0").unwrap();
+        let want = Radian::from(-1.);
+
+        assert_eq!(
+            radiant, want,
+            "got deserialized radiant = {:?}, want {:?}",
+            radiant, want
+        );
+    }
 }
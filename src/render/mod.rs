@@ -0,0 +1,5 @@
+//! Rendering helpers.
+
+mod viewport;
+
+pub use self::viewport::Viewport;
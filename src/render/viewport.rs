@@ -0,0 +1,178 @@
+//! Viewport definition.
+
+use num_traits::Float;
+
+use crate::{projection::Projection, shape::Aabb, Cartesian, Geographic};
+
+/// Maps geographic points into the pixels of an image through a projection.
+///
+/// ## Definition
+/// The bounds select the region of the projected plane to render (only their x and y coordinates
+/// are taken into account), which is stretched to fill the whole image. Pixel coordinates grow
+/// from the top-left corner of the image to the right and downwards, hence the y axis of the
+/// projected plane is flipped.
+///
+/// ## Example
+/// ```
+/// use std::f64::consts::{FRAC_PI_2, PI};
+///
+/// use geocart::{
+///     projection::Equirectangular, render::Viewport, shape::Aabb, Cartesian, Geographic,
+///     Latitude, Longitude,
+/// };
+///
+/// let viewport = Viewport::new(
+///     Equirectangular::default(),
+///     Aabb {
+///         min: Cartesian::new(-PI, -FRAC_PI_2, 0.),
+///         max: Cartesian::new(PI, FRAC_PI_2, 0.),
+///     },
+///     360.,
+///     180.,
+/// );
+///
+/// let north_pole = Geographic::origin().with_latitude(Latitude::from(FRAC_PI_2));
+/// assert_eq!(viewport.to_pixel(&north_pole), (180., 0.));
+/// ```
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Viewport<T, P> {
+    /// The projection from the globe to the plane.
+    pub projection: P,
+    /// The region of the projected plane to render.
+    pub bounds: Aabb<T>,
+    /// The width of the image (in pixels).
+    pub width: T,
+    /// The height of the image (in pixels).
+    pub height: T,
+}
+
+impl<T, P> Viewport<T, P>
+where
+    T: Float,
+    P: Projection<T>,
+{
+    /// Returns the pixel coordinates (x, y) at which the given point is rendered.
+    pub fn to_pixel(&self, coords: &Geographic<T>) -> (T, T) {
+        let projected = self.projection.forward(coords);
+        let (min, max) = (self.bounds.min, self.bounds.max);
+
+        (
+            (projected.x - min.x) / (max.x - min.x) * self.width,
+            (max.y - projected.y) / (max.y - min.y) * self.height,
+        )
+    }
+
+    /// Returns the geographic point rendered at the given pixel coordinates.
+    pub fn from_pixel(&self, x: T, y: T) -> Geographic<T> {
+        let (min, max) = (self.bounds.min, self.bounds.max);
+        let projected = Cartesian::origin()
+            .with_x(min.x + x / self.width * (max.x - min.x))
+            .with_y(max.y - y / self.height * (max.y - min.y));
+
+        self.projection.reverse(&projected)
+    }
+}
+
+impl<T, P> Viewport<T, P> {
+    /// Returns the viewport rendering the given bounds of the projected plane into an image of
+    /// the given size.
+    pub fn new(projection: P, bounds: Aabb<T>, width: T, height: T) -> Self {
+        Self {
+            projection,
+            bounds,
+            width,
+            height,
+        }
+    }
+
+    pub fn with_projection(self, projection: P) -> Self {
+        Self { projection, ..self }
+    }
+
+    pub fn with_bounds(self, bounds: Aabb<T>) -> Self {
+        Self { bounds, ..self }
+    }
+
+    pub fn with_width(self, width: T) -> Self {
+        Self { width, ..self }
+    }
+
+    pub fn with_height(self, height: T) -> Self {
+        Self { height, ..self }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        projection::{Equirectangular, Projection},
+        render::Viewport,
+        shape::Aabb,
+        Cartesian,
+    };
+
+    #[test]
+    fn viewport_corners() {
+        struct Test {
+            name: &'static str,
+            projected: Cartesian<f64>,
+            pixel: (f64, f64),
+        }
+
+        let projection = Equirectangular::default();
+        let viewport = Viewport::new(
+            projection,
+            Aabb {
+                min: Cartesian::new(-1., -0.5, 0.),
+                max: Cartesian::new(2., 1., 0.),
+            },
+            300.,
+            150.,
+        );
+
+        vec![
+            Test {
+                name: "minimum corner must be at the bottom left",
+                projected: viewport.bounds.min,
+                pixel: (0., 150.),
+            },
+            Test {
+                name: "maximum corner must be at the top right",
+                projected: viewport.bounds.max,
+                pixel: (300., 0.),
+            },
+            Test {
+                name: "center must be at the center",
+                projected: Cartesian::new(0.5, 0.25, 0.),
+                pixel: (150., 75.),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let coords = projection.reverse(&test.projected);
+            let pixel = viewport.to_pixel(&coords);
+            let tolerance = 1e-09;
+
+            assert!(
+                (pixel.0 - test.pixel.0).abs() < tolerance
+                    && (pixel.1 - test.pixel.1).abs() < tolerance,
+                "{}: got pixel = {:?}, want {:?}",
+                test.name,
+                pixel,
+                test.pixel
+            );
+
+            let back = viewport.from_pixel(pixel.0, pixel.1);
+            assert!(
+                (back.longitude.into_inner() - coords.longitude.into_inner()).abs() < tolerance
+                    && (back.latitude.into_inner() - coords.latitude.into_inner()).abs()
+                        < tolerance,
+                "{}: got geographic = {:?}, want {:?}",
+                test.name,
+                back,
+                coords
+            );
+        });
+    }
+}
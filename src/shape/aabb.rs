@@ -0,0 +1,208 @@
+//! Axis-aligned bounding box.
+
+use num_traits::Float;
+
+use crate::Cartesian;
+
+/// An [axis-aligned bounding box](https://en.wikipedia.org/wiki/Minimum_bounding_box) in the
+/// cartesian space.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Aabb<T> {
+    /// The corner of the box with the smallest coordinates.
+    pub min: Cartesian<T>,
+    /// The corner of the box with the greatest coordinates.
+    pub max: Cartesian<T>,
+}
+
+impl<T> Aabb<T>
+where
+    T: Float,
+{
+    /// Returns the smallest box containing all the given points, or [`None`] if there are no
+    /// points.
+    pub fn from_points<I>(points: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = Cartesian<T>>,
+    {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+
+        Some(points.fold(
+            Self {
+                min: first,
+                max: first,
+            },
+            |aabb, point| Self {
+                min: aabb.min.min(&point),
+                max: aabb.max.max(&point),
+            },
+        ))
+    }
+
+    /// Returns true if, and only if, the given point lies inside the box (boundaries included).
+    pub fn contains(&self, point: &Cartesian<T>) -> bool {
+        point.clamp(&self.min, &self.max) == *point
+    }
+
+    /// Returns the smallest box containing both, self and rhs.
+    pub fn union(&self, rhs: &Self) -> Self {
+        Self {
+            min: self.min.min(&rhs.min),
+            max: self.max.max(&rhs.max),
+        }
+    }
+
+    /// Returns the box shared by both, self and rhs, or [`None`] if they are disjoint.
+    pub fn intersection(&self, rhs: &Self) -> Option<Self> {
+        let intersection = Self {
+            min: self.min.max(&rhs.min),
+            max: self.max.min(&rhs.max),
+        };
+
+        intersection
+            .min
+            .into_iter()
+            .zip(intersection.max)
+            .all(|(min, max)| min <= max)
+            .then_some(intersection)
+    }
+
+    /// Returns the center of the box.
+    pub fn center(&self) -> Cartesian<T> {
+        (self.min + self.max) / (T::one() + T::one())
+    }
+}
+
+impl<T> Aabb<T> {
+    pub fn with_min(self, min: Cartesian<T>) -> Self {
+        Self { min, ..self }
+    }
+
+    pub fn with_max(self, max: Cartesian<T>) -> Self {
+        Self { max, ..self }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{shape::Aabb, Cartesian};
+
+    #[test]
+    fn aabb_from_points() {
+        let aabb = Aabb::from_points([
+            Cartesian::new(1., -2., 3.),
+            Cartesian::new(-1., 4., 0.),
+            Cartesian::new(0., 0., 5.),
+        ]);
+
+        let want = Aabb::default()
+            .with_min(Cartesian::new(-1., -2., 0.))
+            .with_max(Cartesian::new(1., 4., 5.));
+
+        assert_eq!(aabb, Some(want), "got aabb = {aabb:?}, want {want:?}");
+        assert_eq!(Aabb::<f64>::from_points([]), None);
+    }
+
+    #[test]
+    fn aabb_contains() {
+        struct Test {
+            name: &'static str,
+            point: Cartesian<f64>,
+            contains: bool,
+        }
+
+        let aabb = Aabb::default().with_max(Cartesian::new(1., 1., 1.));
+
+        vec![
+            Test {
+                name: "inner point must be contained",
+                point: Cartesian::new(0.5, 0.5, 0.5),
+                contains: true,
+            },
+            Test {
+                name: "corner must be contained",
+                point: Cartesian::new(1., 1., 1.),
+                contains: true,
+            },
+            Test {
+                name: "outer point must not be contained",
+                point: Cartesian::new(0.5, 1.5, 0.5),
+                contains: false,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let contains = aabb.contains(&test.point);
+
+            assert_eq!(
+                contains, test.contains,
+                "{}: got contains = {}, want {}",
+                test.name, contains, test.contains
+            );
+        });
+    }
+
+    #[test]
+    fn aabb_union_and_intersection() {
+        struct Test {
+            name: &'static str,
+            lhs: Aabb<f64>,
+            rhs: Aabb<f64>,
+            union: Aabb<f64>,
+            intersection: Option<Aabb<f64>>,
+        }
+
+        vec![
+            Test {
+                name: "overlapping boxes",
+                lhs: Aabb::default().with_max(Cartesian::new(2., 2., 2.)),
+                rhs: Aabb::default()
+                    .with_min(Cartesian::new(1., 1., 1.))
+                    .with_max(Cartesian::new(3., 3., 3.)),
+                union: Aabb::default().with_max(Cartesian::new(3., 3., 3.)),
+                intersection: Some(
+                    Aabb::default()
+                        .with_min(Cartesian::new(1., 1., 1.))
+                        .with_max(Cartesian::new(2., 2., 2.)),
+                ),
+            },
+            Test {
+                name: "disjoint boxes",
+                lhs: Aabb::default().with_max(Cartesian::new(1., 1., 1.)),
+                rhs: Aabb::default()
+                    .with_min(Cartesian::new(2., 0., 0.))
+                    .with_max(Cartesian::new(3., 1., 1.)),
+                union: Aabb::default().with_max(Cartesian::new(3., 1., 1.)),
+                intersection: None,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let union = test.lhs.union(&test.rhs);
+            assert_eq!(
+                union, test.union,
+                "{}: got union = {:?}, want {:?}",
+                test.name, union, test.union
+            );
+
+            let intersection = test.lhs.intersection(&test.rhs);
+            assert_eq!(
+                intersection, test.intersection,
+                "{}: got intersection = {:?}, want {:?}",
+                test.name, intersection, test.intersection
+            );
+        });
+    }
+
+    #[test]
+    fn aabb_center() {
+        let center = Aabb::default()
+            .with_min(Cartesian::new(-1., 0., 2.))
+            .with_max(Cartesian::new(3., 2., 2.))
+            .center();
+
+        let want = Cartesian::new(1., 1., 2.);
+        assert_eq!(center, want, "got center = {center:?}, want {want:?}");
+    }
+}
@@ -0,0 +1,163 @@
+//! Adaptive arc shape iterator.
+
+use core::num::NonZeroUsize;
+
+use num_traits::{Euclid, Float, FloatConst, Signed};
+
+use crate::Geographic;
+
+use super::{arc::ArcIter, Arc};
+
+/// An arc between two points in a globe whose amount of segments is decided by the maximum
+/// deviation allowed between each segment (straight line) and the true arc.
+///
+/// ## Definition
+/// The deviation of a segment is its [sagitta](https://en.wikipedia.org/wiki/Sagitta_(geometry)),
+/// the distance between the midpoint of the chord and the midpoint of the arc it subtends. Since
+/// the arc is computed on the unit sphere, the tolerance is relative to the radius of the globe.
+/// Both endpoints are always emitted.
+///
+/// A null tolerance cannot be honored by any finite amount of segments, so tolerances below the
+/// machine epsilon are taken as the epsilon itself: the strictest tolerance yields the densest
+/// arc.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        deserialize = "T: serde::Deserialize<'de> + PartialOrd + Signed + Float + FloatConst + Euclid"
+    ))
+)]
+pub struct AdaptiveArc<T> {
+    /// The initial endpoint of the arc.
+    pub from: Geographic<T>,
+    /// The final endpoint of the arc.
+    pub to: Geographic<T>,
+    /// The maximum sagitta allowed for each segment of the arc.
+    pub tolerance: T,
+}
+
+impl<T> IntoIterator for AdaptiveArc<T>
+where
+    T: Default + PartialOrd + Signed + Float + FloatConst + Euclid,
+{
+    type Item = Geographic<T>;
+
+    type IntoIter = ArcIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        // the sagitta of a chord subtending an angle θ in the unit circle is 1 - cos(θ/2), hence
+        // the greatest angle a segment may subtend is 2·acos(1 - tolerance).
+        let tolerance = self.tolerance.max(T::epsilon());
+        let max_angular_step = (T::one() - tolerance).acos() * (T::one() + T::one());
+
+        Arc::new(NonZeroUsize::MIN)
+            .with_from(self.from)
+            .with_to(self.to)
+            .with_max_angular_step(max_angular_step)
+            .into_iter()
+    }
+}
+
+impl<T> AdaptiveArc<T>
+where
+    T: Default,
+{
+    pub fn new(tolerance: T) -> Self {
+        Self {
+            from: Default::default(),
+            to: Default::default(),
+            tolerance,
+        }
+    }
+}
+
+impl<T> AdaptiveArc<T> {
+    pub fn with_from(self, from: Geographic<T>) -> Self {
+        Self { from, ..self }
+    }
+
+    pub fn with_to(self, to: Geographic<T>) -> Self {
+        Self { to, ..self }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{shape::AdaptiveArc, Geographic, Longitude};
+
+    #[test]
+    fn adaptive_arc_iter() {
+        struct Test {
+            name: &'static str,
+            arc: AdaptiveArc<f64>,
+            min_points: usize,
+            max_points: usize,
+        }
+
+        vec![
+            Test {
+                name: "tiny arc must yield just the endpoints",
+                arc: AdaptiveArc::new(1e-3)
+                    .with_from(Geographic::origin())
+                    .with_to(Geographic::origin().with_longitude(Longitude::from(1e-3))),
+                min_points: 2,
+                max_points: 2,
+            },
+            Test {
+                name: "almost a semicircle must yield many points",
+                arc: AdaptiveArc::new(1e-3)
+                    .with_from(Geographic::origin())
+                    .with_to(Geographic::origin().with_longitude(Longitude::from(3.))),
+                min_points: 30,
+                max_points: usize::MAX,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let points: Vec<_> = test.arc.into_iter().collect();
+
+            assert!(
+                (test.min_points..=test.max_points).contains(&points.len()),
+                "{}: got {} points, want within [{}, {}]",
+                test.name,
+                points.len(),
+                test.min_points,
+                test.max_points
+            );
+
+            let tolerance = 1e-09;
+            let first = points.first().unwrap();
+            let last = points.last().unwrap();
+
+            assert!(
+                first.longitude.into_inner().abs() < tolerance
+                    && (last.longitude.into_inner() - test.arc.to.longitude.into_inner()).abs()
+                        < tolerance,
+                "{}: got endpoints {:?} and {:?}, want {:?} and {:?}",
+                test.name,
+                first,
+                last,
+                test.arc.from,
+                test.arc.to
+            );
+        });
+    }
+
+    #[test]
+    fn adaptive_arc_null_tolerance() {
+        let arc = |tolerance: f64| {
+            AdaptiveArc::new(tolerance)
+                .with_from(Geographic::origin())
+                .with_to(Geographic::origin().with_longitude(Longitude::from(1e-3)))
+        };
+
+        let loose = arc(1e-09).into_iter().count();
+        let strictest = arc(0.).into_iter().count();
+
+        assert!(
+            strictest > loose && loose > 2,
+            "got {strictest} points for a null tolerance, want more than {loose}"
+        );
+    }
+}
@@ -1,17 +1,36 @@
 //! Arc shape iterator.
 
-use std::num::NonZeroUsize;
+use alloc::{vec, vec::Vec};
+use core::num::NonZeroUsize;
 
 use num_traits::{Euclid, Float, FloatConst, Signed};
 
 use crate::{
     transform::{Rotation, Transform},
-    Cartesian, Geographic,
+    Cartesian, Geographic, Latitude, Longitude, Radian,
 };
 
+use super::Polygon;
+
 /// An arc between two points in a globe.
+///
+/// ## Antipodal endpoints
+/// Infinitely many great circles go through antipodal endpoints. By default, the arc between them
+/// follows the meridian of the initial endpoint heading north, or the prime meridian if it is a
+/// pole. Any other path can be chosen through [`Arc::into_iter_via`].
+///
+/// ## Precision
+/// The angle between both endpoints is computed as the arccosine of the dot product of their unit
+/// vectors, which loses precision for short arcs. When using `f32`, arcs shorter than about 1e-3
+/// radians are not reliable.
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        deserialize = "T: serde::Deserialize<'de> + PartialOrd + Signed + Float + FloatConst + Euclid"
+    ))
+)]
 pub struct Arc<T> {
     /// The initial endpoint of the arc.
     pub from: Geographic<T>,
@@ -19,8 +38,43 @@ pub struct Arc<T> {
     pub to: Geographic<T>,
     /// The total amount of segments (straight lines) the arc is made of.
     pub segments: NonZeroUsize,
+    /// The function mapping the uniform position of each point along the arc, as a fraction in
+    /// the range [0, 1], into its eased position. It is not serialized, and defaults to linear.
+    #[cfg_attr(feature = "serde", serde(skip, default = "linear_easing"))]
+    easing: fn(T) -> T,
+}
+
+/// Returns the easing keeping the points of an [`Arc`] evenly spaced.
+#[cfg(feature = "serde")]
+fn linear_easing<T>() -> fn(T) -> T {
+    linear
+}
+
+/// The identity easing, which leaves the position of each point untouched.
+fn linear<T>(fraction: T) -> T {
+    fraction
+}
+
+/// The reason why an [`Arc`] cannot be built.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArcError {
+    /// Both endpoints are the same point, so infinitely many great circles go through them.
+    CoincidentEndpoints,
+    /// Both endpoints are antipodal, so infinitely many great circles go through them.
+    AntipodalEndpoints,
+}
+
+impl core::fmt::Display for ArcError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::CoincidentEndpoints => write!(f, "the endpoints of the arc are the same"),
+            Self::AntipodalEndpoints => write!(f, "the endpoints of the arc are antipodal"),
+        }
+    }
 }
 
+impl core::error::Error for ArcError {}
+
 impl<T> IntoIterator for Arc<T>
 where
     T: Default + PartialOrd + Signed + Float + FloatConst + Euclid,
@@ -30,34 +84,109 @@ where
     type IntoIter = ArcIter<T>;
 
     fn into_iter(self) -> Self::IntoIter {
+        self.prepared().iter()
+    }
+}
+
+impl<T> Arc<T>
+where
+    T: Default + PartialOrd + Signed + Float + FloatConst + Euclid,
+{
+    /// Returns the arc with all the values its iteration depends on computed beforehand, so it can
+    /// be iterated many times at no extra cost.
+    pub fn prepared(self) -> PreparedArc<T> {
         let from = self.from.into_cartesian().normal();
         let to = self.to.into_cartesian().normal();
 
-        let rotation = Rotation::noop().with_axis(from.cross(&to)).with_theta(
-            T::from(self.segments.get())
-                .map(|segments| {
-                    // the formula for the angle (in radians) between the two vectors is the
-                    // arccosine of the division between the dot product and the product of the
-                    // magnitudes.
-                    //
-                    // Assuming both vectors are normalized (magnitude = 1), the formula can be
-                    // simplified as the arccosine of the dot product.
-                    from.dot(&to).acos() / segments
-                })
-                .unwrap_or_default()
-                .into(),
-        );
+        let axis = self.normal().unwrap_or_else(|| {
+            if from.dot(&to) < T::zero() {
+                antipodal_axis(&from)
+            } else {
+                Cartesian::origin()
+            }
+        });
 
-        ArcIter {
-            from,
-            to,
-            total_segments: self.segments.get(),
-            next_segment: 0,
-            rotation,
+        // the formula for the angle (in radians) between the two vectors is the arccosine of the
+        // division between the dot product and the product of the magnitudes.
+        //
+        // Assuming both vectors are normalized (magnitude = 1), the formula can be simplified as
+        // the arccosine of the dot product.
+        self.prepared_about(axis, from.dot(&to).acos())
+    }
+
+    /// Returns an iterator over the points of the arc paired with their position along it, as a
+    /// fraction of the whole arc going from 0 (at the initial endpoint) to 1 (at the final one).
+    ///
+    /// The position of each point is the eased one, see [`Arc::with_easing`].
+    pub fn enumerate_fractions(&self) -> impl Iterator<Item = (T, Geographic<T>)> {
+        let segments = T::from(self.segments.get()).unwrap_or_else(T::one);
+        let easing = self.easing;
+
+        self.into_iter().enumerate().map(move |(index, point)| {
+            let fraction = T::from(index).map(|index| easing(index / segments));
+            (fraction.unwrap_or_else(T::one), point)
+        })
+    }
+
+    /// Returns the iterator over the arc following the great circle that goes through the
+    /// initial endpoint and the given waypoint, heading towards the latter.
+    ///
+    /// This disambiguates the path between antipodal endpoints, which are connected by infinitely
+    /// many great circles. The final endpoint is expected to lie on the same great circle, since
+    /// the waypoint only decides the plane and the direction of the arc. If the waypoint is the
+    /// initial endpoint or its antipode, it is ignored.
+    pub fn into_iter_via(self, via: &Geographic<T>) -> ArcIter<T> {
+        let from = self.from.into_cartesian().normal();
+        let to = self.to.into_cartesian().normal();
+
+        let axis = from.cross(&via.into_cartesian().normal());
+        if axis.magnitude() <= T::epsilon() {
+            return self.into_iter();
+        }
+
+        let axis = axis.normal();
+
+        // the angle from the initial endpoint to the final one rotating about the axis, which may
+        // be greater than π if the waypoint is along the longest path.
+        let angle = Float::atan2(from.cross(&to).dot(&axis), from.dot(&to));
+        let angle = if angle < T::zero() {
+            angle + T::TAU()
+        } else {
+            angle
+        };
+
+        self.prepared_about(axis, angle).iter()
+    }
+
+    /// Returns the arc prepared to rotate the given angle about the given axis.
+    fn prepared_about(self, axis: Cartesian<T>, angle: T) -> PreparedArc<T> {
+        PreparedArc {
+            from: self.from.into_cartesian().normal(),
+            to: self.to.into_cartesian().normal(),
+            axis,
+            angle,
+            segments: self.segments,
+            easing: self.easing,
+            rotation: Rotation::noop(),
         }
+        .with_segments(self.segments)
     }
 }
 
+/// Returns the axis of the default great circle through the given antipodal endpoints, which is
+/// the meridian of the initial endpoint heading north (or the prime meridian at the poles).
+fn antipodal_axis<T>(from: &Cartesian<T>) -> Cartesian<T>
+where
+    T: Float,
+{
+    let axis = from.cross(&Cartesian::origin().with_z(T::one()));
+    if axis.magnitude() > T::epsilon() {
+        return axis;
+    }
+
+    from.cross(&Cartesian::origin().with_x(T::one()))
+}
+
 impl<T> Arc<T>
 where
     T: Default,
@@ -67,7 +196,508 @@ where
             from: Default::default(),
             to: Default::default(),
             segments,
+            easing: linear,
+        }
+    }
+}
+
+impl<T> Arc<T>
+where
+    T: PartialOrd + Signed + Float + FloatConst + Euclid,
+{
+    /// Returns the arc between the given endpoints, or an error if its great circle is undefined.
+    ///
+    /// Unlike building the arc field by field, this constructor guarantees the arc has a
+    /// [normal](Arc::normal), and so that iterating over it follows a well defined path.
+    pub fn try_new(
+        from: Geographic<T>,
+        to: Geographic<T>,
+        segments: NonZeroUsize,
+    ) -> Result<Self, ArcError> {
+        let arc = Self {
+            from,
+            to,
+            segments,
+            easing: linear,
+        };
+        if arc.normal().is_some() {
+            return Ok(arc);
+        }
+
+        if from.into_cartesian().dot(&to.into_cartesian()) > T::zero() {
+            Err(ArcError::CoincidentEndpoints)
+        } else {
+            Err(ArcError::AntipodalEndpoints)
+        }
+    }
+
+    /// Returns the arc departing from the given point with the given initial bearing (clockwise
+    /// from the north) and travelling the given angular distance (in radians).
+    pub fn from_bearing_distance(
+        from: Geographic<T>,
+        bearing: Radian<T>,
+        angular_distance: T,
+        segments: NonZeroUsize,
+    ) -> Self {
+        Self {
+            from,
+            to: from.destination(bearing, angular_distance),
+            segments,
+            easing: linear,
+        }
+    }
+
+    /// Returns the point of the arc that is closest to the given query.
+    ///
+    /// The query is projected onto the plane of the arc's great circle. If the foot of that
+    /// perpendicular lies outside the arc span, the closest endpoint is returned instead.
+    pub fn closest_point(&self, query: &Geographic<T>) -> Geographic<T> {
+        let from = self.from.into_cartesian().normal();
+        let to = self.to.into_cartesian().normal();
+        let point = query.into_cartesian().normal();
+
+        let foot = self.normal().and_then(|normal| {
+            let foot = (point - normal * normal.dot(&point)).normal();
+
+            self.spans(&foot).then_some(foot)
+        });
+
+        [Some(from), Some(to), foot]
+            .into_iter()
+            .flatten()
+            .fold(from, |closest, candidate| {
+                // the greater the dot product between two unit vectors, the smaller the angle
+                // between them.
+                if candidate.dot(&point) > closest.dot(&point) {
+                    candidate
+                } else {
+                    closest
+                }
+            })
+            .into()
+    }
+
+    /// Returns the highest latitude the arc reaches, which is the one of the northernmost point of
+    /// its great circle (its vertex) if it lies within the arc, or the one of its highest
+    /// endpoint otherwise.
+    ///
+    /// If the arc has no [normal](Arc::normal), only its endpoints are taken into account.
+    pub fn max_latitude(&self) -> Latitude<T> {
+        self.extreme_latitude(T::one())
+    }
+
+    /// Returns the lowest latitude the arc reaches, which is the one of the southernmost point of
+    /// its great circle if it lies within the arc, or the one of its lowest endpoint otherwise.
+    ///
+    /// If the arc has no [normal](Arc::normal), only its endpoints are taken into account.
+    pub fn min_latitude(&self) -> Latitude<T> {
+        self.extreme_latitude(-T::one())
+    }
+
+    /// Returns the latitude of the point of the arc that is the furthest in the given direction
+    /// along the axis of the globe (1 for the north, -1 for the south).
+    fn extreme_latitude(&self, direction: T) -> Latitude<T> {
+        let from = self.from.into_cartesian().normal();
+        let to = self.to.into_cartesian().normal();
+
+        // the furthest point of the great circle along the axis is the projection of the axis
+        // onto the plane of the circle.
+        let vertex = self.normal().and_then(|normal| {
+            let axis = Cartesian::origin().with_z(direction);
+            let vertex = axis - normal * normal.dot(&axis);
+            let magnitude = vertex.magnitude();
+            if magnitude <= T::epsilon() {
+                return None;
+            }
+
+            let vertex = vertex / magnitude;
+
+            self.spans(&vertex).then_some(vertex)
+        });
+
+        let furthest = [Some(from), Some(to), vertex]
+            .into_iter()
+            .flatten()
+            .map(|point| point.z * direction)
+            .fold(-T::one(), T::max);
+
+        // the height of a unit vector along the axis is the sine of its latitude.
+        (furthest * direction)
+            .max(-T::one())
+            .min(T::one())
+            .asin()
+            .into()
+    }
+
+    /// Returns true if, and only if, the given unit vector on the arc's great circle lies within
+    /// the arc, with some tolerance for the endpoints.
+    ///
+    /// That is, if it is reached rotating from the initial endpoint and the final endpoint is
+    /// reached rotating from it, both in the arc direction. If the arc has no
+    /// [normal](Arc::normal), no point lies within it.
+    fn spans(&self, point: &Cartesian<T>) -> bool {
+        let Some(normal) = self.normal() else {
+            return false;
+        };
+
+        let from = self.from.into_cartesian().normal();
+        let to = self.to.into_cartesian().normal();
+
+        from.cross(point).dot(&normal) >= -T::epsilon()
+            && point.cross(&to).dot(&normal) >= -T::epsilon()
+    }
+
+    /// Returns the unit vector normal to the plane of the arc's great circle, oriented according
+    /// to the right hand rule from the initial endpoint to the final one.
+    ///
+    /// If both endpoints are the same or antipodal the plane is undefined, and so is its normal.
+    pub fn normal(&self) -> Option<Cartesian<T>> {
+        let from = self.from.into_cartesian().normal();
+        let to = self.to.into_cartesian().normal();
+
+        let normal = from.cross(&to);
+        let magnitude = normal.magnitude();
+        (magnitude > T::epsilon()).then(|| normal / magnitude)
+    }
+
+    /// Returns the portion of the arc that lies on the hemisphere the given normal points
+    /// towards, or [`None`] if the whole arc is hidden.
+    ///
+    /// The arc is trimmed at the point where it crosses the great circle bounding the hemisphere.
+    /// Points exactly on the boundary are considered visible.
+    pub fn clip_to_hemisphere(&self, boundary_normal: &Cartesian<T>) -> Option<Self> {
+        let from = self.from.into_cartesian().normal();
+        let to = self.to.into_cartesian().normal();
+
+        let from_side = from.dot(boundary_normal);
+        let to_side = to.dot(boundary_normal);
+
+        let crossing = || -> Geographic<T> {
+            // the chord between both endpoints crosses the boundary plane at the same direction
+            // the arc does.
+            let t = from_side / (from_side - to_side);
+            (from + (to - from) * t).normal().into()
+        };
+
+        match (from_side >= T::zero(), to_side >= T::zero()) {
+            (true, true) => Some(*self),
+            (true, false) => Some(self.with_to(crossing())),
+            (false, true) => Some(self.with_from(crossing())),
+            (false, false) => None,
+        }
+    }
+
+    /// Returns the points of the arc where its great circle crosses the parallel at the given
+    /// latitude, sorted from the initial endpoint to the final one.
+    ///
+    /// A great circle crosses a parallel at most twice. If the arc's great circle is undefined or
+    /// it is the parallel itself (the equator), no crossing is returned.
+    pub fn crossings_at_latitude(&self, latitude: Latitude<T>) -> Vec<Geographic<T>> {
+        let Some(normal) = self.normal() else {
+            return Vec::new();
+        };
+
+        let from = self.from.into_cartesian().normal();
+
+        // the parallel is the circle of radius cos(lat) at height sin(lat), and the great circle
+        // is the intersection of the sphere with the plane orthogonal to the normal. Both circles
+        // meet where the line shared by both planes intersects the parallel.
+        let (height, radius) = latitude.into_inner().sin_cos();
+        let horizontal = normal.x.hypot(normal.y);
+        if horizontal <= T::epsilon() {
+            return Vec::new();
+        }
+
+        // unit vector towards the closest point of the line to the axis of the globe.
+        let towards = Cartesian::origin()
+            .with_x(normal.x / horizontal)
+            .with_y(normal.y / horizontal);
+
+        let offset = -normal.z * height / horizontal;
+
+        // the discriminant accumulates the rounding errors of the trigonometric functions, so the
+        // tangency is detected within a few units in the last place.
+        let tangency = T::epsilon() * T::from(32).unwrap_or_else(T::one);
+        let discriminant = radius * radius - offset * offset;
+        if discriminant < -tangency {
+            return Vec::new();
+        }
+
+        let along = Cartesian::origin().with_x(-towards.y).with_y(towards.x);
+        let half_chord = discriminant.max(T::zero()).sqrt();
+        let center = (towards * offset).with_z(height);
+
+        let mut crossings = Vec::with_capacity(2);
+        if discriminant <= tangency {
+            crossings.push(center);
+        } else {
+            crossings.push(center + along * half_chord);
+            crossings.push(center - along * half_chord);
         }
+
+        crossings.retain(|crossing| self.spans(crossing));
+
+        crossings.sort_by(|a, b| {
+            from.dot(b)
+                .partial_cmp(&from.dot(a))
+                .unwrap_or(core::cmp::Ordering::Equal)
+        });
+
+        crossings.into_iter().map(Into::into).collect()
+    }
+
+    /// Returns the points where the arc crosses the meridians at the given longitudes, in the same
+    /// order, as the waypoints of a great-circle route.
+    ///
+    /// Longitudes whose meridian is not crossed within the arc are skipped. If the arc's great
+    /// circle is undefined, or it is a meridian itself, no waypoint is returned.
+    pub fn waypoints_at_longitudes(&self, lons: &[Longitude<T>]) -> Vec<Geographic<T>> {
+        let Some(normal) = self.normal() else {
+            return Vec::new();
+        };
+
+        lons.iter()
+            .filter_map(|&longitude| {
+                let (sin, cos) = longitude.into_inner().sin_cos();
+
+                // the meridian is the half of the great circle orthogonal to this normal on the
+                // side of the given longitude, so the waypoint is on the line shared by both
+                // planes.
+                let meridian = Cartesian::origin().with_x(-sin).with_y(cos);
+                let line = normal.cross(&meridian);
+                let magnitude = line.magnitude();
+                if magnitude <= T::epsilon() {
+                    return None;
+                }
+
+                let mut waypoint = line / magnitude;
+                if waypoint.x * cos + waypoint.y * sin < T::zero() {
+                    waypoint = waypoint * -T::one();
+                }
+
+                self.spans(&waypoint)
+                    .then(|| Geographic::from(waypoint).with_longitude(longitude))
+            })
+            .collect()
+    }
+
+    /// Returns the arc split where it crosses the antimeridian, so that each part lies entirely on
+    /// one side of it, as required by most 2D renderers.
+    ///
+    /// An arc not crossing the antimeridian (or just touching it at an endpoint) is returned as
+    /// is. Otherwise both parts share the crossing point, whose longitude is always -π: renderers
+    /// must take it as +π for the eastern part. The segments of the arc are split between both
+    /// parts in proportion to their length, with at least one for each.
+    pub fn split_at_antimeridian(&self) -> Vec<Self> {
+        let Some(crossing) = self
+            .waypoints_at_longitudes(&[Longitude::from(-T::PI())])
+            .into_iter()
+            .next()
+        else {
+            return vec![*self];
+        };
+
+        let west = self.from.central_angle(&crossing);
+        let east = crossing.central_angle(&self.to);
+        if west <= T::epsilon() || east <= T::epsilon() {
+            return vec![*self];
+        }
+
+        let segments = |angle: T| {
+            T::from(self.segments.get())
+                .map(|segments| (segments * angle / (west + east)).round())
+                .and_then(|segments| segments.to_usize())
+                .and_then(NonZeroUsize::new)
+                .unwrap_or(NonZeroUsize::MIN)
+        };
+
+        vec![
+            Self {
+                to: crossing,
+                segments: segments(west),
+                ..*self
+            },
+            Self {
+                from: crossing,
+                segments: segments(east),
+                ..*self
+            },
+        ]
+    }
+
+    /// Returns the points of the arc sampled in such a way that no segment exceeds the given
+    /// angle (in radians).
+    ///
+    /// A non-positive step yields just the endpoints of the arc. Since any easing would break the
+    /// bound on the segments, the points are always evenly spaced.
+    pub fn densify(&self, max_angular_step: T) -> Vec<Geographic<T>>
+    where
+        T: Default,
+    {
+        self.with_easing(linear)
+            .with_max_angular_step(max_angular_step)
+            .into_iter()
+            .collect()
+    }
+
+    /// Returns a copy of self with the least amount of segments such that none of them exceeds
+    /// the given angle (in radians).
+    pub(super) fn with_max_angular_step(self, max_angular_step: T) -> Self {
+        let segments = (self.central_angle() / max_angular_step).ceil();
+        self.with_fractional_segments(segments)
+    }
+
+    /// Returns a copy of self with the given amount of segments, or a single one if it is not a
+    /// positive integer.
+    fn with_fractional_segments(self, segments: T) -> Self {
+        let segments = segments
+            .to_usize()
+            .and_then(NonZeroUsize::new)
+            .unwrap_or(NonZeroUsize::MIN);
+
+        Self { segments, ..self }
+    }
+
+    /// Returns the polygon enclosing all points whose angular distance (in radians) to the arc is
+    /// not greater than the given radius.
+    ///
+    /// The sides of the polygon follow the arc at both sides, sampled at the given amount of
+    /// segments each. Both ends are closed by rounded caps, half circles centered at the
+    /// endpoints of the arc sampled at that same amount of segments. The radius must be small
+    /// enough for the polygon to be smaller than a hemisphere.
+    ///
+    /// If the arc has no [normal](Arc::normal) the polygon is empty.
+    pub fn buffer(&self, angular_radius: T, segments: NonZeroUsize) -> Polygon<T>
+    where
+        T: Default,
+    {
+        let Some(normal) = self.normal() else {
+            return Polygon::default();
+        };
+
+        let (radius_sin, radius_cos) = angular_radius.sin_cos();
+        let offset = |point: &Cartesian<T>, direction: Cartesian<T>| -> Geographic<T> {
+            (*point * radius_cos + direction * radius_sin).into()
+        };
+
+        let sides: Vec<Cartesian<T>> = Self { segments, ..*self }
+            .into_iter()
+            .map(|point| point.into_cartesian().normal())
+            .collect();
+
+        // each cap sweeps half a circle around the endpoint, from one side of the arc to the
+        // other through the given direction.
+        let cap = |point: Cartesian<T>, from: Cartesian<T>, through: Cartesian<T>| {
+            let step = T::PI() / T::from(segments.get()).unwrap_or_else(T::one);
+            (1..segments.get()).map(move |index| {
+                let (sin, cos) = (step * T::from(index).unwrap_or_else(T::zero)).sin_cos();
+                offset(&point, from * cos + through * sin)
+            })
+        };
+
+        let first = sides[0];
+        let last = sides[sides.len() - 1];
+
+        // the left of the arc at any of its points is the normal of its plane, while the
+        // direction of travel is the cross product of that normal and the point.
+        let (left, right) = (normal, normal * -T::one());
+
+        let vertices = sides
+            .iter()
+            .map(|point| offset(point, right))
+            .chain(cap(last, right, normal.cross(&last)))
+            .chain(sides.iter().rev().map(|point| offset(point, left)))
+            .chain(cap(first, left, first.cross(&normal)))
+            .collect();
+
+        Polygon::new(vertices)
+    }
+
+    /// Returns the iterator over the arc between the given endpoints sampled in such a way that
+    /// no segment exceeds the given angular step, always including both endpoints.
+    ///
+    /// A step greater than the arc (or a null one) yields just the endpoints of the arc.
+    pub fn by_angular_step(from: Geographic<T>, to: Geographic<T>, step: Radian<T>) -> ArcIter<T>
+    where
+        T: Default,
+    {
+        let arc = Self::new(NonZeroUsize::MIN).with_from(from).with_to(to);
+        let segments = arc.central_angle() / step.into_inner();
+
+        // steps dividing the arc exactly must not add an extra segment due to precision errors.
+        let rounded = segments.round();
+        let segments = if (segments - rounded).abs() <= T::epsilon().sqrt() {
+            rounded
+        } else {
+            segments.ceil()
+        };
+
+        arc.with_fractional_segments(segments).into_iter()
+    }
+
+    /// Returns the points of the arc between the given endpoints spaced by the given chord length
+    /// (the straight-line distance) on the unit sphere, starting at the initial endpoint.
+    ///
+    /// Equal chords subtend equal angles of 2·asin(chord / 2), so consecutive points are that
+    /// angle apart, except for the final endpoint, which is always included and may be closer to
+    /// its predecessor. A chord not shorter than the one between both endpoints (or a non-positive
+    /// one) yields just the endpoints of the arc.
+    pub fn by_chord_length(from: Geographic<T>, to: Geographic<T>, chord: T) -> Vec<Geographic<T>>
+    where
+        T: Default,
+    {
+        let arc = Self::new(NonZeroUsize::MIN).with_from(from).with_to(to);
+        let total = arc.central_angle();
+
+        let two = T::one() + T::one();
+        let step = two * (chord / two).asin();
+        if step.is_nan() || step <= T::zero() || step >= total {
+            return arc.into_iter().collect();
+        }
+
+        let prepared = arc.prepared();
+        let steps = (total / step).floor().to_usize().unwrap_or_default();
+
+        let mut points: Vec<_> = (0..=steps)
+            .filter_map(|index| {
+                let rotation = Rotation::noop()
+                    .with_axis(prepared.axis)
+                    .with_theta((step * T::from(index)?).into());
+
+                Some(rotation.transform(prepared.from).into())
+            })
+            .collect();
+
+        // the final endpoint must not be repeated if the chord divides the arc exactly.
+        if total - step * T::from(steps).unwrap_or_default() > T::epsilon().sqrt() {
+            points.push(prepared.to.into());
+        } else if let Some(last) = points.last_mut() {
+            *last = prepared.to.into();
+        }
+
+        points
+    }
+
+    /// Returns the greatest deviation between the segments (straight lines) of the arc and the
+    /// true arc, relative to the radius of the globe.
+    ///
+    /// That is the [sagitta](https://en.wikipedia.org/wiki/Sagitta_(geometry)) of each segment:
+    /// the distance between the midpoint of its chord and the midpoint of the arc it subtends,
+    /// which is the same for all of them. Same as in [`AdaptiveArc`](super::AdaptiveArc), it
+    /// tells whether more segments are required for the arc to meet a given tolerance.
+    pub fn max_sagitta(&self) -> T {
+        let segments = T::from(self.segments.get()).unwrap_or_else(T::one);
+        let half_step = self.central_angle() / (segments + segments);
+
+        T::one() - half_step.cos()
+    }
+
+    /// Returns the angle (in radians) between both endpoints of the arc.
+    fn central_angle(&self) -> T {
+        let from = self.from.into_cartesian().normal();
+        let to = self.to.into_cartesian().normal();
+
+        from.dot(&to).acos()
     }
 }
 
@@ -79,6 +709,96 @@ impl<T> Arc<T> {
     pub fn with_to(self, to: Geographic<T>) -> Self {
         Self { to, ..self }
     }
+
+    /// Returns a copy of self whose points are placed along the arc according to the given
+    /// easing, for non-uniform sampling like the one required by animations.
+    ///
+    /// The easing maps the uniform position of each point, as a fraction in the range [0, 1],
+    /// into the position it must be placed at. It is expected to map 0 into 0, while the final
+    /// endpoint is always the last point of the arc.
+    pub fn with_easing(self, easing: fn(T) -> T) -> Self {
+        Self { easing, ..self }
+    }
+
+    /// Returns the easing the points of the arc are placed according to.
+    pub fn easing(&self) -> fn(T) -> T {
+        self.easing
+    }
+}
+
+/// An [`Arc`] whose endpoints, axis and per-segment rotation have been computed once, so it can be
+/// iterated many times, or resampled with a different amount of segments, without recomputing
+/// them.
+///
+/// ## Example
+/// ```
+/// use std::num::NonZeroUsize;
+///
+/// use geocart::{shape::Arc, Geographic, Longitude};
+///
+/// let arc = Arc::new(NonZeroUsize::new(4).unwrap())
+///     .with_from(Geographic::origin())
+///     .with_to(Geographic::origin().with_longitude(Longitude::from(1.)));
+///
+/// let prepared = arc.prepared();
+/// assert!(prepared.iter().eq(arc));
+///
+/// let coarse = prepared.with_segments(NonZeroUsize::new(2).unwrap());
+/// assert_eq!(coarse.iter().count(), 3);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct PreparedArc<T> {
+    from: Cartesian<T>,
+    to: Cartesian<T>,
+    axis: Cartesian<T>,
+    angle: T,
+    segments: NonZeroUsize,
+    easing: fn(T) -> T,
+    rotation: Rotation<T>,
+}
+
+impl<T> PreparedArc<T>
+where
+    T: Copy,
+{
+    /// Returns an iterator over the arc.
+    pub fn iter(&self) -> ArcIter<T> {
+        ArcIter {
+            from: self.from,
+            to: self.to,
+            total_segments: self.segments.get(),
+            next_segment: 0,
+            easing: self.easing,
+            rotation: self.rotation,
+        }
+    }
+
+    /// Returns the total amount of segments the arc is made of.
+    pub fn segments(&self) -> NonZeroUsize {
+        self.segments
+    }
+}
+
+impl<T> PreparedArc<T>
+where
+    T: Default + Signed + Float + FloatConst,
+{
+    /// Returns a copy of self made of the given amount of segments, reusing everything but the
+    /// per-segment rotation.
+    pub fn with_segments(self, segments: NonZeroUsize) -> Self {
+        let rotation = Rotation::noop().with_axis(self.axis).with_theta(
+            T::from(segments.get())
+                .map(|segments| self.angle / segments)
+                .unwrap_or_default()
+                .into(),
+        );
+
+        Self {
+            segments,
+            rotation,
+            ..self
+        }
+    }
 }
 
 /// Iterator over the [`Arc`] shape.
@@ -88,6 +808,7 @@ pub struct ArcIter<T> {
     to: Cartesian<T>,
     total_segments: usize,
     next_segment: usize,
+    easing: fn(T) -> T,
     rotation: Rotation<T>,
 }
 
@@ -103,12 +824,17 @@ where
         }
 
         if self.next_segment == self.total_segments {
+            self.next_segment += 1;
             return Some(self.to.into());
         }
 
+        // the eased position is given as a fraction of the arc, while the rotation is per segment.
+        let total_segments = T::from(self.total_segments)?;
+        let position = (self.easing)(T::from(self.next_segment)? / total_segments);
+
         let next = Rotation::noop()
             .with_axis(self.rotation.axis)
-            .with_theta(self.rotation.theta * T::from(self.next_segment)?)
+            .with_theta(self.rotation.theta * (position * total_segments))
             .transform(self.from)
             .into();
 
@@ -116,3 +842,1425 @@ where
         Some(next)
     }
 }
+
+/// Returns the index of the arc closest to the given query, together with the
+/// [closest point](Arc::closest_point) on it, or [`None`] if there are no arcs.
+///
+/// Arcs are compared by the angular distance between the query and their closest point, so the
+/// first of them wins in case of a tie.
+pub fn nearest_on_arcs<T>(query: &Geographic<T>, arcs: &[Arc<T>]) -> Option<(usize, Geographic<T>)>
+where
+    T: PartialOrd + Signed + Float + FloatConst + Euclid,
+{
+    arcs.iter()
+        .map(|arc| arc.closest_point(query))
+        .enumerate()
+        .map(|(index, closest)| (index, closest, query.central_angle(&closest)))
+        .fold(None, |nearest, candidate| match nearest {
+            Some((_, _, distance)) if distance <= candidate.2 => nearest,
+            _ => Some(candidate),
+        })
+        .map(|(index, closest, _)| (index, closest))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        f64::consts::{FRAC_PI_2, FRAC_PI_4, PI},
+        num::NonZeroUsize,
+    };
+
+    use crate::{
+        shape::{nearest_on_arcs, Arc, ArcError},
+        Cartesian, Geographic, Latitude, Longitude, Tolerance,
+    };
+
+    #[test]
+    fn arc_normal() {
+        struct Test {
+            name: &'static str,
+            arc: Arc<f64>,
+            normal: Option<Cartesian<f64>>,
+        }
+
+        vec![
+            Test {
+                name: "eastward equatorial arc must be normal to the north",
+                arc: Arc::new(NonZeroUsize::MIN)
+                    .with_from(Geographic::origin())
+                    .with_to(Geographic::origin().with_longitude(Longitude::from(0.3))),
+                normal: Some(Cartesian::origin().with_z(1.)),
+            },
+            Test {
+                name: "westward equatorial arc must be normal to the south",
+                arc: Arc::new(NonZeroUsize::MIN)
+                    .with_from(Geographic::origin())
+                    .with_to(Geographic::origin().with_longitude(Longitude::from(-2.))),
+                normal: Some(Cartesian::origin().with_z(-1.)),
+            },
+            Test {
+                name: "antipodal endpoints must have no normal",
+                arc: Arc::new(NonZeroUsize::MIN)
+                    .with_from(Geographic::origin())
+                    .with_to(Geographic::origin().with_longitude(Longitude::from(PI))),
+                normal: None,
+            },
+            Test {
+                name: "coincident endpoints must have no normal",
+                arc: Arc::new(NonZeroUsize::MIN)
+                    .with_from(Geographic::origin().with_latitude(Latitude::from(0.2)))
+                    .with_to(Geographic::origin().with_latitude(Latitude::from(0.2))),
+                normal: None,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let normal = test.arc.normal();
+            let tolerance = 1e-09;
+
+            match (normal, test.normal) {
+                (Some(got), Some(want)) => got.into_iter().zip(want).for_each(|(got, want)| {
+                    assert!(
+                        (got - want).abs() < tolerance,
+                        "{}: got normal = {:?}, want {:?}",
+                        test.name,
+                        normal,
+                        test.normal
+                    );
+                }),
+                (got, want) => assert_eq!(
+                    got, want,
+                    "{}: got normal = {:?}, want {:?}",
+                    test.name, got, want
+                ),
+            }
+        });
+    }
+
+    #[test]
+    fn arc_iter_must_follow_the_great_circle() {
+        let arc = Arc::new(NonZeroUsize::new(3).unwrap())
+            .with_from(Geographic::origin())
+            .with_to(Geographic::origin().with_longitude(Longitude::from(0.3)));
+
+        let tolerance = 1e-09;
+        arc.into_iter().enumerate().for_each(|(index, point)| {
+            let want = 0.1 * index as f64;
+
+            assert!(
+                (point.longitude.into_inner() - want).abs() < tolerance
+                    && point.latitude.into_inner().abs() < tolerance
+                    && (point.altitude.into_inner() - 1.).abs() < tolerance,
+                "got point = {point:?}, want longitude {want} on the unit sphere"
+            );
+        });
+    }
+
+    #[test]
+    fn arc_densify() {
+        struct Test {
+            name: &'static str,
+            arc: Arc<f64>,
+            step: f64,
+            min_points: usize,
+        }
+
+        vec![
+            Test {
+                name: "quarter of the equator must be split in small segments",
+                arc: Arc::new(NonZeroUsize::MIN)
+                    .with_from(Geographic::origin())
+                    .with_to(Geographic::origin().with_longitude(Longitude::from(FRAC_PI_2))),
+                step: 0.1,
+                min_points: 16,
+            },
+            Test {
+                name: "step larger than the arc must yield the endpoints",
+                arc: Arc::new(NonZeroUsize::MIN)
+                    .with_from(Geographic::origin())
+                    .with_to(Geographic::origin().with_longitude(Longitude::from(0.05))),
+                step: 0.1,
+                min_points: 2,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let points = test.arc.densify(test.step);
+
+            assert!(
+                points.len() >= test.min_points,
+                "{}: got {} points, want at least {}",
+                test.name,
+                points.len(),
+                test.min_points
+            );
+
+            let tolerance = 1e-09;
+            points.windows(2).for_each(|segment| {
+                let angle = segment[0]
+                    .into_cartesian()
+                    .normal()
+                    .dot(&segment[1].into_cartesian().normal())
+                    .acos();
+
+                assert!(
+                    angle <= test.step + tolerance,
+                    "{}: got segment of {} radians, want at most {}",
+                    test.name,
+                    angle,
+                    test.step
+                );
+            });
+        });
+    }
+
+    #[test]
+    fn arc_closest_point() {
+        struct Test {
+            name: &'static str,
+            arc: Arc<f64>,
+            query: Geographic<f64>,
+            output: Geographic<f64>,
+        }
+
+        let equatorial_arc = Arc::new(NonZeroUsize::MIN)
+            .with_from(Geographic::origin())
+            .with_to(Geographic::origin().with_longitude(Longitude::from(FRAC_PI_2)));
+
+        vec![
+            Test {
+                name: "point above the middle of the arc must snap to the midpoint",
+                arc: equatorial_arc,
+                query: Geographic::origin()
+                    .with_longitude(Longitude::from(FRAC_PI_4))
+                    .with_latitude(Latitude::from(0.5)),
+                output: Geographic::origin().with_longitude(Longitude::from(FRAC_PI_4)),
+            },
+            Test {
+                name: "point beyond the initial endpoint must snap to it",
+                arc: equatorial_arc,
+                query: Geographic::origin().with_longitude(Longitude::from(-FRAC_PI_4)),
+                output: Geographic::origin(),
+            },
+            Test {
+                name: "point beyond the final endpoint must snap to it",
+                arc: equatorial_arc,
+                query: Geographic::origin()
+                    .with_longitude(Longitude::from(3. * FRAC_PI_4))
+                    .with_latitude(Latitude::from(-0.5)),
+                output: Geographic::origin().with_longitude(Longitude::from(FRAC_PI_2)),
+            },
+            Test {
+                name: "point on the arc must not change",
+                arc: equatorial_arc,
+                query: Geographic::origin().with_longitude(Longitude::from(0.3)),
+                output: Geographic::origin().with_longitude(Longitude::from(0.3)),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let closest = test.arc.closest_point(&test.query);
+            let tolerance = 1e-09;
+
+            assert!(
+                (closest.longitude.into_inner() - test.output.longitude.into_inner()).abs()
+                    < tolerance
+                    && (closest.latitude.into_inner() - test.output.latitude.into_inner()).abs()
+                        < tolerance,
+                "{}: got closest point = {:?}, want {:?}",
+                test.name,
+                closest,
+                test.output
+            );
+        });
+    }
+
+    #[test]
+    fn arc_iter_f32() {
+        use std::f32::consts::FRAC_PI_2;
+
+        let arc = Arc::new(NonZeroUsize::new(4).unwrap())
+            .with_from(Geographic::origin())
+            .with_to(Geographic::origin().with_longitude(Longitude::from(FRAC_PI_2)));
+
+        let tolerance = 1e-06;
+        let points: Vec<_> = arc.into_iter().collect();
+
+        assert_eq!(points.len(), 5, "got {} points, want 5", points.len());
+        points.into_iter().enumerate().for_each(|(index, point)| {
+            let want = FRAC_PI_2 * index as f32 / 4.;
+
+            assert!(
+                (point.longitude.into_inner() - want).abs() < tolerance
+                    && point.latitude.into_inner().abs() < tolerance,
+                "got point = {point:?}, want longitude {want}"
+            );
+        });
+    }
+
+    #[test]
+    fn arc_clip_to_hemisphere() {
+        struct Test {
+            name: &'static str,
+            arc: Arc<f64>,
+            output: Option<(Geographic<f64>, Geographic<f64>)>,
+        }
+
+        let northern_normal = Cartesian::origin().with_z(1.);
+
+        vec![
+            Test {
+                name: "fully visible arc must not change",
+                arc: Arc::new(NonZeroUsize::MIN)
+                    .with_from(Geographic::origin().with_latitude(Latitude::from(0.1)))
+                    .with_to(Geographic::origin().with_latitude(Latitude::from(0.5))),
+                output: Some((
+                    Geographic::origin().with_latitude(Latitude::from(0.1)),
+                    Geographic::origin().with_latitude(Latitude::from(0.5)),
+                )),
+            },
+            Test {
+                name: "fully hidden arc must be none",
+                arc: Arc::new(NonZeroUsize::MIN)
+                    .with_from(Geographic::origin().with_latitude(Latitude::from(-0.1)))
+                    .with_to(
+                        Geographic::origin()
+                            .with_longitude(Longitude::from(1.))
+                            .with_latitude(Latitude::from(-0.5)),
+                    ),
+                output: None,
+            },
+            Test {
+                name: "straddling arc must be trimmed at the boundary",
+                arc: Arc::new(NonZeroUsize::MIN)
+                    .with_from(Geographic::origin().with_latitude(Latitude::from(-0.5)))
+                    .with_to(Geographic::origin().with_latitude(Latitude::from(0.5))),
+                output: Some((
+                    Geographic::origin(),
+                    Geographic::origin().with_latitude(Latitude::from(0.5)),
+                )),
+            },
+            Test {
+                name: "arc leaving the hemisphere must be trimmed at the boundary",
+                arc: Arc::new(NonZeroUsize::MIN)
+                    .with_from(Geographic::origin().with_latitude(Latitude::from(0.5)))
+                    .with_to(Geographic::origin().with_latitude(Latitude::from(-0.2))),
+                output: Some((
+                    Geographic::origin().with_latitude(Latitude::from(0.5)),
+                    Geographic::origin(),
+                )),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let clipped = test.arc.clip_to_hemisphere(&northern_normal);
+            let tolerance = 1e-09;
+
+            let matches = |got: &Geographic<f64>, want: &Geographic<f64>| {
+                (got.longitude.into_inner() - want.longitude.into_inner()).abs() < tolerance
+                    && (got.latitude.into_inner() - want.latitude.into_inner()).abs() < tolerance
+            };
+
+            assert!(
+                match (&clipped, &test.output) {
+                    (Some(got), Some((from, to))) =>
+                        matches(&got.from, from) && matches(&got.to, to),
+                    (None, None) => true,
+                    _ => false,
+                },
+                "{}: got clipped = {:?}, want {:?}",
+                test.name,
+                clipped,
+                test.output
+            );
+        });
+    }
+
+    #[test]
+    fn arc_from_bearing_distance() {
+        let arc = Arc::from_bearing_distance(
+            Geographic::origin().with_longitude(Longitude::from(0.2)),
+            FRAC_PI_2.into(),
+            0.4,
+            NonZeroUsize::MIN,
+        );
+
+        let want = Geographic::origin().with_longitude(Longitude::from(0.6));
+        let tolerance = 1e-09;
+
+        assert!(
+            (arc.to.longitude.into_inner() - want.longitude.into_inner()).abs() < tolerance
+                && arc.to.latitude.into_inner().abs() < tolerance,
+            "got to = {:?}, want {:?}",
+            arc.to,
+            want
+        );
+    }
+
+    #[test]
+    fn arc_crossings_at_latitude() {
+        struct Test {
+            name: &'static str,
+            arc: Arc<f64>,
+            latitude: Latitude<f64>,
+            crossings: Vec<Geographic<f64>>,
+        }
+
+        // great circle inclined 45 degrees whose ascending node is at the longitude -PI/2,
+        // parametrized by the angle from that node.
+        let tilted = |angle: f64| -> Geographic<f64> {
+            Cartesian::origin()
+                .with_x(angle.sin() * FRAC_PI_4.cos())
+                .with_y(-angle.cos())
+                .with_z(angle.sin() * FRAC_PI_4.sin())
+                .into()
+        };
+
+        let crossing_angle = (0.3_f64.sin() / FRAC_PI_4.sin()).asin();
+
+        vec![
+            Test {
+                name: "tilted arc must cross a mid latitude twice",
+                arc: Arc::new(NonZeroUsize::MIN)
+                    .with_from(tilted(0.1))
+                    .with_to(tilted(PI - 0.1)),
+                latitude: Latitude::from(0.3),
+                crossings: vec![tilted(crossing_angle), tilted(PI - crossing_angle)],
+            },
+            Test {
+                name: "crossings out of the arc span must be discarded",
+                arc: Arc::new(NonZeroUsize::MIN)
+                    .with_from(tilted(0.1))
+                    .with_to(tilted(1.5)),
+                latitude: Latitude::from(0.3),
+                crossings: vec![tilted(crossing_angle)],
+            },
+            Test {
+                name: "tilted arc must touch its maximum latitude once",
+                arc: Arc::new(NonZeroUsize::MIN)
+                    .with_from(tilted(0.1))
+                    .with_to(tilted(PI - 0.1)),
+                latitude: Latitude::from(FRAC_PI_4),
+                crossings: vec![tilted(FRAC_PI_2)],
+            },
+            Test {
+                name: "flat equatorial arc must never reach a high latitude",
+                arc: Arc::new(NonZeroUsize::MIN)
+                    .with_from(Geographic::origin())
+                    .with_to(Geographic::origin().with_longitude(Longitude::from(2.))),
+                latitude: Latitude::from(1.),
+                crossings: vec![],
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let crossings = test.arc.crossings_at_latitude(test.latitude);
+            let tolerance = 1e-06;
+
+            assert_eq!(
+                crossings.len(),
+                test.crossings.len(),
+                "{}: got crossings = {:?}, want {:?}",
+                test.name,
+                crossings,
+                test.crossings
+            );
+
+            crossings
+                .iter()
+                .zip(&test.crossings)
+                .for_each(|(got, want)| {
+                    assert!(
+                        got.distance(want) < tolerance,
+                        "{}: got crossings = {:?}, want {:?}",
+                        test.name,
+                        crossings,
+                        test.crossings
+                    );
+                });
+        });
+    }
+
+    #[test]
+    fn arc_try_new() {
+        struct Test {
+            name: &'static str,
+            from: Geographic<f64>,
+            to: Geographic<f64>,
+            result: Result<(), ArcError>,
+        }
+
+        vec![
+            Test {
+                name: "distinct endpoints must build the arc",
+                from: Geographic::origin(),
+                to: Geographic::origin().with_latitude(Latitude::from(1.)),
+                result: Ok(()),
+            },
+            Test {
+                name: "coincident endpoints must fail",
+                from: Geographic::origin().with_longitude(Longitude::from(0.5)),
+                to: Geographic::origin().with_longitude(Longitude::from(0.5)),
+                result: Err(ArcError::CoincidentEndpoints),
+            },
+            Test {
+                name: "antipodal endpoints must fail",
+                from: Geographic::origin().with_latitude(Latitude::from(-FRAC_PI_2)),
+                to: Geographic::origin().with_latitude(Latitude::from(FRAC_PI_2)),
+                result: Err(ArcError::AntipodalEndpoints),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let result = Arc::try_new(test.from, test.to, NonZeroUsize::MIN).map(|arc| {
+                assert_eq!(
+                    arc.from, test.from,
+                    "{}: got from = {:?}",
+                    test.name, arc.from
+                );
+                assert_eq!(arc.to, test.to, "{}: got to = {:?}", test.name, arc.to);
+            });
+
+            assert_eq!(
+                result, test.result,
+                "{}: got result = {:?}, want {:?}",
+                test.name, result, test.result
+            );
+        });
+    }
+
+    #[test]
+    fn arc_max_sagitta() {
+        struct Test {
+            name: &'static str,
+            arc: Arc<f64>,
+            sagitta: f64,
+        }
+
+        vec![
+            Test {
+                name: "tiny arc must have a near zero sagitta",
+                arc: Arc::new(NonZeroUsize::MIN)
+                    .with_from(Geographic::origin())
+                    .with_to(Geographic::origin().with_longitude(Longitude::from(1e-4))),
+                sagitta: 1. - 0.5e-4_f64.cos(),
+            },
+            Test {
+                name: "semicircle must deviate the whole radius",
+                arc: Arc::new(NonZeroUsize::MIN)
+                    .with_from(Geographic::origin())
+                    .with_to(Geographic::origin().with_longitude(Longitude::from(PI))),
+                sagitta: 1.,
+            },
+            Test {
+                name: "more segments must reduce the sagitta",
+                arc: Arc::new(NonZeroUsize::new(2).unwrap())
+                    .with_from(Geographic::origin())
+                    .with_to(Geographic::origin().with_longitude(Longitude::from(PI))),
+                sagitta: 1. - FRAC_PI_4.cos(),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let sagitta = test.arc.max_sagitta();
+            let tolerance = 1e-09;
+
+            assert!(
+                (sagitta - test.sagitta).abs() < tolerance,
+                "{}: got sagitta = {}, want {}",
+                test.name,
+                sagitta,
+                test.sagitta
+            );
+        });
+    }
+
+    #[test]
+    fn arc_buffer() {
+        struct Test {
+            name: &'static str,
+            point: Geographic<f64>,
+            contains: bool,
+        }
+
+        let arc = Arc::new(NonZeroUsize::MIN)
+            .with_from(Geographic::origin())
+            .with_to(
+                Geographic::origin()
+                    .with_longitude(Longitude::from(1.))
+                    .with_latitude(Latitude::from(0.5)),
+            );
+
+        let radius: f64 = 0.1;
+        let buffer = arc.buffer(radius, NonZeroUsize::new(8).unwrap());
+        let normal = arc.normal().unwrap();
+
+        arc.densify(0.05).into_iter().for_each(|point| {
+            assert!(
+                buffer.contains(&point),
+                "arc point {point:?} must be inside the buffer"
+            );
+        });
+
+        let midpoint = (arc.from.into_cartesian() + arc.to.into_cartesian()).normal();
+
+        vec![
+            Test {
+                name: "point just inside the radius at the left",
+                point: (midpoint * (radius * 0.9).cos() + normal * (radius * 0.9).sin()).into(),
+                contains: true,
+            },
+            Test {
+                name: "point just beyond the radius at the left",
+                point: (midpoint * (radius * 1.1).cos() + normal * (radius * 1.1).sin()).into(),
+                contains: false,
+            },
+            Test {
+                name: "point just beyond the radius at the right",
+                point: (midpoint * (radius * 1.1).cos() - normal * (radius * 1.1).sin()).into(),
+                contains: false,
+            },
+            Test {
+                name: "point just inside the initial cap",
+                point: Geographic::origin().with_longitude(Longitude::from(-0.09)),
+                contains: true,
+            },
+            Test {
+                name: "point just beyond the initial cap",
+                point: Geographic::origin().with_longitude(Longitude::from(-0.11)),
+                contains: false,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let contains = buffer.contains(&test.point);
+            assert_eq!(
+                contains, test.contains,
+                "{}: got contains = {}, want {}",
+                test.name, contains, test.contains
+            );
+        });
+    }
+
+    #[test]
+    fn arc_by_angular_step() {
+        struct Test {
+            name: &'static str,
+            to: Geographic<f64>,
+            step: f64,
+            points: usize,
+        }
+
+        vec![
+            Test {
+                name: "step dividing the arc must yield both endpoints and the inner points",
+                to: Geographic::origin().with_latitude(Latitude::from(FRAC_PI_2)),
+                step: PI / 6.,
+                points: 4,
+            },
+            Test {
+                name: "step not dividing the arc must not exceed it",
+                to: Geographic::origin().with_latitude(Latitude::from(FRAC_PI_2)),
+                step: 0.5,
+                points: 5,
+            },
+            Test {
+                name: "step greater than the arc must yield just the endpoints",
+                to: Geographic::origin().with_longitude(Longitude::from(0.2)),
+                step: 1.,
+                points: 2,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let points: Vec<_> =
+                Arc::by_angular_step(Geographic::origin(), test.to, test.step.into()).collect();
+
+            assert_eq!(
+                points.len(),
+                test.points,
+                "{}: got points = {:?}",
+                test.name,
+                points
+            );
+
+            let tolerance = 1e-09;
+            let last = points[points.len() - 1];
+            assert!(
+                last.distance_meters(&test.to, 1.) < tolerance,
+                "{}: got last point = {:?}, want {:?}",
+                test.name,
+                last,
+                test.to
+            );
+        });
+    }
+
+    #[test]
+    fn arc_antipodal_endpoints() {
+        struct Test {
+            name: &'static str,
+            arc: Arc<f64>,
+            via: Option<Geographic<f64>>,
+            midpoint: Geographic<f64>,
+        }
+
+        let antipodal = Arc::new(NonZeroUsize::new(2).unwrap())
+            .with_from(Geographic::origin().with_longitude(Longitude::from(0.5)))
+            .with_to(Geographic::origin().with_longitude(Longitude::from(0.5 - PI)));
+
+        vec![
+            Test {
+                name: "antipodal arc must head north by default",
+                arc: antipodal,
+                via: None,
+                midpoint: Geographic::origin().with_latitude(Latitude::from(FRAC_PI_2)),
+            },
+            Test {
+                name: "antipodal arc must go through the waypoint",
+                arc: antipodal,
+                via: Some(Geographic::origin().with_longitude(Longitude::from(1.))),
+                midpoint: Geographic::origin().with_longitude(Longitude::from(0.5 + FRAC_PI_2)),
+            },
+            Test {
+                name: "antipodal arc must go through a southern waypoint",
+                arc: antipodal,
+                via: Some(
+                    Geographic::origin()
+                        .with_longitude(Longitude::from(0.5))
+                        .with_latitude(Latitude::from(-0.3)),
+                ),
+                midpoint: Geographic::origin().with_latitude(Latitude::from(-FRAC_PI_2)),
+            },
+            Test {
+                name: "waypoint along the longest path must be followed",
+                arc: Arc::new(NonZeroUsize::new(2).unwrap())
+                    .with_from(Geographic::origin())
+                    .with_to(Geographic::origin().with_longitude(Longitude::from(FRAC_PI_2))),
+                via: Some(Geographic::origin().with_longitude(Longitude::from(-0.1))),
+                midpoint: Geographic::origin()
+                    .with_longitude(Longitude::from(-3. * FRAC_PI_2 / 2.)),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let points: Vec<_> = match test.via {
+                Some(via) => test.arc.into_iter_via(&via).collect(),
+                None => test.arc.into_iter().collect(),
+            };
+
+            let tolerance = 1e-09;
+            assert_eq!(points.len(), 3, "{}: got points = {:?}", test.name, points);
+
+            assert!(
+                points[1].distance_meters(&test.midpoint, 1.) < tolerance,
+                "{}: got midpoint = {:?}, want {:?}",
+                test.name,
+                points[1],
+                test.midpoint
+            );
+
+            assert!(
+                points[2].distance_meters(&test.arc.to, 1.) < tolerance,
+                "{}: got last point = {:?}, want {:?}",
+                test.name,
+                points[2],
+                test.arc.to
+            );
+        });
+    }
+
+    #[test]
+    fn arc_prepared() {
+        struct Test {
+            name: &'static str,
+            arc: Arc<f64>,
+            towards: Cartesian<f64>,
+            angle: f64,
+        }
+
+        let from = Geographic::origin()
+            .with_longitude(Longitude::from(-2.))
+            .with_latitude(Latitude::from(0.3));
+        let to = Geographic::origin()
+            .with_longitude(Longitude::from(2.5))
+            .with_latitude(Latitude::from(-FRAC_PI_4));
+
+        let (a, b) = (from.into_cartesian(), to.into_cartesian());
+
+        vec![
+            Test {
+                name: "regular arc",
+                arc: Arc::new(NonZeroUsize::new(7).unwrap())
+                    .with_from(from)
+                    .with_to(to),
+                towards: (b - a * a.dot(&b)).normal(),
+                angle: a.dot(&b).acos(),
+            },
+            Test {
+                name: "coincident endpoints",
+                arc: Arc::new(NonZeroUsize::new(3).unwrap())
+                    .with_from(Geographic::origin())
+                    .with_to(Geographic::origin()),
+                towards: Cartesian::origin().with_z(1.),
+                angle: 0.,
+            },
+            Test {
+                name: "antipodal endpoints",
+                arc: Arc::new(NonZeroUsize::new(4).unwrap())
+                    .with_from(Geographic::origin())
+                    .with_to(Geographic::origin().with_longitude(Longitude::from(PI))),
+                towards: Cartesian::origin().with_z(1.),
+                angle: PI,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            // the points of the arc are evenly spaced along the great circle going from the
+            // initial endpoint towards the given direction.
+            let slerp = |segments: usize| -> Vec<Cartesian<f64>> {
+                let from = test.arc.from.into_cartesian();
+                (0..=segments)
+                    .map(|index| {
+                        let theta = test.angle * index as f64 / segments as f64;
+                        from * theta.cos() + test.towards * theta.sin()
+                    })
+                    .collect()
+            };
+
+            let matches = |got: &[Geographic<f64>], want: &[Cartesian<f64>]| {
+                got.len() == want.len()
+                    && got
+                        .iter()
+                        .zip(want)
+                        .all(|(got, want)| got.into_cartesian().distance(want) < 1e-09)
+            };
+
+            let prepared = test.arc.prepared();
+
+            // iterating the same prepared arc many times must always yield the same points.
+            (0..2).for_each(|_| {
+                let got: Vec<_> = prepared.iter().collect();
+                let want = slerp(test.arc.segments.get());
+                assert!(
+                    matches(&got, &want),
+                    "{}: got {:?}, want {:?}",
+                    test.name,
+                    got,
+                    want
+                );
+            });
+
+            [1, 2, 5, 16].into_iter().for_each(|segments| {
+                let got: Vec<_> = prepared
+                    .with_segments(NonZeroUsize::new(segments).unwrap())
+                    .iter()
+                    .collect();
+                let want = slerp(segments);
+                assert!(
+                    matches(&got, &want),
+                    "{}: got resampled {:?}, want {:?}",
+                    test.name,
+                    got,
+                    want
+                );
+            });
+        });
+    }
+
+    #[test]
+    fn arc_by_chord_length() {
+        struct Test {
+            name: &'static str,
+            from: Geographic<f64>,
+            to: Geographic<f64>,
+            chord: f64,
+            points: usize,
+        }
+
+        vec![
+            Test {
+                name: "chord not dividing the arc must shorten the last segment",
+                from: Geographic::origin(),
+                to: Geographic::origin().with_longitude(Longitude::from(1.)),
+                chord: 0.3,
+                points: 5,
+            },
+            Test {
+                name: "chord dividing the arc must not repeat the final endpoint",
+                from: Geographic::origin().with_latitude(Latitude::from(-0.2)),
+                to: Geographic::origin().with_latitude(Latitude::from(1.)),
+                chord: 2. * 0.15_f64.sin(),
+                points: 5,
+            },
+            Test {
+                name: "chord longer than the arc must yield the endpoints",
+                from: Geographic::origin(),
+                to: Geographic::origin().with_longitude(Longitude::from(0.5)),
+                chord: 1.,
+                points: 2,
+            },
+            Test {
+                name: "chord beyond the diameter must yield the endpoints",
+                from: Geographic::origin(),
+                to: Geographic::origin().with_longitude(Longitude::from(FRAC_PI_2)),
+                chord: 3.,
+                points: 2,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let points = Arc::by_chord_length(test.from, test.to, test.chord);
+            assert_eq!(
+                points.len(),
+                test.points,
+                "{}: got {:?}, want {} points",
+                test.name,
+                points,
+                test.points
+            );
+
+            let tolerance = 1e-09;
+            let (first, last) = (points[0], points[points.len() - 1]);
+            assert!(
+                Cartesian::from(first).distance(&test.from.into()) < tolerance
+                    && Cartesian::from(last).distance(&test.to.into()) < tolerance,
+                "{}: got endpoints {:?} and {:?}, want {:?} and {:?}",
+                test.name,
+                first,
+                last,
+                test.from,
+                test.to
+            );
+
+            if test.points == 2 {
+                return;
+            }
+
+            points.windows(2).enumerate().for_each(|(index, pair)| {
+                let chord = Cartesian::from(pair[0]).distance(&pair[1].into());
+                let is_last = index == points.len() - 2;
+
+                assert!(
+                    (chord - test.chord).abs() < tolerance || is_last && chord < test.chord,
+                    "{}: got chord #{} = {}, want {}",
+                    test.name,
+                    index,
+                    chord,
+                    test.chord
+                );
+            });
+        });
+    }
+
+    #[test]
+    fn arc_waypoints_at_longitudes() {
+        let degrees = |lon: f64, lat: f64| {
+            Geographic::origin()
+                .with_longitude(Longitude::from(lon.to_radians()))
+                .with_latitude(Latitude::from(lat.to_radians()))
+        };
+
+        let (london, new_york) = (degrees(-0.1278, 51.5074), degrees(-74.006, 40.7128));
+
+        // the latitude of the great circle through both endpoints at the given longitude, as in
+        // the aviation formulary.
+        let latitude_at = |lon: f64| {
+            let (lat1, lon1) = (london.latitude.into_inner(), london.longitude.into_inner());
+            let (lat2, lon2) = (
+                new_york.latitude.into_inner(),
+                new_york.longitude.into_inner(),
+            );
+
+            ((lat1.sin() * lat2.cos() * (lon - lon2).sin()
+                - lat2.sin() * lat1.cos() * (lon - lon1).sin())
+                / (lat1.cos() * lat2.cos() * (lon1 - lon2).sin()))
+            .atan()
+        };
+
+        struct Test {
+            name: &'static str,
+            arc: Arc<f64>,
+            lons: Vec<f64>,
+            want: Vec<f64>,
+        }
+
+        vec![
+            Test {
+                name: "london to new york must cross the intermediate meridians",
+                arc: Arc::new(NonZeroUsize::MIN)
+                    .with_from(london)
+                    .with_to(new_york),
+                lons: vec![10., -10., -20., -30., -40., -50., -60., -70., -80.],
+                want: vec![-10., -20., -30., -40., -50., -60., -70.],
+            },
+            Test {
+                name: "new york to london must keep the requested order",
+                arc: Arc::new(NonZeroUsize::MIN)
+                    .with_from(new_york)
+                    .with_to(london),
+                lons: vec![-70., -20.],
+                want: vec![-70., -20.],
+            },
+            Test {
+                name: "arc along a meridian must have no waypoints",
+                arc: Arc::new(NonZeroUsize::MIN)
+                    .with_from(degrees(-20., 10.))
+                    .with_to(degrees(-20., 50.)),
+                lons: vec![-20., 0.],
+                want: vec![],
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let lons: Vec<_> = test
+                .lons
+                .iter()
+                .map(|lon| Longitude::from(lon.to_radians()))
+                .collect();
+
+            let waypoints = test.arc.waypoints_at_longitudes(&lons);
+            assert_eq!(
+                waypoints.len(),
+                test.want.len(),
+                "{}: got waypoints = {:?}, want at {:?}",
+                test.name,
+                waypoints,
+                test.want
+            );
+
+            waypoints
+                .iter()
+                .zip(&test.want)
+                .for_each(|(waypoint, lon)| {
+                    let lon = lon.to_radians();
+                    let want = latitude_at(lon);
+
+                    assert!(
+                        (waypoint.longitude.into_inner() - lon).abs() < 1e-09
+                            && (waypoint.latitude.into_inner() - want).abs() < 1e-09,
+                        "{}: got waypoint = {:?}, want latitude {} at {}",
+                        test.name,
+                        waypoint,
+                        want,
+                        lon
+                    );
+                });
+        });
+    }
+
+    #[test]
+    fn arc_split_at_antimeridian() {
+        struct Test {
+            name: &'static str,
+            arc: Arc<f64>,
+            parts: usize,
+        }
+
+        vec![
+            Test {
+                name: "arc crossing the antimeridian must be split in two",
+                arc: Arc::new(NonZeroUsize::new(10).unwrap())
+                    .with_from(
+                        Geographic::origin()
+                            .with_longitude(Longitude::from(2.9))
+                            .with_latitude(Latitude::from(0.3)),
+                    )
+                    .with_to(
+                        Geographic::origin()
+                            .with_longitude(Longitude::from(-2.8))
+                            .with_latitude(Latitude::from(0.6)),
+                    ),
+                parts: 2,
+            },
+            Test {
+                name: "arc crossing the antimeridian westward must be split in two",
+                arc: Arc::new(NonZeroUsize::new(3).unwrap())
+                    .with_from(
+                        Geographic::origin()
+                            .with_longitude(Longitude::from(-3.))
+                            .with_latitude(Latitude::from(-0.2)),
+                    )
+                    .with_to(Geographic::origin().with_longitude(Longitude::from(3.))),
+                parts: 2,
+            },
+            Test {
+                name: "arc crossing the prime meridian must be kept",
+                arc: Arc::new(NonZeroUsize::new(4).unwrap())
+                    .with_from(Geographic::origin().with_longitude(Longitude::from(-0.5)))
+                    .with_to(Geographic::origin().with_longitude(Longitude::from(0.5))),
+                parts: 1,
+            },
+            Test {
+                name: "arc ending at the antimeridian must be kept",
+                arc: Arc::new(NonZeroUsize::new(4).unwrap())
+                    .with_from(Geographic::origin().with_longitude(Longitude::from(2.5)))
+                    .with_to(Geographic::origin().with_longitude(Longitude::from(PI))),
+                parts: 1,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let parts = test.arc.split_at_antimeridian();
+            assert_eq!(
+                parts.len(),
+                test.parts,
+                "{}: got parts = {:?}, want {}",
+                test.name,
+                parts,
+                test.parts
+            );
+
+            let (first, last) = (parts[0], parts[parts.len() - 1]);
+            assert!(
+                first.from == test.arc.from && last.to == test.arc.to,
+                "{}: got parts = {:?}, want from {:?} to {:?}",
+                test.name,
+                parts,
+                test.arc.from,
+                test.arc.to
+            );
+
+            if test.parts == 1 {
+                return;
+            }
+
+            let crossing = first.to;
+            assert!(
+                crossing == last.from && crossing.longitude == Longitude::from(-PI),
+                "{}: got crossing = {:?}, want on the antimeridian",
+                test.name,
+                crossing
+            );
+
+            // every point of each part must stay on the same side of the antimeridian.
+            parts.iter().for_each(|part| {
+                let signs: Vec<_> = part
+                    .into_iter()
+                    .filter(|point| point.longitude != Longitude::from(-PI))
+                    .map(|point| point.longitude.into_inner().is_sign_positive())
+                    .collect();
+
+                assert!(
+                    signs.windows(2).all(|pair| pair[0] == pair[1]),
+                    "{}: got part {:?} on both sides",
+                    test.name,
+                    part
+                );
+
+                let on_circle = part.into_iter().all(|point| {
+                    let closest = test.arc.closest_point(&point).into_cartesian().normal();
+                    closest.distance(&point.into_cartesian().normal()) < 1e-09
+                });
+
+                assert!(on_circle, "{}: got part {:?} off the arc", test.name, part);
+            });
+        });
+    }
+
+    #[test]
+    fn arc_enumerate_fractions() {
+        struct Test {
+            name: &'static str,
+            segments: usize,
+        }
+
+        vec![
+            Test {
+                name: "single segment must yield the endpoints",
+                segments: 1,
+            },
+            Test {
+                name: "many segments must be evenly spaced",
+                segments: 7,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let arc = Arc::new(NonZeroUsize::new(test.segments).unwrap())
+                .with_from(
+                    Geographic::origin()
+                        .with_longitude(Longitude::from(-1.))
+                        .with_latitude(Latitude::from(0.2)),
+                )
+                .with_to(
+                    Geographic::origin()
+                        .with_longitude(Longitude::from(0.4))
+                        .with_latitude(Latitude::from(-0.6)),
+                );
+
+            let pairs: Vec<_> = arc.enumerate_fractions().collect();
+            let points: Vec<_> = arc.into_iter().collect();
+
+            assert_eq!(
+                pairs.len(),
+                test.segments + 1,
+                "{}: got {} pairs, want {}",
+                test.name,
+                pairs.len(),
+                test.segments + 1
+            );
+
+            let (first, last) = (pairs[0], pairs[pairs.len() - 1]);
+            assert!(
+                first.0 == 0.
+                    && last.0 == 1.
+                    && first.1 == points[0]
+                    && last.1 == arc.to.into_cartesian().normal().into(),
+                "{}: got first = {:?} and last = {:?}",
+                test.name,
+                first,
+                last
+            );
+
+            pairs
+                .iter()
+                .zip(&points)
+                .enumerate()
+                .for_each(|(index, ((fraction, got), want))| {
+                    let spacing = index as f64 / test.segments as f64;
+                    assert!(
+                        (fraction - spacing).abs() < 1e-12 && got == want,
+                        "{}: got pair #{} = ({}, {:?}), want ({}, {:?})",
+                        test.name,
+                        index,
+                        fraction,
+                        got,
+                        spacing,
+                        want
+                    );
+                });
+        });
+    }
+
+    #[test]
+    fn arc_with_easing() {
+        fn smoothstep(t: f64) -> f64 {
+            t * t * (3. - 2. * t)
+        }
+
+        let arc = Arc::new(NonZeroUsize::new(10).unwrap())
+            .with_from(Geographic::origin())
+            .with_to(Geographic::origin().with_longitude(Longitude::from(1.)));
+
+        let linear: Vec<_> = arc.into_iter().collect();
+        let eased: Vec<_> = arc.with_easing(smoothstep).into_iter().collect();
+
+        assert_eq!(
+            arc.with_easing(smoothstep).easing()(0.5),
+            smoothstep(0.5),
+            "got an easing other than the given one"
+        );
+
+        assert_eq!(
+            eased.len(),
+            linear.len(),
+            "got {} eased points, want {}",
+            eased.len(),
+            linear.len()
+        );
+
+        let tolerance = 1e-09;
+        let steps = |points: &[Geographic<f64>]| -> Vec<f64> {
+            points
+                .windows(2)
+                .map(|pair| pair[1].longitude.into_inner() - pair[0].longitude.into_inner())
+                .collect()
+        };
+
+        let uniform = steps(&linear);
+        assert!(
+            uniform.iter().all(|step| (step - 0.1).abs() < tolerance),
+            "got linear steps = {uniform:?}, want all of them 0.1"
+        );
+
+        let steps = steps(&eased);
+        let (first, middle, last) = (steps[0], steps[steps.len() / 2], steps[steps.len() - 1]);
+        assert!(
+            first < 0.1 && last < 0.1 && middle > 0.1,
+            "got eased steps = {steps:?}, want them shorter at the endpoints"
+        );
+
+        eased
+            .iter()
+            .zip(arc.with_easing(smoothstep).enumerate_fractions())
+            .for_each(|(point, (fraction, enumerated))| {
+                assert!(
+                    (point.longitude.into_inner() - fraction).abs() < tolerance,
+                    "got longitude = {:?}, want the eased fraction {fraction}",
+                    point.longitude
+                );
+
+                assert_eq!(
+                    *point, enumerated,
+                    "got enumerated point = {enumerated:?}, want {point:?}"
+                );
+            });
+
+        let endpoints = (eased[0], eased[eased.len() - 1]);
+        assert_eq!(
+            endpoints,
+            (linear[0], linear[linear.len() - 1]),
+            "got eased endpoints = {endpoints:?}, want the linear ones"
+        );
+    }
+
+    #[test]
+    fn arc_extreme_latitudes() {
+        struct Test {
+            name: &'static str,
+            arc: Arc<f64>,
+            max: f64,
+            min: f64,
+        }
+
+        let point = |longitude: f64, latitude: f64| {
+            Geographic::origin()
+                .with_longitude(Longitude::from(longitude))
+                .with_latitude(Latitude::from(latitude))
+        };
+
+        // the vertex of a great circle through two points at the same latitude is halfway between
+        // their longitudes.
+        let vertex = (0.7_f64.tan() / 1_f64.cos()).atan();
+
+        vec![
+            Test {
+                name: "northern route must reach a vertex higher than its endpoints",
+                arc: Arc::new(NonZeroUsize::MIN)
+                    .with_from(point(-1., 0.7))
+                    .with_to(point(1., 0.7)),
+                max: vertex,
+                min: 0.7,
+            },
+            Test {
+                name: "southern route must reach a vertex lower than its endpoints",
+                arc: Arc::new(NonZeroUsize::MIN)
+                    .with_from(point(1., -0.7))
+                    .with_to(point(-1., -0.7)),
+                max: -0.7,
+                min: -vertex,
+            },
+            Test {
+                name: "arc not containing its vertex must be bounded by its endpoints",
+                arc: Arc::new(NonZeroUsize::MIN)
+                    .with_from(point(0., 0.1))
+                    .with_to(point(0.2, 0.3)),
+                max: 0.3,
+                min: 0.1,
+            },
+            Test {
+                name: "equatorial arc must stay at the equator",
+                arc: Arc::new(NonZeroUsize::MIN)
+                    .with_from(point(0., 0.))
+                    .with_to(point(1., 0.)),
+                max: 0.,
+                min: 0.,
+            },
+            Test {
+                name: "meridian arc through the pole must reach it",
+                arc: Arc::new(NonZeroUsize::MIN)
+                    .with_from(point(0., 1.))
+                    .with_to(point(PI, 1.)),
+                max: FRAC_PI_2,
+                min: 1.,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let tolerance = 1e-09;
+
+            let max = test.arc.max_latitude().into_inner();
+            assert!(
+                (max - test.max).abs() < tolerance,
+                "{}: got max latitude = {}, want {}",
+                test.name,
+                max,
+                test.max
+            );
+
+            let min = test.arc.min_latitude().into_inner();
+            assert!(
+                (min - test.min).abs() < tolerance,
+                "{}: got min latitude = {}, want {}",
+                test.name,
+                min,
+                test.min
+            );
+        });
+    }
+
+    #[test]
+    fn arc_nearest_on_arcs() {
+        struct Test {
+            name: &'static str,
+            query: Geographic<f64>,
+            output: Option<(usize, Geographic<f64>)>,
+        }
+
+        let point = |longitude: f64, latitude: f64| {
+            Geographic::origin()
+                .with_longitude(Longitude::from(longitude))
+                .with_latitude(Latitude::from(latitude))
+        };
+
+        let arcs = [
+            Arc::new(NonZeroUsize::MIN)
+                .with_from(point(0., 0.))
+                .with_to(point(1., 0.)),
+            Arc::new(NonZeroUsize::MIN)
+                .with_from(point(2., -1.))
+                .with_to(point(2., 1.)),
+            Arc::new(NonZeroUsize::MIN)
+                .with_from(point(-1., 1.))
+                .with_to(point(-0.5, 1.)),
+        ];
+
+        vec![
+            Test {
+                name: "query above the first arc must snap onto it",
+                query: point(0.5, 0.1),
+                output: Some((0, point(0.5, 0.))),
+            },
+            Test {
+                name: "query beside the second arc must snap onto its meridian",
+                query: point(2.2, 0.3),
+                output: Some((
+                    1,
+                    point(2., 0.3_f64.sin().atan2(0.2_f64.cos() * 0.3_f64.cos())),
+                )),
+            },
+            Test {
+                name: "query beyond the third arc must snap onto its nearest endpoint",
+                query: point(-1.2, 1.1),
+                output: Some((2, point(-1., 1.))),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let output = nearest_on_arcs(&test.query, &arcs);
+            let matches = match (output, test.output) {
+                (Some((index, got)), Some((want_index, want))) => {
+                    index == want_index
+                        && got
+                            .longitude
+                            .approx_eq(&want.longitude, Tolerance::default())
+                        && (got.latitude.into_inner() - want.latitude.into_inner()).abs() < 1e-09
+                }
+                (got, want) => got.is_none() && want.is_none(),
+            };
+
+            assert!(
+                matches,
+                "{}: got nearest = {:?}, want {:?}",
+                test.name, output, test.output
+            );
+        });
+
+        let output = nearest_on_arcs(&point(0., 0.), &[]);
+        assert_eq!(
+            output, None,
+            "got nearest on no arcs = {output:?}, want None"
+        );
+    }
+}
@@ -0,0 +1,434 @@
+//! Geodesic shape iterator.
+
+use core::num::NonZeroUsize;
+
+use num_traits::{Euclid, Float, FloatConst, Signed};
+
+use crate::{Ellipsoid, Geographic};
+
+/// The maximum amount of iterations performed by the Vincenty's formulae before giving up.
+const MAX_ITERATIONS: usize = 200;
+
+/// A [geodesic](https://en.wikipedia.org/wiki/Geodesics_on_an_ellipsoid) between two points on an
+/// ellipsoid.
+///
+/// ## Definition
+/// The intermediate points are computed through the direct [Vincenty's
+/// formulae](https://en.wikipedia.org/wiki/Vincenty%27s_formulae), stepping along the geodesic
+/// whose length and initial azimuth are given by the inverse ones.
+///
+/// ### Nearly antipodal endpoints
+/// The inverse formula may not converge for nearly antipodal endpoints. In such a case the
+/// ellipsoid is approximated by a sphere of the same equatorial radius, and so the geodesic falls
+/// back to the great-circle arc between both endpoints.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        deserialize = "T: serde::Deserialize<'de> + PartialOrd + Signed + Float + FloatConst + Euclid"
+    ))
+)]
+pub struct Geodesic<T> {
+    /// The initial endpoint of the geodesic.
+    pub from: Geographic<T>,
+    /// The final endpoint of the geodesic.
+    pub to: Geographic<T>,
+    /// The ellipsoid on which the geodesic is drawn.
+    pub ellipsoid: Ellipsoid<T>,
+    /// The total amount of segments (straight lines) the geodesic is made of.
+    pub segments: NonZeroUsize,
+}
+
+impl<T> IntoIterator for Geodesic<T>
+where
+    T: PartialOrd + Signed + Float + FloatConst + Euclid,
+{
+    type Item = Geographic<T>;
+
+    type IntoIter = GeodesicIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let (ellipsoid, (distance, azimuth)) = match inverse(&self.ellipsoid, &self.from, &self.to)
+        {
+            Some(solution) => (self.ellipsoid, solution),
+            None => {
+                let sphere = self.ellipsoid.with_flattening(T::zero());
+                let distance = self.from.with_altitude(T::one().into()).distance(&self.to)
+                    * sphere.semi_major_axis.into_inner();
+
+                (sphere, (distance, spherical_azimuth(&self.from, &self.to)))
+            }
+        };
+
+        GeodesicIter {
+            from: self.from,
+            to: self.to,
+            ellipsoid,
+            distance,
+            azimuth,
+            total_segments: self.segments.get(),
+            next_segment: 0,
+        }
+    }
+}
+
+impl<T> Geodesic<T>
+where
+    T: Default,
+{
+    pub fn new(ellipsoid: Ellipsoid<T>, segments: NonZeroUsize) -> Self {
+        Self {
+            from: Default::default(),
+            to: Default::default(),
+            ellipsoid,
+            segments,
+        }
+    }
+}
+
+impl<T> Geodesic<T> {
+    pub fn with_from(self, from: Geographic<T>) -> Self {
+        Self { from, ..self }
+    }
+
+    pub fn with_to(self, to: Geographic<T>) -> Self {
+        Self { to, ..self }
+    }
+}
+
+/// Iterator over the [`Geodesic`] shape.
+#[derive(Debug)]
+pub struct GeodesicIter<T> {
+    from: Geographic<T>,
+    to: Geographic<T>,
+    ellipsoid: Ellipsoid<T>,
+    distance: T,
+    azimuth: T,
+    total_segments: usize,
+    next_segment: usize,
+}
+
+impl<T> Iterator for GeodesicIter<T>
+where
+    T: PartialOrd + Signed + Float + FloatConst + Euclid,
+{
+    type Item = Geographic<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next_segment > self.total_segments {
+            return None;
+        }
+
+        if self.next_segment == self.total_segments {
+            self.next_segment += 1;
+            return Some(self.to);
+        }
+
+        let distance = self.distance * T::from(self.next_segment)? / T::from(self.total_segments)?;
+
+        let next = direct(&self.ellipsoid, &self.from, self.azimuth, distance);
+
+        self.next_segment += 1;
+        Some(next)
+    }
+}
+
+/// Returns the given value as a T.
+fn constant<T: Float>(value: f64) -> T {
+    T::from(value).unwrap_or_else(T::nan)
+}
+
+/// Returns the correction terms A and B of the Vincenty's formulae for the given squared cosine
+/// of the azimuth at the equator.
+fn correction_terms<T: Float>(ellipsoid: &Ellipsoid<T>, cos_sq_alpha: T) -> (T, T) {
+    let a = ellipsoid.semi_major_axis.into_inner();
+    let b = ellipsoid.semi_minor_axis();
+
+    let u_sq = cos_sq_alpha * (a.powi(2) - b.powi(2)) / b.powi(2);
+    let big_a = T::one()
+        + u_sq / constant(16384.)
+            * (constant::<T>(4096.)
+                + u_sq
+                    * (constant::<T>(-768.)
+                        + u_sq * (constant::<T>(320.) - constant::<T>(175.) * u_sq)));
+    let big_b = u_sq / constant(1024.)
+        * (constant::<T>(256.)
+            + u_sq
+                * (constant::<T>(-128.) + u_sq * (constant::<T>(74.) - constant::<T>(47.) * u_sq)));
+
+    (big_a, big_b)
+}
+
+/// Returns the Δσ term of the Vincenty's formulae.
+fn delta_sigma<T: Float>(big_b: T, sin_sigma: T, cos_sigma: T, cos_2sigma_m: T) -> T {
+    big_b
+        * sin_sigma
+        * (cos_2sigma_m
+            + big_b / constant(4.)
+                * (cos_sigma * (constant::<T>(-1.) + constant::<T>(2.) * cos_2sigma_m.powi(2))
+                    - big_b / constant(6.)
+                        * cos_2sigma_m
+                        * (constant::<T>(-3.) + constant::<T>(4.) * sin_sigma.powi(2))
+                        * (constant::<T>(-3.) + constant::<T>(4.) * cos_2sigma_m.powi(2))))
+}
+
+/// Returns the length and the initial azimuth of the geodesic between both points, or [`None`]
+/// if the inverse Vincenty's formula does not converge.
+fn inverse<T>(ellipsoid: &Ellipsoid<T>, from: &Geographic<T>, to: &Geographic<T>) -> Option<(T, T)>
+where
+    T: Float,
+{
+    let f = ellipsoid.flattening;
+    let b = ellipsoid.semi_minor_axis();
+
+    let big_l = to.longitude.into_inner() - from.longitude.into_inner();
+    let u1 = ((T::one() - f) * from.latitude.into_inner().tan()).atan();
+    let u2 = ((T::one() - f) * to.latitude.into_inner().tan()).atan();
+    let (sin_u1, cos_u1) = u1.sin_cos();
+    let (sin_u2, cos_u2) = u2.sin_cos();
+
+    let mut lambda = big_l;
+    for _ in 0..MAX_ITERATIONS {
+        let (sin_lambda, cos_lambda) = lambda.sin_cos();
+
+        let sin_sigma = ((cos_u2 * sin_lambda).powi(2)
+            + (cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda).powi(2))
+        .sqrt();
+
+        if sin_sigma == T::zero() {
+            // coincident points
+            return Some((T::zero(), T::zero()));
+        }
+
+        let cos_sigma = sin_u1 * sin_u2 + cos_u1 * cos_u2 * cos_lambda;
+        let sigma = sin_sigma.atan2(cos_sigma);
+        let sin_alpha = cos_u1 * cos_u2 * sin_lambda / sin_sigma;
+        let cos_sq_alpha = T::one() - sin_alpha.powi(2);
+        let cos_2sigma_m = if cos_sq_alpha == T::zero() {
+            // equatorial line
+            T::zero()
+        } else {
+            cos_sigma - constant::<T>(2.) * sin_u1 * sin_u2 / cos_sq_alpha
+        };
+
+        let c = f / constant(16.)
+            * cos_sq_alpha
+            * (constant::<T>(4.) + f * (constant::<T>(4.) - constant::<T>(3.) * cos_sq_alpha));
+        let prev_lambda = lambda;
+        lambda = big_l
+            + (T::one() - c)
+                * f
+                * sin_alpha
+                * (sigma
+                    + c * sin_sigma
+                        * (cos_2sigma_m
+                            + c * cos_sigma
+                                * (constant::<T>(-1.) + constant::<T>(2.) * cos_2sigma_m.powi(2))));
+
+        if (lambda - prev_lambda).abs() <= constant(1e-12) {
+            let (big_a, big_b) = correction_terms(ellipsoid, cos_sq_alpha);
+            let distance =
+                b * big_a * (sigma - delta_sigma(big_b, sin_sigma, cos_sigma, cos_2sigma_m));
+            let azimuth =
+                (cos_u2 * sin_lambda).atan2(cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda);
+
+            return Some((distance, azimuth));
+        }
+    }
+
+    None
+}
+
+/// Returns the point at the given distance along the geodesic departing from the given point
+/// with the given initial azimuth, as specified by the direct Vincenty's formula.
+fn direct<T>(
+    ellipsoid: &Ellipsoid<T>,
+    from: &Geographic<T>,
+    azimuth: T,
+    distance: T,
+) -> Geographic<T>
+where
+    T: PartialOrd + Signed + Float + FloatConst + Euclid,
+{
+    let f = ellipsoid.flattening;
+    let b = ellipsoid.semi_minor_axis();
+
+    let (sin_alpha1, cos_alpha1) = azimuth.sin_cos();
+    let u1 = ((T::one() - f) * from.latitude.into_inner().tan()).atan();
+    let (sin_u1, cos_u1) = u1.sin_cos();
+
+    let sigma1 = u1.tan().atan2(cos_alpha1);
+    let sin_alpha = cos_u1 * sin_alpha1;
+    let cos_sq_alpha = T::one() - sin_alpha.powi(2);
+    let (big_a, big_b) = correction_terms(ellipsoid, cos_sq_alpha);
+
+    let mut sigma = distance / (b * big_a);
+    let mut cos_2sigma_m = (constant::<T>(2.) * sigma1 + sigma).cos();
+    for _ in 0..MAX_ITERATIONS {
+        cos_2sigma_m = (constant::<T>(2.) * sigma1 + sigma).cos();
+
+        let prev_sigma = sigma;
+        sigma = distance / (b * big_a) + delta_sigma(big_b, sigma.sin(), sigma.cos(), cos_2sigma_m);
+
+        if (sigma - prev_sigma).abs() <= constant(1e-12) {
+            break;
+        }
+    }
+
+    let (sin_sigma, cos_sigma) = sigma.sin_cos();
+    let tmp = sin_u1 * sin_sigma - cos_u1 * cos_sigma * cos_alpha1;
+    let latitude = (sin_u1 * cos_sigma + cos_u1 * sin_sigma * cos_alpha1)
+        .atan2((T::one() - f) * (sin_alpha.powi(2) + tmp.powi(2)).sqrt());
+
+    let lambda =
+        (sin_sigma * sin_alpha1).atan2(cos_u1 * cos_sigma - sin_u1 * sin_sigma * cos_alpha1);
+    let c = f / constant(16.)
+        * cos_sq_alpha
+        * (constant::<T>(4.) + f * (constant::<T>(4.) - constant::<T>(3.) * cos_sq_alpha));
+    let big_l = lambda
+        - (T::one() - c)
+            * f
+            * sin_alpha
+            * (sigma
+                + c * sin_sigma
+                    * (cos_2sigma_m
+                        + c * cos_sigma
+                            * (constant::<T>(-1.) + constant::<T>(2.) * cos_2sigma_m.powi(2))));
+
+    from.with_longitude((from.longitude.into_inner() + big_l).into())
+        .with_latitude(latitude.into())
+}
+
+/// Returns the initial azimuth of the great circle between both points.
+fn spherical_azimuth<T: Float>(from: &Geographic<T>, to: &Geographic<T>) -> T {
+    let (sin_lat1, cos_lat1) = from.latitude.into_inner().sin_cos();
+    let (sin_lat2, cos_lat2) = to.latitude.into_inner().sin_cos();
+    let (sin_delta, cos_delta) =
+        (to.longitude.into_inner() - from.longitude.into_inner()).sin_cos();
+
+    (sin_delta * cos_lat2).atan2(cos_lat1 * sin_lat2 - sin_lat1 * cos_lat2 * cos_delta)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{f64::consts::PI, num::NonZeroUsize};
+
+    use crate::{
+        shape::{geodesic::inverse, Arc, Geodesic},
+        Ellipsoid, Geographic, Latitude, Longitude,
+    };
+
+    /// Returns the radians of the given sexagesimal degrees.
+    fn dms(degrees: f64, minutes: f64, seconds: f64) -> f64 {
+        (degrees.abs() + minutes / 60. + seconds / 3600.).to_radians() * degrees.signum()
+    }
+
+    #[test]
+    fn geodesic_inverse() {
+        // Flinders Peak to Buninyong, as given by Geoscience Australia.
+        let from = Geographic::origin()
+            .with_longitude(Longitude::from(dms(144., 25., 29.5244)))
+            .with_latitude(Latitude::from(dms(-37., 57., 3.7203)));
+        let to = Geographic::origin()
+            .with_longitude(Longitude::from(dms(143., 55., 35.3839)))
+            .with_latitude(Latitude::from(dms(-37., 39., 10.1561)));
+
+        let (distance, azimuth) = inverse(&Ellipsoid::wgs84(), &from, &to).unwrap();
+
+        assert!(
+            (distance - 54_972.271).abs() < 1e-03,
+            "got distance = {distance}, want 54972.271"
+        );
+
+        let want = dms(306., 52., 5.37) - 2. * PI;
+        assert!(
+            (azimuth - want).abs() < 1e-06,
+            "got azimuth = {azimuth}, want {want}"
+        );
+    }
+
+    #[test]
+    fn geodesic_iter() {
+        struct Test {
+            name: &'static str,
+            from: Geographic<f64>,
+            to: Geographic<f64>,
+        }
+
+        vec![
+            Test {
+                name: "oblique geodesic",
+                from: Geographic::origin()
+                    .with_longitude(Longitude::from(-0.5))
+                    .with_latitude(Latitude::from(0.7)),
+                to: Geographic::origin()
+                    .with_longitude(Longitude::from(1.2))
+                    .with_latitude(Latitude::from(0.2)),
+            },
+            Test {
+                name: "meridian geodesic",
+                from: Geographic::origin().with_latitude(Latitude::from(-0.4)),
+                to: Geographic::origin().with_latitude(Latitude::from(1.)),
+            },
+            Test {
+                name: "nearly antipodal geodesic",
+                from: Geographic::origin().with_latitude(Latitude::from(0.1)),
+                to: Geographic::origin()
+                    .with_longitude(Longitude::from(PI - 0.01))
+                    .with_latitude(Latitude::from(-0.1)),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let segments = NonZeroUsize::new(8).unwrap();
+            let sphere = Ellipsoid::wgs84().with_flattening(1e-12);
+
+            let geodesic = Geodesic::new(sphere, segments)
+                .with_from(test.from)
+                .with_to(test.to);
+
+            let arc = Arc::new(segments).with_from(test.from).with_to(test.to);
+
+            let tolerance = 1e-06;
+            geodesic.into_iter().zip(arc).for_each(|(got, want)| {
+                assert!(
+                    (got.longitude.into_inner() - want.longitude.into_inner()).abs() < tolerance
+                        && (got.latitude.into_inner() - want.latitude.into_inner()).abs()
+                            < tolerance,
+                    "{}: got point = {:?}, want {:?}",
+                    test.name,
+                    got,
+                    want
+                );
+            });
+        });
+    }
+
+    #[test]
+    fn geodesic_iter_must_fall_back_on_nearly_antipodal_endpoints() {
+        let from = Geographic::origin();
+        let to = Geographic::origin()
+            .with_longitude(Longitude::from(179.7_f64.to_radians()))
+            .with_latitude(Latitude::from(0.5_f64.to_radians()));
+
+        assert!(
+            inverse(&Ellipsoid::wgs84(), &from, &to).is_none(),
+            "inverse formula should not converge"
+        );
+
+        let points: Vec<_> = Geodesic::new(Ellipsoid::wgs84(), NonZeroUsize::new(4).unwrap())
+            .with_from(from)
+            .with_to(to)
+            .into_iter()
+            .collect();
+
+        assert_eq!(points.len(), 5, "got {} points, want 5", points.len());
+        assert!(
+            points.iter().all(|point| {
+                point.longitude.into_inner().is_finite() && point.latitude.into_inner().is_finite()
+            }),
+            "got points = {points:?}, want finite coordinates"
+        );
+    }
+}
@@ -0,0 +1,205 @@
+//! Graticule generator.
+
+use alloc::vec::Vec;
+use core::num::NonZeroUsize;
+
+use num_traits::{Euclid, Float, FloatConst, Signed};
+
+use crate::{Altitude, Geographic, Latitude, Longitude, Radian};
+
+/// Returns the meridians and parallels of the globe at the given spacing, each of them sampled as
+/// a polyline of the given amount of segments.
+///
+/// Meridians go from the south pole to the north one, starting at the prime meridian and heading
+/// east. Parallels are closed polylines (their first and last points are the same), sorted from
+/// south to north and always including the equator. Since poles are single points, no parallel is
+/// generated for them. Besides the equator, parallels are small circles, and so they are sampled
+/// along them instead of along any great circle.
+///
+/// Meridians are yielded first, followed by parallels. A null step yields no lines of that kind.
+pub fn graticule<T>(
+    lon_step: Radian<T>,
+    lat_step: Radian<T>,
+    segments: NonZeroUsize,
+) -> Vec<Vec<Geographic<T>>>
+where
+    T: PartialOrd + Signed + Float + FloatConst + Euclid,
+{
+    let lon_step = lon_step.into_inner();
+    let lat_step = lat_step.into_inner();
+
+    let meridians = (0..line_count(T::TAU(), lon_step)).map(|index| {
+        let longitude = lon_step * T::from(index).unwrap_or_else(T::zero);
+        polyline(segments, |t| (longitude, -T::FRAC_PI_2() + T::PI() * t))
+    });
+
+    // parallels are symmetric with respect to the equator, which is not repeated.
+    let northern = line_count(T::FRAC_PI_2(), lat_step);
+    let parallels = (1..northern)
+        .rev()
+        .map(|index| -lat_step * T::from(index).unwrap_or_else(T::zero))
+        .chain((0..northern).map(|index| lat_step * T::from(index).unwrap_or_else(T::zero)))
+        .map(|latitude| polyline(segments, |t| (-T::PI() + T::TAU() * t, latitude)));
+
+    meridians.chain(parallels).collect()
+}
+
+/// Returns the amount of lines spaced by step within [0, span), considering a line falling on span
+/// itself (within the precision error) as out of range.
+fn line_count<T>(span: T, step: T) -> usize
+where
+    T: Float,
+{
+    if step <= T::zero() {
+        return 0;
+    }
+
+    let count = span / step;
+    let rounded = count.round();
+    let count = if (count - rounded).abs() <= T::epsilon().sqrt() {
+        rounded
+    } else {
+        count.ceil()
+    };
+
+    count.to_usize().unwrap_or_default()
+}
+
+/// Returns the polyline made of the given amount of segments, where the coordinates (longitude and
+/// latitude) of each point are given by the position function for a parameter in [0, 1].
+fn polyline<T, F>(segments: NonZeroUsize, position: F) -> Vec<Geographic<T>>
+where
+    T: PartialOrd + Signed + Float + FloatConst + Euclid,
+    F: Fn(T) -> (T, T),
+{
+    let total = T::from(segments.get()).unwrap_or_else(T::one);
+    (0..=segments.get())
+        .map(|index| {
+            let (longitude, latitude) = position(T::from(index).unwrap_or_else(T::zero) / total);
+            Geographic::new(
+                Longitude::from(longitude),
+                Latitude::from(latitude),
+                Altitude::from(T::zero()),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        f64::consts::{FRAC_PI_2, FRAC_PI_6, PI},
+        num::NonZeroUsize,
+    };
+
+    use crate::{shape::graticule, Radian};
+
+    #[test]
+    fn graticule_lines() {
+        struct Test {
+            name: &'static str,
+            lon_step: Radian<f64>,
+            lat_step: Radian<f64>,
+            meridians: usize,
+            parallels: usize,
+        }
+
+        vec![
+            Test {
+                name: "30 degrees spacing",
+                lon_step: FRAC_PI_6.into(),
+                lat_step: FRAC_PI_6.into(),
+                meridians: 12,
+                parallels: 5,
+            },
+            Test {
+                name: "steps not dividing the globe must not exceed it",
+                lon_step: 1.0.into(),
+                lat_step: 1.0.into(),
+                meridians: 7,
+                parallels: 3,
+            },
+            Test {
+                name: "null steps must yield no lines",
+                lon_step: 0.0.into(),
+                lat_step: 0.0.into(),
+                meridians: 0,
+                parallels: 0,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let segments = NonZeroUsize::new(8).unwrap();
+            let lines = graticule(test.lon_step, test.lat_step, segments);
+
+            let meridians = lines
+                .iter()
+                .filter(|line| {
+                    let (first, last) = (line[0], line[line.len() - 1]);
+                    first.longitude == last.longitude && first.latitude != last.latitude
+                })
+                .count();
+
+            assert_eq!(
+                meridians, test.meridians,
+                "{}: got meridians = {}, want {}",
+                test.name, meridians, test.meridians
+            );
+
+            assert_eq!(
+                lines.len() - meridians,
+                test.parallels,
+                "{}: got parallels = {}, want {}",
+                test.name,
+                lines.len() - meridians,
+                test.parallels
+            );
+
+            assert!(
+                lines.iter().all(|line| line.len() == segments.get() + 1),
+                "{}: every line must have {} points",
+                test.name,
+                segments.get() + 1
+            );
+        });
+    }
+
+    #[test]
+    fn graticule_parallels_must_keep_their_latitude() {
+        let lines = graticule(
+            Radian::from(PI),
+            Radian::from(FRAC_PI_6),
+            NonZeroUsize::new(4).unwrap(),
+        );
+
+        let tolerance = 1e-09;
+        let parallels = &lines[2..];
+        let latitudes = [-2. * FRAC_PI_6, -FRAC_PI_6, 0., FRAC_PI_6, 2. * FRAC_PI_6];
+
+        parallels
+            .iter()
+            .zip(latitudes)
+            .for_each(|(parallel, latitude)| {
+                assert!(
+                    parallel
+                        .iter()
+                        .all(|point| (point.latitude.into_inner() - latitude).abs() < tolerance),
+                    "got parallel = {parallel:?}, want latitude {latitude}"
+                );
+
+                assert_eq!(
+                    parallel[0],
+                    parallel[parallel.len() - 1],
+                    "got parallel = {parallel:?}, want it closed"
+                );
+            });
+
+        let meridian = &lines[0];
+        assert!(
+            (meridian[0].latitude.into_inner() + FRAC_PI_2).abs() < tolerance
+                && (meridian[meridian.len() - 1].latitude.into_inner() - FRAC_PI_2).abs()
+                    < tolerance,
+            "got meridian = {meridian:?}, want it from pole to pole"
+        );
+    }
+}
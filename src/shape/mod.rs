@@ -1,5 +1,19 @@
 //! Shape implementations.
 
+mod aabb;
+mod adaptive_arc;
 mod arc;
+mod geodesic;
+mod graticule;
+mod polygon;
+mod small_circle;
+mod terminator;
 
-pub use self::arc::Arc;
+pub use self::aabb::Aabb;
+pub use self::adaptive_arc::AdaptiveArc;
+pub use self::arc::{nearest_on_arcs, Arc, ArcError, ArcIter, PreparedArc};
+pub use self::geodesic::{Geodesic, GeodesicIter};
+pub use self::graticule::graticule;
+pub use self::polygon::Polygon;
+pub use self::small_circle::{SmallCircle, SmallCircleIter};
+pub use self::terminator::terminator;
@@ -0,0 +1,365 @@
+//! Polygon shape.
+
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use num_traits::{Float, FloatConst, Signed};
+
+use crate::{Cartesian, Geographic};
+
+/// A polygon on the surface of a globe, whose edges are great-circle arcs.
+///
+/// ## Definition
+/// The vertices are given in counter-clockwise order as seen from outside the globe, which means
+/// the interior of the polygon is on the left of each edge. The polygon is implicitly closed,
+/// hence the last vertex must not repeat the first one. Its interior is assumed to be smaller than
+/// a hemisphere.
+///
+/// Since all predicates are computed on the sphere, polygons may cross the antimeridian or
+/// contain a pole. The behavior for self-intersecting polygons is undefined.
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        deserialize = "T: serde::Deserialize<'de> + PartialOrd + Signed + Float + FloatConst + num_traits::Euclid"
+    ))
+)]
+pub struct Polygon<T> {
+    /// The vertices of the polygon.
+    pub vertices: Vec<Geographic<T>>,
+}
+
+impl<T> Polygon<T>
+where
+    T: Signed + Float + FloatConst,
+{
+    /// Returns true if, and only if, the given point is inside the polygon.
+    ///
+    /// The point is inside if the boundary of the polygon winds around it, as seen from the point
+    /// itself. Points on the boundary may be reported either way.
+    pub fn contains(&self, point: &Geographic<T>) -> bool {
+        if self.vertices.len() < 3 {
+            return false;
+        }
+
+        let point = point.into_cartesian().normal();
+        let winding = self
+            .edges()
+            .map(|(from, to)| {
+                // the angle between both vertices as seen from the point, signed according to the
+                // right hand rule around it.
+                let from = from.into_cartesian().normal();
+                let to = to.into_cartesian().normal();
+
+                Float::atan2(
+                    from.cross(&to).dot(&point),
+                    from.dot(&to) - from.dot(&point) * to.dot(&point),
+                )
+            })
+            .fold(T::zero(), |winding, angle| winding + angle);
+
+        // a point enclosed by the boundary sees it turn 2π, while any other sees no turn at all.
+        winding > T::PI()
+    }
+
+    /// Returns the area of the polygon on the unit sphere (in steradians).
+    ///
+    /// To get the area on a sphere of radius r, multiply the result by r².
+    pub fn area(&self) -> T {
+        let Some(first) = self.vertices.first() else {
+            return T::zero();
+        };
+
+        let first = first.into_cartesian().normal();
+        let area = self
+            .vertices
+            .windows(2)
+            .skip(1)
+            .map(|pair| {
+                signed_triangle_area(
+                    &first,
+                    &pair[0].into_cartesian().normal(),
+                    &pair[1].into_cartesian().normal(),
+                )
+            })
+            .fold(T::zero(), |area, triangle| area + triangle);
+
+        area.abs()
+    }
+
+    /// Returns the triangles the polygon is made of, with their vertices in counter-clockwise
+    /// order.
+    ///
+    /// The triangulation follows the [ear clipping](https://en.wikipedia.org/wiki/Polygon_triangulation#Ear_clipping_method)
+    /// method, where the convexity of each vertex and the emptiness of each ear are decided by the
+    /// side of the great circles the vertices are on.
+    pub fn triangulate(&self) -> Vec<[Geographic<T>; 3]> {
+        let mut remaining: Vec<usize> = (0..self.vertices.len()).collect();
+        let mut triangles = Vec::with_capacity(self.vertices.len().saturating_sub(2));
+
+        while remaining.len() > 3 {
+            let ear = (0..remaining.len()).find(|&index| {
+                let prev = remaining[(index + remaining.len() - 1) % remaining.len()];
+                let next = remaining[(index + 1) % remaining.len()];
+                self.is_ear(prev, remaining[index], next, &remaining)
+            });
+
+            // degenerated polygons may have no ears at all.
+            let Some(ear) = ear else {
+                break;
+            };
+
+            let prev = remaining[(ear + remaining.len() - 1) % remaining.len()];
+            let next = remaining[(ear + 1) % remaining.len()];
+            triangles.push([
+                self.vertices[prev],
+                self.vertices[remaining[ear]],
+                self.vertices[next],
+            ]);
+
+            remaining.remove(ear);
+        }
+
+        if let [a, b, c] = remaining[..] {
+            triangles.push([self.vertices[a], self.vertices[b], self.vertices[c]]);
+        }
+
+        triangles
+    }
+
+    /// Returns true if, and only if, the triangle made of the given vertices is an ear of the
+    /// polygon: it is convex and no other vertex lies inside of it.
+    fn is_ear(&self, prev: usize, current: usize, next: usize, remaining: &[usize]) -> bool {
+        let a = self.vertices[prev].into_cartesian();
+        let b = self.vertices[current].into_cartesian();
+        let c = self.vertices[next].into_cartesian();
+
+        let (ab, bc, ca) = (a.cross(&b), b.cross(&c), c.cross(&a));
+        if self.vertices[next].side_of(&ab) != Ordering::Greater {
+            return false;
+        }
+
+        remaining
+            .iter()
+            .filter(|&&index| index != prev && index != current && index != next)
+            .all(|&index| {
+                let vertex = &self.vertices[index];
+                [ab, bc, ca]
+                    .iter()
+                    .any(|normal| vertex.side_of(normal) == Ordering::Less)
+            })
+    }
+
+    /// Returns an iterator over the edges of the polygon, including the closing one.
+    fn edges(&self) -> impl Iterator<Item = (&Geographic<T>, &Geographic<T>)> {
+        self.vertices
+            .iter()
+            .zip(self.vertices.iter().cycle().skip(1))
+    }
+}
+
+impl<T> Polygon<T> {
+    /// Returns the polygon with the given vertices.
+    pub fn new(vertices: Vec<Geographic<T>>) -> Self {
+        Self { vertices }
+    }
+}
+
+/// Returns the area of the spherical triangle with the given unit vectors as vertices, being
+/// positive if they are in counter-clockwise order and negative otherwise.
+///
+/// The area is computed through the formula of Van Oosterom and Strackee for the solid angle of
+/// the triangle.
+fn signed_triangle_area<T: Float>(a: &Cartesian<T>, b: &Cartesian<T>, c: &Cartesian<T>) -> T {
+    let numerator = a.dot(&b.cross(c));
+    let denominator = T::one() + a.dot(b) + b.dot(c) + c.dot(a);
+    (T::one() + T::one()) * Float::atan2(numerator, denominator)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    use crate::{geographic::triangle_area, shape::Polygon, Geographic, Latitude, Longitude};
+
+    fn point(longitude: f64, latitude: f64) -> Geographic<f64> {
+        Geographic::origin()
+            .with_longitude(Longitude::from(longitude))
+            .with_latitude(Latitude::from(latitude))
+    }
+
+    #[test]
+    fn polygon_triangulate() {
+        struct Test {
+            name: &'static str,
+            polygon: Polygon<f64>,
+            triangles: usize,
+            area: Option<f64>,
+        }
+
+        vec![
+            Test {
+                name: "convex quadrilateral",
+                polygon: Polygon::new(vec![
+                    point(-0.3, -0.2),
+                    point(0.4, -0.3),
+                    point(0.5, 0.4),
+                    point(-0.2, 0.3),
+                ]),
+                triangles: 2,
+                area: None,
+            },
+            Test {
+                name: "concave pentagon",
+                polygon: Polygon::new(vec![
+                    point(0., 0.),
+                    point(0.5, 0.),
+                    point(0.5, 0.5),
+                    point(0.25, 0.1),
+                    point(0., 0.5),
+                ]),
+                triangles: 3,
+                area: None,
+            },
+            Test {
+                name: "quadrilateral across the antimeridian",
+                polygon: Polygon::new(vec![
+                    point(PI - 0.2, -0.2),
+                    point(-PI + 0.2, -0.2),
+                    point(-PI + 0.2, 0.2),
+                    point(PI - 0.2, 0.2),
+                ]),
+                triangles: 2,
+                area: None,
+            },
+            Test {
+                name: "octant",
+                polygon: Polygon::new(vec![
+                    point(0., 0.),
+                    point(FRAC_PI_2, 0.),
+                    point(0., FRAC_PI_2),
+                ]),
+                triangles: 1,
+                area: Some(FRAC_PI_2),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let triangles = test.polygon.triangulate();
+            let tolerance = 1e-09;
+
+            assert_eq!(
+                triangles.len(),
+                test.triangles,
+                "{}: got triangles = {:?}",
+                test.name,
+                triangles
+            );
+
+            let area = test.polygon.area();
+            let summed = triangles
+                .iter()
+                .map(|[a, b, c]| triangle_area(a, b, c, 1.))
+                .sum::<f64>();
+
+            assert!(
+                (area - summed).abs() < 1e-06,
+                "{}: got summed area = {}, want {}",
+                test.name,
+                summed,
+                area
+            );
+
+            if let Some(want) = test.area {
+                assert!(
+                    (area - want).abs() < tolerance,
+                    "{}: got area = {}, want {}",
+                    test.name,
+                    area,
+                    want
+                );
+            }
+        });
+    }
+
+    #[test]
+    fn polygon_contains() {
+        struct Test {
+            name: &'static str,
+            polygon: Polygon<f64>,
+            point: Geographic<f64>,
+            contains: bool,
+        }
+
+        let across_antimeridian = Polygon::new(vec![
+            point(PI - 0.2, -0.2),
+            point(-PI + 0.2, -0.2),
+            point(-PI + 0.2, 0.2),
+            point(PI - 0.2, 0.2),
+        ]);
+
+        let around_the_pole = Polygon::new(vec![
+            point(0., 1.2),
+            point(2. * PI / 3., 1.2),
+            point(-2. * PI / 3., 1.2),
+        ]);
+
+        vec![
+            Test {
+                name: "point on the antimeridian must be inside",
+                polygon: across_antimeridian.clone(),
+                point: point(PI, 0.1),
+                contains: true,
+            },
+            Test {
+                name: "point on the prime meridian must be outside",
+                polygon: across_antimeridian.clone(),
+                point: point(0., 0.1),
+                contains: false,
+            },
+            Test {
+                name: "antipode of an inner point must be outside",
+                polygon: across_antimeridian,
+                point: point(0., 0.),
+                contains: false,
+            },
+            Test {
+                name: "pole must be inside",
+                polygon: around_the_pole.clone(),
+                point: point(0., FRAC_PI_2),
+                contains: true,
+            },
+            Test {
+                name: "point below the polygon must be outside",
+                polygon: around_the_pole,
+                point: point(0.5, 0.4),
+                contains: false,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let contains = test.polygon.contains(&test.point);
+            assert_eq!(
+                contains, test.contains,
+                "{}: got contains = {}, want {}",
+                test.name, contains, test.contains
+            );
+        });
+    }
+
+    #[test]
+    fn polygon_serde_round_trip() {
+        let polygon = Polygon {
+            vertices: vec![point(0., 0.), point(1., 0.), point(0.5, 1.)],
+        };
+
+        let encoded = serde_json::to_string(&polygon).unwrap();
+        let decoded: Polygon<f64> = serde_json::from_str(&encoded).unwrap();
+
+        assert_eq!(
+            decoded, polygon,
+            "got round trip = {decoded:?}, want {polygon:?}"
+        );
+    }
+}
@@ -0,0 +1,362 @@
+//! Small circle shape iterator.
+
+use alloc::{vec, vec::Vec};
+use core::num::NonZeroUsize;
+
+use num_traits::{Euclid, Float, FloatConst, Signed};
+
+use crate::{
+    transform::{Rotation, Transform},
+    Cartesian, Geographic, Radian,
+};
+
+/// A circle on the surface of a globe made of all the points at the same angular distance from a
+/// center.
+///
+/// ## Definition
+/// Unlike great circles, small circles do not split the globe in two halves, since their plane
+/// does not go through the center of the globe. The boundary is iterated in counter-clockwise
+/// order as seen from outside the globe, starting at the north of the center. Since the circle
+/// is closed, the first and last points of the iteration are the same.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        deserialize = "T: serde::Deserialize<'de> + PartialOrd + Signed + Float + FloatConst + Euclid"
+    ))
+)]
+pub struct SmallCircle<T> {
+    /// The center of the circle.
+    pub center: Geographic<T>,
+    /// The angular distance (in radians) from the center to any point of the circle.
+    pub radius: T,
+    /// The total amount of segments (straight lines) the circle is made of.
+    pub segments: NonZeroUsize,
+}
+
+impl<T> IntoIterator for SmallCircle<T>
+where
+    T: Default + PartialOrd + Signed + Float + FloatConst + Euclid,
+{
+    type Item = Geographic<T>;
+
+    type IntoIter = SmallCircleIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let start = self
+            .center
+            .destination(Radian::from(T::zero()), self.radius)
+            .into_cartesian()
+            .normal();
+
+        let rotation = Rotation::noop()
+            .with_axis(self.center.into_cartesian().normal())
+            .with_theta(
+                T::from(self.segments.get())
+                    .map(|segments| T::TAU() / segments)
+                    .unwrap_or_default()
+                    .into(),
+            );
+
+        SmallCircleIter {
+            start,
+            total_segments: self.segments.get(),
+            next_segment: 0,
+            rotation,
+        }
+    }
+}
+
+impl<T> SmallCircle<T>
+where
+    T: Float,
+{
+    /// Returns true if, and only if, the great-circle distance between the given point and the
+    /// center of the circle is not greater than its radius.
+    pub fn contains(&self, point: &Geographic<T>) -> bool {
+        self.center.central_angle(point) <= self.radius
+    }
+}
+
+impl<T> SmallCircle<T>
+where
+    T: PartialOrd + Signed + Float + FloatConst + Euclid,
+{
+    /// Returns the points where the boundaries of self and other meet, as required to fix a
+    /// position from two range rings.
+    ///
+    /// Two circles meet at two points at most, or at one if they are tangent. Disjoint circles,
+    /// as well as concentric (or antipodal) ones, have no intersection at all.
+    pub fn intersection(&self, other: &SmallCircle<T>) -> Vec<Geographic<T>> {
+        let lhs = self.center.into_cartesian().normal();
+        let rhs = other.center.into_cartesian().normal();
+
+        let axis = lhs.cross(&rhs);
+        let axis_squared = axis.dot(&axis);
+        if axis_squared <= T::epsilon() {
+            return Vec::new();
+        }
+
+        // the plane of each circle is the one orthogonal to its center at a distance of the
+        // cosine of its radius, and both planes meet at a line (the radical line) parallel to the
+        // cross product of both centers. The intersections are where that line meets the sphere.
+        let cos = lhs.dot(&rhs);
+        let (lhs_cos, rhs_cos) = (self.radius.cos(), other.radius.cos());
+        let lhs_weight = (lhs_cos - cos * rhs_cos) / axis_squared;
+        let rhs_weight = (rhs_cos - cos * lhs_cos) / axis_squared;
+        let closest = lhs * lhs_weight + rhs * rhs_weight;
+
+        // same tangency criteria as in Arc::crossings_at_latitude.
+        let tangency = T::epsilon() * T::from(32).unwrap_or_else(T::one);
+        let discriminant = (T::one() - closest.dot(&closest)) / axis_squared;
+        if discriminant < -tangency {
+            return Vec::new();
+        }
+
+        if discriminant <= tangency {
+            return vec![closest.normal().into()];
+        }
+
+        let offset = axis * discriminant.sqrt();
+        vec![(closest + offset).into(), (closest - offset).into()]
+    }
+}
+
+impl<T> SmallCircle<T>
+where
+    T: Default,
+{
+    pub fn new(segments: NonZeroUsize) -> Self {
+        Self {
+            center: Default::default(),
+            radius: Default::default(),
+            segments,
+        }
+    }
+}
+
+impl<T> SmallCircle<T> {
+    pub fn with_center(self, center: Geographic<T>) -> Self {
+        Self { center, ..self }
+    }
+
+    pub fn with_radius(self, radius: T) -> Self {
+        Self { radius, ..self }
+    }
+}
+
+/// Iterator over the [`SmallCircle`] shape.
+#[derive(Debug)]
+pub struct SmallCircleIter<T> {
+    start: Cartesian<T>,
+    total_segments: usize,
+    next_segment: usize,
+    rotation: Rotation<T>,
+}
+
+impl<T> Iterator for SmallCircleIter<T>
+where
+    T: Default + PartialOrd + Signed + Float + FloatConst + Euclid,
+{
+    type Item = Geographic<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next_segment > self.total_segments {
+            return None;
+        }
+
+        if self.next_segment == self.total_segments {
+            self.next_segment += 1;
+            return Some(self.start.into());
+        }
+
+        let next = Rotation::noop()
+            .with_axis(self.rotation.axis)
+            .with_theta(self.rotation.theta * T::from(self.next_segment)?)
+            .transform(self.start)
+            .into();
+
+        self.next_segment += 1;
+        Some(next)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{f64::consts::FRAC_PI_2, num::NonZeroUsize};
+
+    use crate::{shape::SmallCircle, Geographic, Latitude, Longitude};
+
+    #[test]
+    fn small_circle_contains() {
+        struct Test {
+            name: &'static str,
+            point: Geographic<f64>,
+            contains: bool,
+        }
+
+        let circle = SmallCircle::new(NonZeroUsize::MIN)
+            .with_center(
+                Geographic::origin()
+                    .with_longitude(Longitude::from(1.))
+                    .with_latitude(Latitude::from(0.5)),
+            )
+            .with_radius(0.2);
+
+        vec![
+            Test {
+                name: "center must be inside",
+                point: circle.center,
+                contains: true,
+            },
+            Test {
+                name: "point just inside the radius",
+                point: circle.center.with_latitude(Latitude::from(0.5 + 0.199)),
+                contains: true,
+            },
+            Test {
+                name: "point just outside the radius",
+                point: circle.center.with_latitude(Latitude::from(0.5 - 0.201)),
+                contains: false,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let contains = circle.contains(&test.point);
+            assert_eq!(
+                contains, test.contains,
+                "{}: got contains = {}, want {}",
+                test.name, contains, test.contains
+            );
+        });
+    }
+
+    #[test]
+    fn small_circle_iter() {
+        let circle = SmallCircle::new(NonZeroUsize::new(6).unwrap())
+            .with_center(Geographic::origin().with_latitude(Latitude::from(FRAC_PI_2)))
+            .with_radius(0.3);
+
+        let points: Vec<_> = circle.into_iter().collect();
+        let tolerance = 1e-09;
+
+        assert_eq!(points.len(), 7, "got points = {points:?}");
+        assert_eq!(points.first(), points.last(), "got points = {points:?}");
+
+        points.iter().for_each(|point| {
+            assert!(
+                (point.latitude.into_inner() - (FRAC_PI_2 - 0.3)).abs() < tolerance,
+                "got point = {point:?}, want it on the parallel of the circle"
+            );
+        });
+    }
+
+    #[test]
+    fn small_circle_intersection() {
+        struct Test {
+            name: &'static str,
+            lhs: SmallCircle<f64>,
+            rhs: SmallCircle<f64>,
+            intersection: Vec<Geographic<f64>>,
+        }
+
+        let circle = |longitude: f64, latitude: f64, radius: f64| {
+            SmallCircle::new(NonZeroUsize::MIN)
+                .with_center(
+                    Geographic::origin()
+                        .with_longitude(Longitude::from(longitude))
+                        .with_latitude(Latitude::from(latitude)),
+                )
+                .with_radius(radius)
+        };
+
+        let point = |longitude: f64, latitude: f64| {
+            Geographic::origin()
+                .with_longitude(Longitude::from(longitude))
+                .with_latitude(Latitude::from(latitude))
+        };
+
+        // both points at the prime meridian whose distance to the centers is the radius.
+        let latitude = (0.5_f64.cos() / 0.3_f64.cos()).acos();
+
+        vec![
+            Test {
+                name: "overlapping circles must meet at two symmetric points",
+                lhs: circle(-0.3, 0., 0.5),
+                rhs: circle(0.3, 0., 0.5),
+                intersection: vec![point(0., -latitude), point(0., latitude)],
+            },
+            Test {
+                name: "tangent circles must meet at a single point",
+                lhs: circle(0., 0.25, 0.25),
+                rhs: circle(0., -0.25, 0.25),
+                intersection: vec![point(0., 0.)],
+            },
+            Test {
+                name: "disjoint circles must not meet",
+                lhs: circle(-0.3, 0., 0.1),
+                rhs: circle(0.3, 0., 0.1),
+                intersection: vec![],
+            },
+            Test {
+                name: "nested circles must not meet",
+                lhs: circle(0., 0., 1.),
+                rhs: circle(0.1, 0., 0.2),
+                intersection: vec![],
+            },
+            Test {
+                name: "concentric circles must not meet",
+                lhs: circle(1., 1., 0.2),
+                rhs: circle(1., 1., 0.2),
+                intersection: vec![],
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let tolerance = 1e-09;
+
+            let mut intersection = test.lhs.intersection(&test.rhs);
+            intersection
+                .sort_by(|a, b| a.latitude.into_inner().total_cmp(&b.latitude.into_inner()));
+
+            assert_eq!(
+                intersection.len(),
+                test.intersection.len(),
+                "{}: got intersection = {:?}, want {:?}",
+                test.name,
+                intersection,
+                test.intersection
+            );
+
+            intersection
+                .iter()
+                .zip(&test.intersection)
+                .for_each(|(got, want)| {
+                    let distance = got
+                        .into_cartesian()
+                        .normal()
+                        .distance(&want.into_cartesian().normal());
+
+                    assert!(
+                        distance < tolerance,
+                        "{}: got intersection = {:?}, want {:?}",
+                        test.name,
+                        intersection,
+                        test.intersection
+                    );
+
+                    [test.lhs, test.rhs].iter().for_each(|circle| {
+                        let radius = circle.center.distance_meters(got, 1.);
+                        assert!(
+                            (radius - circle.radius).abs() < 1e-06,
+                            "{}: got distance to center = {}, want {}",
+                            test.name,
+                            radius,
+                            circle.radius
+                        );
+                    });
+                });
+        });
+    }
+}
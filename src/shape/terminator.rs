@@ -0,0 +1,80 @@
+//! Day/night terminator generator.
+
+use core::num::NonZeroUsize;
+
+use num_traits::FloatConst;
+
+use crate::Geographic;
+
+use super::SmallCircle;
+
+/// Returns the [terminator](https://en.wikipedia.org/wiki/Terminator_(solar)) for the given
+/// [subsolar point](crate::geographic::subsolar_point), made of the given amount of segments.
+///
+/// The terminator is the great circle a quarter of a turn away from the subsolar point, which
+/// splits the globe into the illuminated half (the one containing the subsolar point, see
+/// [`Geographic::is_illuminated`]) and the dark one. Hence the returned circle, centered at the
+/// subsolar point, contains the day side.
+pub fn terminator<T>(subsolar: Geographic<T>, segments: NonZeroUsize) -> SmallCircle<T>
+where
+    T: FloatConst,
+{
+    SmallCircle {
+        center: subsolar,
+        radius: T::FRAC_PI_2(),
+        segments,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{f64::consts::FRAC_PI_2, num::NonZeroUsize};
+
+    use crate::{geographic::subsolar_point, shape::terminator, Geographic, Latitude, Longitude};
+
+    #[test]
+    fn terminator_must_split_day_and_night() {
+        struct Test {
+            name: &'static str,
+            subsolar: Geographic<f64>,
+        }
+
+        vec![
+            Test {
+                name: "equinox noon at greenwich",
+                subsolar: subsolar_point(Latitude::from(0.), Longitude::from(0.)),
+            },
+            Test {
+                name: "solstice at an arbitrary hour",
+                subsolar: subsolar_point(Latitude::from(0.409), Longitude::from(2.5)),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let terminator = terminator(test.subsolar, NonZeroUsize::new(16).unwrap());
+            assert_eq!(
+                terminator.center, test.subsolar,
+                "{}: got center = {:?}, want {:?}",
+                test.name, terminator.center, test.subsolar
+            );
+
+            assert_eq!(
+                terminator.segments.get(),
+                16,
+                "{}: wrong segments",
+                test.name
+            );
+
+            terminator.into_iter().for_each(|point| {
+                let distance = test.subsolar.distance_meters(&point, 1.);
+                assert!(
+                    (distance - FRAC_PI_2).abs() < 1e-09,
+                    "{}: got distance to the subsolar point = {}, want {}",
+                    test.name,
+                    distance,
+                    FRAC_PI_2
+                );
+            });
+        });
+    }
+}
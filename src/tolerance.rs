@@ -0,0 +1,217 @@
+//! Tolerance definition.
+
+use num_traits::Float;
+
+/// The maximum absolute error accepted when comparing values that, being mathematically equal,
+/// have been computed through different paths (like a round trip between systems of coordinates).
+///
+/// ## Accuracy
+/// The default tolerances are the precision the crate guarantees for the conversions between
+/// systems of coordinates and the round trips of the projections, for values in the unit sphere:
+/// 1e-09 for `f64` and 1e-05 for `f32`. Values of greater magnitude (like distances in meters)
+/// require the tolerance to be scaled accordingly.
+///
+/// ## Example
+/// ```
+/// use geocart::{Cartesian, Geographic, Tolerance};
+///
+/// let point = Cartesian::origin().with_x(-0.5).with_y(0.25).with_z(0.8);
+/// let round_trip = Geographic::from(point).into_cartesian();
+///
+/// assert!(round_trip.approx_eq(&point, Tolerance::default()));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(from = "T", bound(deserialize = "T: serde::Deserialize<'de> + Float"))
+)]
+pub struct Tolerance<T>(T);
+
+impl Tolerance<f64> {
+    /// Returns the default tolerance for `f64` values.
+    pub const fn default_for_f64() -> Self {
+        Self(1e-09)
+    }
+}
+
+impl Default for Tolerance<f64> {
+    fn default() -> Self {
+        Self::default_for_f64()
+    }
+}
+
+impl Tolerance<f32> {
+    /// Returns the default tolerance for `f32` values.
+    pub const fn default_for_f32() -> Self {
+        Self(1e-05)
+    }
+}
+
+impl Default for Tolerance<f32> {
+    fn default() -> Self {
+        Self::default_for_f32()
+    }
+}
+
+impl<T> From<T> for Tolerance<T>
+where
+    T: Float,
+{
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T> Tolerance<T>
+where
+    T: Float,
+{
+    /// Returns the tolerance accepting the given absolute error, which is always positive.
+    pub fn new(value: T) -> Self {
+        Self(value.abs())
+    }
+
+    /// Returns true if, and only if, the absolute difference between lhs and rhs is not greater
+    /// than self.
+    pub fn approx_eq(&self, lhs: T, rhs: T) -> bool {
+        (lhs - rhs).abs() <= self.0
+    }
+}
+
+impl<T> Tolerance<T> {
+    /// Returns the inner value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        projection::{
+            CylindricalEqualArea, Equirectangular, Mercator, Mollweide, Projection,
+            TransverseMercator,
+        },
+        Cartesian, Geographic, Latitude, Longitude, Tolerance,
+    };
+
+    #[test]
+    fn tolerance_approx_eq() {
+        struct Test {
+            name: &'static str,
+            tolerance: Tolerance<f64>,
+            lhs: f64,
+            rhs: f64,
+            approx_eq: bool,
+        }
+
+        vec![
+            Test {
+                name: "values within the tolerance must be equal",
+                tolerance: Tolerance::default(),
+                lhs: 1.,
+                rhs: 1. + 1e-10,
+                approx_eq: true,
+            },
+            Test {
+                name: "values beyond the tolerance must not be equal",
+                tolerance: Tolerance::default(),
+                lhs: 1.,
+                rhs: 1. + 1e-08,
+                approx_eq: false,
+            },
+            Test {
+                name: "negative tolerance must be taken as positive",
+                tolerance: Tolerance::new(-0.5),
+                lhs: 1.,
+                rhs: 1.25,
+                approx_eq: true,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let approx_eq = test.tolerance.approx_eq(test.lhs, test.rhs);
+            assert_eq!(
+                approx_eq, test.approx_eq,
+                "{}: got approx_eq = {}, want {}",
+                test.name, approx_eq, test.approx_eq
+            );
+        });
+    }
+
+    #[test]
+    fn tolerance_default_must_fit_round_trips() {
+        let points = [(0., 0.), (1., 0.5), (-2.5, -1.2), (3., 1.3), (-0.4, 0.01)];
+
+        points.into_iter().for_each(|(longitude, latitude)| {
+            let point = Geographic::origin()
+                .with_longitude(Longitude::from(longitude))
+                .with_latitude(Latitude::from(latitude))
+                .with_altitude(1.0.into());
+
+            let round_trip = Geographic::from(point.into_cartesian());
+            assert!(
+                round_trip.approx_eq(&point, Tolerance::default()),
+                "got f64 round trip = {round_trip:?}, want {point:?}"
+            );
+
+            let cartesian = point.into_cartesian();
+            assert!(
+                Geographic::from(cartesian)
+                    .into_cartesian()
+                    .approx_eq(&cartesian, Tolerance::default()),
+                "got f64 cartesian round trip of {cartesian:?}"
+            );
+
+            let point = Geographic::origin()
+                .with_longitude(Longitude::from(longitude as f32))
+                .with_latitude(Latitude::from(latitude as f32))
+                .with_altitude(1.0.into());
+
+            let round_trip = Geographic::from(point.into_cartesian());
+            assert!(
+                round_trip.approx_eq(&point, Tolerance::default()),
+                "got f32 round trip = {round_trip:?}, want {point:?}"
+            );
+
+            let projections: Vec<Box<dyn Projection<f64>>> = vec![
+                Box::new(CylindricalEqualArea::default()),
+                Box::new(Equirectangular::default()),
+                Box::new(Mercator::default()),
+                Box::new(Mollweide::default()),
+                Box::new(TransverseMercator::default()),
+            ];
+
+            let point = Geographic::origin()
+                .with_longitude(Longitude::from(longitude))
+                .with_latitude(Latitude::from(latitude));
+
+            projections.into_iter().for_each(|projection| {
+                let round_trip = projection.reverse(&projection.forward(&point));
+                assert!(
+                    round_trip
+                        .into_cartesian()
+                        .approx_eq(&point.into_cartesian(), Tolerance::default()),
+                    "got projection round trip = {round_trip:?}, want {point:?}"
+                );
+            });
+        });
+    }
+
+    #[test]
+    fn tolerance_cartesian_approx_eq() {
+        let point = Cartesian::origin().with_x(1.).with_y(2.).with_z(3.);
+        let tolerance = Tolerance::new(0.1);
+
+        assert!(
+            point.approx_eq(&point.with_z(3.05), tolerance),
+            "got points further than the tolerance"
+        );
+
+        assert!(
+            !point.approx_eq(&point.with_x(1.08).with_y(2.08), tolerance),
+            "got points within the tolerance"
+        );
+    }
+}
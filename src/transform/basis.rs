@@ -0,0 +1,185 @@
+//! Change of basis transformation.
+
+use num_traits::Float;
+
+use crate::{cartesian::Cartesian, geographic::Geographic};
+
+use super::Transform;
+
+/// Implements the [change of basis](https://en.wikipedia.org/wiki/Change_of_basis) through which
+/// an arbitrary cartesian point is expressed in a different reference frame sharing the same
+/// origin.
+///
+/// ## Statement
+/// Being x, y and z three orthonormal vectors, the coordinates of a vector v in the frame they
+/// describe are the dot products of v with each of them.
+///
+/// ## Example
+/// ```
+/// use geocart::{
+///     transform::{Basis, Transform},
+///     Cartesian, Geographic,
+/// };
+///
+/// // at the intersection of the equator and the prime meridian the east points towards the y
+/// // axis, the north towards the z axis and the up towards the x axis.
+/// let local = Basis::enu_at(&Geographic::<f64>::default())
+///     .transform(Cartesian::origin().with_z(1.));
+///
+/// assert_eq!(local, Cartesian::origin().with_y(1.));
+/// ```
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Basis<T> {
+    /// The unit vector of the first axis of the frame.
+    pub x: Cartesian<T>,
+    /// The unit vector of the second axis of the frame.
+    pub y: Cartesian<T>,
+    /// The unit vector of the third axis of the frame.
+    pub z: Cartesian<T>,
+}
+
+impl<T> Transform<Cartesian<T>> for Basis<T>
+where
+    T: Float,
+{
+    fn transform(&self, point: Cartesian<T>) -> Cartesian<T> {
+        Cartesian::new(self.x.dot(&point), self.y.dot(&point), self.z.dot(&point))
+    }
+}
+
+impl<T> Basis<T>
+where
+    T: Float,
+{
+    /// Returns the basis of the local East-North-Up frame at the given point of the sphere.
+    ///
+    /// The altitude of the point is ignored, since the frame only depends on its direction. The
+    /// frame is undefined at the poles, where the east is conventionally taken along the meridian
+    /// zero.
+    pub fn enu_at(point: &Geographic<T>) -> Self {
+        Self {
+            x: point.tangent_east(),
+            y: point.tangent_north(),
+            z: point.normal_up(),
+        }
+    }
+}
+
+impl<T> Basis<T>
+where
+    T: Copy,
+{
+    /// Returns the basis that reverts the change performed by self.
+    ///
+    /// Since the vectors of the basis are orthonormal, the inverse of its matrix is its
+    /// transpose.
+    pub fn inverse(&self) -> Self {
+        Self {
+            x: Cartesian::new(self.x.x, self.y.x, self.z.x),
+            y: Cartesian::new(self.x.y, self.y.y, self.z.y),
+            z: Cartesian::new(self.x.z, self.y.z, self.z.z),
+        }
+    }
+}
+
+impl<T> Basis<T> {
+    /// Returns the basis made of the given vectors.
+    pub fn new(x: Cartesian<T>, y: Cartesian<T>, z: Cartesian<T>) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn with_x(self, x: Cartesian<T>) -> Self {
+        Self { x, ..self }
+    }
+
+    pub fn with_y(self, y: Cartesian<T>) -> Self {
+        Self { y, ..self }
+    }
+
+    pub fn with_z(self, z: Cartesian<T>) -> Self {
+        Self { z, ..self }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::FRAC_PI_2;
+
+    use crate::{
+        transform::{Basis, Transform},
+        Cartesian, Geographic, Latitude, Longitude,
+    };
+
+    #[test]
+    fn basis_enu_at() {
+        struct Test {
+            name: &'static str,
+            point: Geographic<f64>,
+            east: Cartesian<f64>,
+            north: Cartesian<f64>,
+            up: Cartesian<f64>,
+        }
+
+        vec![
+            Test {
+                name: "up at the north pole must point along the z axis",
+                point: Geographic::origin().with_latitude(Latitude::from(FRAC_PI_2)),
+                east: Cartesian::origin().with_y(1.),
+                north: Cartesian::origin().with_x(-1.),
+                up: Cartesian::origin().with_z(1.),
+            },
+            Test {
+                name: "equator at a quarter turn to the east",
+                point: Geographic::origin().with_longitude(Longitude::from(FRAC_PI_2)),
+                east: Cartesian::origin().with_x(-1.),
+                north: Cartesian::origin().with_z(1.),
+                up: Cartesian::origin().with_y(1.),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let basis = Basis::enu_at(&test.point);
+            let tolerance = 1e-09;
+
+            [
+                ("east", basis.x, test.east),
+                ("north", basis.y, test.north),
+                ("up", basis.z, test.up),
+            ]
+            .into_iter()
+            .for_each(|(axis, got, want)| {
+                got.into_iter().zip(want).for_each(|(got_c, want_c)| {
+                    assert!(
+                        (got_c - want_c).abs() < tolerance,
+                        "{}: got {} = {:?}, want {:?}",
+                        test.name,
+                        axis,
+                        got,
+                        want
+                    );
+                });
+            });
+        });
+    }
+
+    #[test]
+    fn basis_inverse_must_be_identity() {
+        let basis = Basis::enu_at(
+            &Geographic::origin()
+                .with_longitude(Longitude::from(-2.3))
+                .with_latitude(Latitude::from(0.7)),
+        );
+
+        let point = Cartesian::<f64>::new(1., -2., 3.);
+        let got = basis.inverse().transform(basis.transform(point));
+        let tolerance = 1e-09;
+
+        got.into_iter().zip(point).for_each(|(got_c, want_c)| {
+            assert!(
+                (got_c - want_c).abs() < tolerance,
+                "got point = {got:?}, want {point:?}"
+            );
+        });
+    }
+}
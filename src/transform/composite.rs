@@ -0,0 +1,81 @@
+//! Composition of transformations.
+
+use super::Transform;
+
+/// Implements the [composition](https://en.wikipedia.org/wiki/Function_composition) of two
+/// transformations, applying the first one and then the second one to its output.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Composite<A, B> {
+    /// The transformation applied first.
+    pub first: A,
+    /// The transformation applied to the output of the first one.
+    pub second: B,
+}
+
+impl<A, B, Rhs> Transform<Rhs> for Composite<A, B>
+where
+    A: Transform<Rhs>,
+    B: Transform<Rhs>,
+{
+    fn transform(&self, point: Rhs) -> Rhs {
+        self.second.transform(self.first.transform(point))
+    }
+}
+
+impl<A, B> Composite<A, B> {
+    pub fn new(first: A, second: B) -> Self {
+        Self { first, second }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::FRAC_PI_2;
+
+    use crate::{
+        transform::{Composite, Rotation, Transform, Translation},
+        Cartesian,
+    };
+
+    #[test]
+    fn composite_must_apply_first_then_second() {
+        let translation = Translation::new(Cartesian::origin().with_x(1.));
+        let rotation = Rotation::noop()
+            .with_axis(Cartesian::origin().with_z(1.))
+            .with_theta(FRAC_PI_2.into());
+
+        struct Test {
+            name: &'static str,
+            output: Cartesian<f64>,
+            want: Cartesian<f64>,
+        }
+
+        vec![
+            Test {
+                name: "translation then rotation",
+                output: Composite::new(translation, rotation).transform(Cartesian::origin()),
+                want: Cartesian::origin().with_y(1.),
+            },
+            Test {
+                name: "rotation then translation",
+                output: Composite::new(rotation, translation).transform(Cartesian::origin()),
+                want: Cartesian::origin().with_x(1.),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            test.output
+                .into_iter()
+                .zip(test.want)
+                .for_each(|(got, want)| {
+                    assert!(
+                        (got - want).abs() < 1e-09,
+                        "{}: got {:?}, want {:?}",
+                        test.name,
+                        test.output,
+                        test.want
+                    );
+                });
+        });
+    }
+}
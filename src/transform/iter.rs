@@ -0,0 +1,64 @@
+//! Lazy transformation of iterators.
+
+use super::Transform;
+
+/// Iterator yielding the transformation of each item of the inner iterator, as returned by
+/// [`Transform::transform_all`].
+#[derive(Debug, Clone)]
+pub struct TransformIter<'a, I, U: ?Sized> {
+    pub(super) iter: I,
+    pub(super) transform: &'a U,
+}
+
+impl<I, U> Iterator for TransformIter<'_, I, U>
+where
+    I: Iterator,
+    U: Transform<I::Item> + ?Sized,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|point| self.transform.transform(point))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{f64::consts::FRAC_PI_2, num::NonZeroUsize};
+
+    use crate::{
+        shape::Arc,
+        transform::{Rotation, Transform},
+        Cartesian, Geographic, Latitude, Longitude,
+    };
+
+    #[test]
+    fn transform_all_must_match_manual_mapping() {
+        let arc = Arc::new(NonZeroUsize::new(8).unwrap())
+            .with_from(Geographic::origin().with_latitude(Latitude::from(0.3)))
+            .with_to(Geographic::origin().with_longitude(Longitude::from(1.2)));
+
+        let rotation = Rotation::noop()
+            .with_axis(Cartesian::origin().with_z(1.))
+            .with_theta(FRAC_PI_2.into());
+
+        let points: Vec<_> = arc
+            .into_iter()
+            .map(|point| point.into_cartesian())
+            .collect();
+
+        let got: Vec<_> = rotation.transform_all(points.clone()).collect();
+        let want: Vec<_> = points
+            .into_iter()
+            .map(|point| rotation.transform(point))
+            .collect();
+
+        assert_eq!(got, want, "got transformed = {got:?}, want {want:?}");
+    }
+}
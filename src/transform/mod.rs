@@ -1,11 +1,33 @@
 //! Transform definitions and implementations.
 
+mod basis;
+mod composite;
+mod iter;
+mod project;
 mod rotation;
+mod translation;
 
+pub use self::basis::Basis;
+pub use self::composite::Composite;
+pub use self::iter::TransformIter;
+pub use self::project::{ProjectForward, ProjectReverse};
 pub use self::rotation::Rotation;
+pub use self::translation::Translation;
 
 /// A geometric transformation.
 pub trait Transform<Rhs> {
     /// Performs the transformation over `Rhs`.
     fn transform(&self, point: Rhs) -> Rhs;
+
+    /// Returns an iterator that lazily performs the transformation over each of the given points.
+    fn transform_all<I>(&self, points: I) -> TransformIter<'_, I::IntoIter, Self>
+    where
+        I: IntoIterator<Item = Rhs>,
+        Self: Sized,
+    {
+        TransformIter {
+            iter: points.into_iter(),
+            transform: self,
+        }
+    }
 }
@@ -0,0 +1,195 @@
+//! Projection transformations.
+
+use num_traits::{Euclid, Float, FloatConst, Signed};
+
+use crate::{projection::Projection, Cartesian, Geographic};
+
+use super::Transform;
+
+/// Bridges the forward operation of a [`Projection`] into a [`Transform`] over [`Cartesian`]
+/// points.
+///
+/// ## Statement
+/// The input is a point in the three dimensional space of the globe (like an
+/// [ECEF](https://en.wikipedia.org/wiki/Earth-centered,_Earth-fixed_coordinate_system) one), which
+/// is converted into its [`Geographic`] equivalent and then projected. The output is the projected
+/// point on the plane. That way, projections can be chained after any other transformation of the
+/// globe, like a rotation.
+///
+/// ## Example
+/// ```
+/// use std::f64::consts::FRAC_PI_2;
+///
+/// use geocart::{
+///     projection::Equirectangular,
+///     transform::{ProjectForward, Rotation, Transform},
+///     Cartesian,
+/// };
+///
+/// // due precision error both values may not be exactly the same
+/// let tolerance = 1e-09;
+///
+/// let rotated = Rotation::noop()
+///     .with_axis(Cartesian::origin().with_z(1.))
+///     .with_theta(FRAC_PI_2.into())
+///     .transform(Cartesian::origin().with_x(1.));
+///
+/// let projected = ProjectForward::new(Equirectangular::default()).transform(rotated);
+///
+/// assert!(
+///     (projected.x - FRAC_PI_2).abs() < tolerance,
+///     "the rotated point should be projected at a quarter of the width"
+/// );
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ProjectForward<P> {
+    /// The projection to apply.
+    pub projection: P,
+}
+
+impl<T, P> Transform<Cartesian<T>> for ProjectForward<P>
+where
+    T: PartialOrd + Signed + Float + FloatConst + Euclid,
+    P: Projection<T>,
+{
+    fn transform(&self, point: Cartesian<T>) -> Cartesian<T> {
+        self.projection.forward(&Geographic::from(point))
+    }
+}
+
+impl<P> ProjectForward<P> {
+    pub fn new(projection: P) -> Self {
+        Self { projection }
+    }
+}
+
+/// Bridges the reverse operation of a [`Projection`] into a [`Transform`] over [`Cartesian`]
+/// points.
+///
+/// ## Statement
+/// The input is a projected point on the plane, whose [`Geographic`] equivalent is converted into
+/// a point in the three dimensional space of the globe. Since projections discard the altitude,
+/// the output always lies on the unit sphere.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ProjectReverse<P> {
+    /// The projection to revert.
+    pub projection: P,
+}
+
+impl<T, P> Transform<Cartesian<T>> for ProjectReverse<P>
+where
+    T: Signed + Float + FloatConst,
+    P: Projection<T>,
+{
+    fn transform(&self, point: Cartesian<T>) -> Cartesian<T> {
+        self.projection.reverse(&point).into_cartesian()
+    }
+}
+
+impl<P> ProjectReverse<P> {
+    pub fn new(projection: P) -> Self {
+        Self { projection }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+    use crate::{
+        projection::{Equirectangular, Mercator, Projection},
+        transform::{ProjectForward, ProjectReverse, Rotation, Transform},
+        Cartesian, Geographic, Latitude, Longitude,
+    };
+
+    #[test]
+    fn project_forward_after_rotation() {
+        struct Test {
+            name: &'static str,
+            rotation: Rotation<f64>,
+            input: Cartesian<f64>,
+            output: Cartesian<f64>,
+        }
+
+        vec![
+            Test {
+                name: "rotation about the z axis must shift the longitude",
+                rotation: Rotation::noop()
+                    .with_axis(Cartesian::origin().with_z(1.))
+                    .with_theta(FRAC_PI_2.into()),
+                input: Cartesian::origin().with_x(1.),
+                output: Cartesian::origin().with_x(FRAC_PI_2),
+            },
+            Test {
+                name: "rotation about the y axis must shift the latitude",
+                rotation: Rotation::noop()
+                    .with_axis(Cartesian::origin().with_y(-1.))
+                    .with_theta(FRAC_PI_4.into()),
+                input: Cartesian::origin().with_x(2.),
+                output: Cartesian::origin().with_y(FRAC_PI_4),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let projection = ProjectForward::new(Equirectangular::default());
+            let projected = projection.transform(test.rotation.transform(test.input));
+            let tolerance = 1e-09;
+
+            projected
+                .into_iter()
+                .zip(test.output)
+                .for_each(|(got, want)| {
+                    assert!(
+                        (got - want).abs() < tolerance,
+                        "{}: got projected = {:?}, want {:?}",
+                        test.name,
+                        projected,
+                        test.output
+                    );
+                });
+        });
+    }
+
+    #[test]
+    fn project_reverse() {
+        let projection = Equirectangular::default();
+        let geographic = Geographic::<f64>::origin()
+            .with_longitude(Longitude::from(-1.))
+            .with_latitude(Latitude::from(0.5));
+
+        let got = ProjectReverse::new(projection).transform(projection.forward(&geographic));
+        let want = geographic.into_cartesian();
+        let tolerance = 1e-09;
+
+        got.into_iter().zip(want).for_each(|(got_c, want_c)| {
+            assert!(
+                (got_c - want_c).abs() < tolerance,
+                "got reversed = {got:?}, want {want:?}"
+            );
+        });
+    }
+
+    #[test]
+    fn project_forward_must_match_manual_steps() {
+        let points = vec![
+            Cartesian::origin().with_x(1.),
+            Cartesian::origin().with_x(-3.).with_y(2.).with_z(1.),
+            Cartesian::origin().with_y(-0.5).with_z(-7.),
+            Cartesian::origin().with_x(6_371_000.).with_z(1_000.),
+        ];
+
+        points.into_iter().for_each(|ecef| {
+            let geographic = Geographic::from(ecef);
+
+            let equirectangular = Equirectangular::default().with_radius(2.0.into());
+            let got = ProjectForward::new(equirectangular).transform(ecef);
+            let want = equirectangular.forward(&geographic);
+            assert_eq!(got, want, "equirectangular: got = {got:?}, want {want:?}");
+
+            let mercator = Mercator::default();
+            let got = ProjectForward::new(mercator).transform(ecef);
+            let want = mercator.forward(&geographic);
+            assert_eq!(got, want, "mercator: got = {got:?}, want {want:?}");
+        });
+    }
+}
@@ -1,10 +1,12 @@
 //! Rotation transformation.
 
+use core::ops::Mul;
+
 use num_traits::{Float, FloatConst, Signed};
 
-use crate::{cartesian::Cartesian, radian::Radian};
+use crate::{cartesian::Cartesian, radian::Radian, vector::Vector};
 
-use super::Transform;
+use super::{Composite, Transform, Translation};
 
 /// Implements the [geometric transformation](https://en.wikipedia.org/wiki/Rotation_matrix)
 /// through which an arbitrary cartesian point can be rotated given an axis and an angle of
@@ -54,12 +56,41 @@ where
     T: Float,
 {
     fn transform(&self, coords: Cartesian<T>) -> Cartesian<T> {
+        if self.axis == Cartesian::origin() {
+            // rotating about no axis at all performs no transformation.
+            return coords;
+        }
+
         coords * self.theta.into_inner().cos()
             + self.axis.cross(&coords) * self.theta.into_inner().sin()
             + self.axis * self.axis.dot(&coords) * (T::one() - self.theta.into_inner().cos())
     }
 }
 
+impl<T> Mul<Cartesian<T>> for Rotation<T>
+where
+    T: Float,
+{
+    type Output = Cartesian<T>;
+
+    /// Rotates the given point, same as [`Transform::transform`].
+    fn mul(self, rhs: Cartesian<T>) -> Self::Output {
+        self.transform(rhs)
+    }
+}
+
+impl<T> Mul for Rotation<T>
+where
+    T: Signed + Float + FloatConst,
+{
+    type Output = Self;
+
+    /// Composes both rotations, same as [`Rotation::compose`].
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.compose(&rhs)
+    }
+}
+
 impl<T> Rotation<T>
 where
     T: Signed + Float + FloatConst,
@@ -73,9 +104,186 @@ where
     }
 }
 
-impl<T> Rotation<T> {
+impl<T> Rotation<T>
+where
+    T: Signed + Float + FloatConst,
+{
+    /// Creates the rotation equivalent to the given [Tait–Bryan
+    /// angles](https://en.wikipedia.org/wiki/Euler_angles#Tait%E2%80%93Bryan_angles).
+    ///
+    /// ## Convention
+    /// The angles are intrinsic, in the z-y′-x″ order: the yaw about the z axis is applied first,
+    /// then the pitch about the new y axis, and finally the roll about the new x axis. That is,
+    /// the rotation matrix is Rz(yaw)·Ry(pitch)·Rx(roll).
+    pub fn from_euler(roll: Radian<T>, pitch: Radian<T>, yaw: Radian<T>) -> Self {
+        let two = T::one() + T::one();
+        let (sr, cr) = (roll.into_inner() / two).sin_cos();
+        let (sp, cp) = (pitch.into_inner() / two).sin_cos();
+        let (sy, cy) = (yaw.into_inner() / two).sin_cos();
+
+        // the quaternion representing the same rotation.
+        let w = cr * cp * cy + sr * sp * sy;
+        let vector = Cartesian::origin()
+            .with_x(sr * cp * cy - cr * sp * sy)
+            .with_y(cr * sp * cy + sr * cp * sy)
+            .with_z(cr * cp * sy - sr * sp * cy);
+
+        Self::from_quaternion(w, vector)
+    }
+
+    /// Returns the rotation equivalent to applying rhs first, and then self. That is, the one
+    /// whose rotation matrix is the product of the matrices of self and rhs, in that order.
+    pub fn compose(&self, rhs: &Self) -> Self {
+        let two = T::one() + T::one();
+        let (lhs_sin, lhs_w) = (self.theta.into_inner() / two).sin_cos();
+        let (rhs_sin, rhs_w) = (rhs.theta.into_inner() / two).sin_cos();
+        let (lhs_vector, rhs_vector) = (self.axis * lhs_sin, rhs.axis * rhs_sin);
+
+        // the hamilton product of the quaternions representing both rotations.
+        let w = lhs_w * rhs_w - lhs_vector.dot(&rhs_vector);
+        let vector = rhs_vector * lhs_w + lhs_vector * rhs_w + lhs_vector.cross(&rhs_vector);
+
+        Self::from_quaternion(w, vector)
+    }
+
+    /// Returns the rotation represented by the unit quaternion with the given scalar and vector
+    /// parts.
+    fn from_quaternion(w: T, vector: Cartesian<T>) -> Self {
+        let sin_half_theta = vector.magnitude();
+        if sin_half_theta <= T::epsilon() {
+            return Self::noop();
+        }
+
+        Self {
+            axis: vector / sin_half_theta,
+            theta: ((T::one() + T::one()) * sin_half_theta.atan2(w)).into(),
+        }
+    }
+
+    /// Returns the roll, pitch and yaw angles of the rotation, following the same convention as
+    /// [`Rotation::from_euler`].
+    ///
+    /// ## Gimbal lock
+    /// When the pitch is ±π/2 the roll and the yaw rotate about the same axis, so they cannot be
+    /// told apart. In such a case the yaw is zero and the combined angle is assigned to the roll.
+    pub fn to_euler(&self) -> (Radian<T>, Radian<T>, Radian<T>) {
+        let matrix = self.matrix();
+
+        let sin_pitch = (-matrix[2][0]).max(-T::one()).min(T::one());
+        let pitch = sin_pitch.asin();
+
+        if sin_pitch.abs() >= T::one() - T::epsilon() {
+            let roll = (-matrix[1][2]).atan2(matrix[1][1]);
+            return (roll.into(), pitch.into(), T::zero().into());
+        }
+
+        let roll = matrix[2][1].atan2(matrix[2][2]);
+        let yaw = matrix[1][0].atan2(matrix[0][0]);
+        (roll.into(), pitch.into(), yaw.into())
+    }
+
+    /// Returns the rotation about the same axis whose angle is the one of self scaled by the
+    /// given exponent. That is, the result of applying self as many times as the exponent says.
+    ///
+    /// The scaled angle is the signed one in the range __(-π, +π]__ (see [`Rotation::minimal`]),
+    /// so that a fraction of a rotation is a fraction of the shortest path.
+    pub fn powf(&self, exponent: T) -> Self {
+        let minimal = self.minimal();
+
+        Self {
+            axis: minimal.axis,
+            theta: minimal.theta * exponent,
+        }
+    }
+
+    /// Returns the rotation equivalent to self whose angle is not greater than π. That is, self
+    /// if its angle is already in range, or the complementary rotation about the negated axis
+    /// otherwise.
+    pub fn minimal(&self) -> Self {
+        if self.theta.into_inner() <= T::PI() {
+            return *self;
+        }
+
+        Self {
+            axis: self.axis * -T::one(),
+            theta: (T::TAU() - self.theta.into_inner()).into(),
+        }
+    }
+
+    /// Returns the [rotation matrix](https://en.wikipedia.org/wiki/Rotation_matrix#Rotation_matrix_from_axis_and_angle)
+    /// of self.
+    fn matrix(&self) -> [[T; 3]; 3] {
+        let Cartesian { x, y, z } = self.axis;
+        let (sin, cos) = self.theta.into_inner().sin_cos();
+        let one_minus_cos = T::one() - cos;
+
+        [
+            [
+                cos + x * x * one_minus_cos,
+                x * y * one_minus_cos - z * sin,
+                x * z * one_minus_cos + y * sin,
+            ],
+            [
+                y * x * one_minus_cos + z * sin,
+                cos + y * y * one_minus_cos,
+                y * z * one_minus_cos - x * sin,
+            ],
+            [
+                z * x * one_minus_cos - y * sin,
+                z * y * one_minus_cos + x * sin,
+                cos + z * z * one_minus_cos,
+            ],
+        ]
+    }
+}
+
+impl<T> Rotation<T>
+where
+    T: Float,
+{
+    /// Returns a copy of self rotating about the given axis, normalized into a unit vector.
+    ///
+    /// A null axis describes no rotation at all, and so it is kept as is.
     pub fn with_axis(self, axis: Cartesian<T>) -> Self {
-        Self { axis, ..self }
+        let magnitude = axis.magnitude();
+        if magnitude <= T::epsilon() {
+            return Self {
+                axis: Cartesian::origin(),
+                ..self
+            };
+        }
+
+        Self {
+            axis: axis / magnitude,
+            ..self
+        }
+    }
+}
+
+impl<T> Rotation<T>
+where
+    T: Copy + Float,
+{
+    /// Returns the transformation rotating about an axis parallel to the one of self that goes
+    /// through the given pivot, instead of the origin.
+    ///
+    /// That is, the pivot is moved to the origin, the point is rotated, and then moved back.
+    pub fn about_point(&self, pivot: Cartesian<T>) -> impl Transform<Cartesian<T>> {
+        Composite::new(
+            Composite::new(Translation::new(pivot * -T::one()), *self),
+            Translation::new(pivot),
+        )
+    }
+}
+
+impl<T> Rotation<T> {
+    /// Returns a copy of self rotating about the given axis, which needs no normalization since
+    /// it is a unit vector already.
+    pub fn with_unit_axis(self, axis: Vector<T>) -> Self {
+        Self {
+            axis: axis.into(),
+            ..self
+        }
     }
 
     pub fn with_theta(self, theta: Radian<T>) -> Self {
@@ -85,12 +293,12 @@ impl<T> Rotation<T> {
 
 #[cfg(test)]
 mod tests {
-    use std::f64::consts::{FRAC_PI_2, PI};
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI, TAU};
 
     use crate::{
         radian::Radian,
         transform::{Rotation, Transform},
-        Cartesian,
+        Cartesian, Vector,
     };
 
     #[test]
@@ -184,4 +392,502 @@ mod tests {
                 });
         });
     }
+
+    #[test]
+    fn cartesian_rotation_f32() {
+        use std::f32::consts::{FRAC_PI_2, PI};
+
+        struct Test {
+            name: &'static str,
+            theta: Radian<f32>,
+            axis: Cartesian<f32>,
+            input: Cartesian<f32>,
+            output: Cartesian<f32>,
+        }
+
+        vec![
+            Test {
+                name: "half of a whole rotation on the x axis must change the y point",
+                theta: Radian::from(PI),
+                axis: Cartesian::origin().with_x(1.),
+                input: Cartesian::origin().with_y(1.),
+                output: Cartesian::origin().with_y(-1.),
+            },
+            Test {
+                name: "a quarter of a whole rotation on the z axis must change the y point",
+                theta: Radian::from(FRAC_PI_2),
+                axis: Cartesian::origin().with_z(1.),
+                input: Cartesian::origin().with_y(1.),
+                output: Cartesian::origin().with_x(-1.),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let rotated = Rotation::noop()
+                .with_axis(test.axis)
+                .with_theta(test.theta)
+                .transform(test.input);
+
+            let tolerance = 1e-06;
+
+            rotated
+                .into_iter()
+                .zip(test.output)
+                .for_each(|(got, want)| {
+                    assert!(
+                        (got - want).abs() < tolerance,
+                        "{}: got rotated = {:?}, want {:?}",
+                        test.name,
+                        rotated,
+                        test.output
+                    );
+                });
+        });
+    }
+
+    #[test]
+    fn rotation_euler_angles() {
+        struct Test {
+            name: &'static str,
+            input: (f64, f64, f64),
+            output: (f64, f64, f64),
+        }
+
+        vec![
+            Test {
+                name: "noop rotation must have no angles",
+                input: (0., 0., 0.),
+                output: (0., 0., 0.),
+            },
+            Test {
+                name: "arbitrary angles must be recovered",
+                input: (0.3, 0.2, 1.),
+                output: (0.3, 0.2, 1.),
+            },
+            Test {
+                name: "negative angles must be recovered",
+                input: (-1.2, -0.7, 2.5),
+                output: (-1.2, -0.7, 2.5),
+            },
+            Test {
+                name: "north gimbal lock must assign the combined angle to the roll",
+                input: (0.4, FRAC_PI_2, 0.1),
+                output: (0.3, FRAC_PI_2, 0.),
+            },
+            Test {
+                name: "south gimbal lock must assign the combined angle to the roll",
+                input: (0.4, -FRAC_PI_2, 0.1),
+                output: (0.5, -FRAC_PI_2, 0.),
+            },
+            Test {
+                name: "pitch close to the north gimbal lock must keep every angle",
+                input: (0.4, FRAC_PI_2 - 1e-06, 0.1),
+                output: (0.4, FRAC_PI_2 - 1e-06, 0.1),
+            },
+            Test {
+                name: "pitch close to the south gimbal lock must keep every angle",
+                input: (-2.1, -FRAC_PI_2 + 1e-06, 1.3),
+                output: (-2.1, -FRAC_PI_2 + 1e-06, 1.3),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let (roll, pitch, yaw) = Rotation::from_euler(
+                test.input.0.into(),
+                test.input.1.into(),
+                test.input.2.into(),
+            )
+            .to_euler();
+
+            let tolerance = 1e-06;
+            [
+                (roll, test.output.0),
+                (pitch, test.output.1),
+                (yaw, test.output.2),
+            ]
+            .into_iter()
+            .for_each(|(got, want)| {
+                let got = got.into_inner();
+                let want = Radian::from(want).into_inner();
+                let diff = (got - want).abs();
+
+                assert!(
+                    diff < tolerance || (diff - TAU).abs() < tolerance,
+                    "{}: got angles = {:?}, want {:?}",
+                    test.name,
+                    (roll, pitch, yaw),
+                    test.output
+                );
+            });
+        });
+    }
+
+    #[test]
+    fn rotation_from_euler_must_match_matrix_order() {
+        // a yaw of π/2 followed by a pitch of π/2 takes the x axis to the -z one.
+        let rotated = Rotation::from_euler(0_f64.into(), FRAC_PI_2.into(), FRAC_PI_2.into())
+            .transform(Cartesian::origin().with_x(1.));
+
+        let tolerance = 1e-09;
+        rotated
+            .into_iter()
+            .zip(Cartesian::origin().with_z(-1.))
+            .for_each(|(got, want)| {
+                assert!(
+                    (got - want).abs() < tolerance,
+                    "got rotated = {rotated:?}, want -z"
+                );
+            });
+    }
+
+    #[test]
+    fn rotation_powf() {
+        struct Test {
+            name: &'static str,
+            rotation: Rotation<f64>,
+            exponent: f64,
+            output: Rotation<f64>,
+        }
+
+        let axis = Cartesian::origin().with_x(0.6).with_y(0.8);
+
+        vec![
+            Test {
+                name: "squared rotation must double the angle",
+                rotation: Rotation::noop()
+                    .with_axis(axis)
+                    .with_theta(FRAC_PI_4.into()),
+                exponent: 2.,
+                output: Rotation::noop()
+                    .with_axis(axis)
+                    .with_theta(FRAC_PI_2.into()),
+            },
+            Test {
+                name: "square root rotation must halve the angle",
+                rotation: Rotation::noop()
+                    .with_axis(axis)
+                    .with_theta(FRAC_PI_2.into()),
+                exponent: 0.5,
+                output: Rotation::noop()
+                    .with_axis(axis)
+                    .with_theta(FRAC_PI_4.into()),
+            },
+            Test {
+                name: "square root of a negative rotation must halve the negative angle",
+                rotation: Rotation::noop()
+                    .with_axis(axis)
+                    .with_theta((-FRAC_PI_2).into()),
+                exponent: 0.5,
+                output: Rotation::noop()
+                    .with_axis(axis)
+                    .with_theta((-FRAC_PI_4).into()),
+            },
+            Test {
+                name: "inverse rotation must negate the angle",
+                rotation: Rotation::noop()
+                    .with_axis(axis)
+                    .with_theta(FRAC_PI_4.into()),
+                exponent: -1.,
+                output: Rotation::noop()
+                    .with_axis(axis)
+                    .with_theta((-FRAC_PI_4).into()),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let rotation = test.rotation.powf(test.exponent);
+            let tolerance = 1e-09;
+
+            [
+                Cartesian::origin().with_x(1.),
+                Cartesian::origin().with_y(1.),
+                Cartesian::origin().with_x(1.).with_y(2.).with_z(3.),
+            ]
+            .into_iter()
+            .for_each(|point| {
+                let got = rotation.transform(point);
+                let want = test.output.transform(point);
+
+                got.into_iter().zip(want).for_each(|(got_c, want_c)| {
+                    assert!(
+                        (got_c - want_c).abs() < tolerance,
+                        "{}: got rotated = {:?}, want {:?}",
+                        test.name,
+                        got,
+                        want
+                    );
+                });
+            });
+        });
+    }
+
+    #[test]
+    fn rotation_axis_must_be_normalized() {
+        struct Test {
+            name: &'static str,
+            axis: Cartesian<f64>,
+            output: Cartesian<f64>,
+        }
+
+        let input = Cartesian::origin().with_x(2.).with_y(-1.).with_z(0.5);
+
+        vec![
+            Test {
+                name: "non unit axis must rotate as its unit vector",
+                axis: Cartesian::origin().with_z(5.),
+                output: Rotation::noop()
+                    .with_axis(Cartesian::<f64>::UNIT_Z)
+                    .with_theta(FRAC_PI_4.into())
+                    .transform(input),
+            },
+            Test {
+                name: "null axis must perform no rotation",
+                axis: Cartesian::origin(),
+                output: input,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let rotated = Rotation::noop()
+                .with_axis(test.axis)
+                .with_theta(FRAC_PI_4.into())
+                .transform(input);
+
+            let tolerance = 1e-09;
+            rotated
+                .into_iter()
+                .zip(test.output)
+                .for_each(|(got, want)| {
+                    assert!(
+                        (got - want).abs() < tolerance,
+                        "{}: got rotated = {:?}, want {:?}",
+                        test.name,
+                        rotated,
+                        test.output
+                    );
+                });
+        });
+    }
+
+    #[test]
+    fn rotation_about_point() {
+        struct Test {
+            name: &'static str,
+            rotation: Rotation<f64>,
+            pivot: Cartesian<f64>,
+            input: Cartesian<f64>,
+            output: Cartesian<f64>,
+        }
+
+        vec![
+            Test {
+                name: "half turn must move the point to the opposite side of the pivot",
+                rotation: Rotation::noop()
+                    .with_axis(Cartesian::origin().with_z(1.))
+                    .with_theta(PI.into()),
+                pivot: Cartesian::origin().with_x(2.).with_y(1.),
+                input: Cartesian::origin().with_x(3.).with_y(1.).with_z(4.),
+                output: Cartesian::origin().with_x(1.).with_y(1.).with_z(4.),
+            },
+            Test {
+                name: "pivot must stay in place",
+                rotation: Rotation::noop()
+                    .with_axis(Cartesian::origin().with_x(1.).with_y(1.))
+                    .with_theta(FRAC_PI_4.into()),
+                pivot: Cartesian::origin().with_x(-1.).with_y(5.).with_z(2.),
+                input: Cartesian::origin().with_x(-1.).with_y(5.).with_z(2.),
+                output: Cartesian::origin().with_x(-1.).with_y(5.).with_z(2.),
+            },
+            Test {
+                name: "origin pivot must match the plain rotation",
+                rotation: Rotation::noop()
+                    .with_axis(Cartesian::origin().with_x(1.))
+                    .with_theta(FRAC_PI_2.into()),
+                pivot: Cartesian::origin(),
+                input: Cartesian::origin().with_y(1.),
+                output: Cartesian::origin().with_z(1.),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let output = test.rotation.about_point(test.pivot).transform(test.input);
+            output.into_iter().zip(test.output).for_each(|(got, want)| {
+                assert!(
+                    (got - want).abs() < 1e-09,
+                    "{}: got {:?}, want {:?}",
+                    test.name,
+                    output,
+                    test.output
+                );
+            });
+        });
+    }
+
+    #[test]
+    fn rotation_with_unit_axis() {
+        let axis = Cartesian::origin().with_x(1.).with_y(2.).with_z(-2.);
+        let point = Cartesian::origin().with_x(0.5).with_y(-3.).with_z(1.);
+
+        let want = Rotation::noop()
+            .with_axis(axis)
+            .with_theta(1.2.into())
+            .transform(point);
+
+        let got = Rotation::noop()
+            .with_unit_axis(Vector::from_cartesian(axis).unwrap())
+            .with_theta(1.2.into())
+            .transform(point);
+
+        assert!(
+            got.distance(&want) < 1e-09,
+            "got rotated = {got:?}, want {want:?}"
+        );
+    }
+
+    #[test]
+    fn rotation_mul() {
+        struct Test {
+            name: &'static str,
+            lhs: Rotation<f64>,
+            rhs: Rotation<f64>,
+        }
+
+        let about = |axis: Cartesian<f64>, theta: f64| {
+            Rotation::noop()
+                .with_axis(axis)
+                .with_theta(Radian::from(theta))
+        };
+
+        let points = [
+            Cartesian::origin().with_x(1.),
+            Cartesian::origin().with_x(-2.).with_y(0.5).with_z(3.),
+            Cartesian::origin().with_y(-1.).with_z(0.25),
+        ];
+
+        vec![
+            Test {
+                name: "rotations about the same axis must add up",
+                lhs: about(Cartesian::origin().with_z(1.), FRAC_PI_2),
+                rhs: about(Cartesian::origin().with_z(1.), FRAC_PI_4),
+            },
+            Test {
+                name: "rotations about different axes must apply rhs first",
+                lhs: about(Cartesian::origin().with_x(1.), FRAC_PI_2),
+                rhs: about(Cartesian::origin().with_z(1.), FRAC_PI_2),
+            },
+            Test {
+                name: "arbitrary rotations must compose",
+                lhs: about(Cartesian::origin().with_x(1.).with_y(-2.).with_z(0.5), 2.5),
+                rhs: about(Cartesian::origin().with_x(0.3).with_y(1.).with_z(-1.), 4.),
+            },
+            Test {
+                name: "opposite rotations must cancel each other",
+                lhs: about(Cartesian::origin().with_y(1.), 1.),
+                rhs: about(Cartesian::origin().with_y(1.), TAU - 1.),
+            },
+            Test {
+                name: "noop must not change the rotation",
+                lhs: Rotation::noop(),
+                rhs: about(Cartesian::origin().with_z(1.), PI),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let tolerance = 1e-09;
+            let composed = test.lhs * test.rhs;
+
+            points.into_iter().for_each(|point| {
+                let got = test.lhs * point;
+                let want = test.lhs.transform(point);
+                assert_eq!(
+                    got, want,
+                    "{}: got rotation * point = {:?}, want {:?}",
+                    test.name, got, want
+                );
+
+                let got = composed * point;
+                let want = test.lhs.transform(test.rhs.transform(point));
+                assert!(
+                    got.distance(&want) < tolerance,
+                    "{}: got composed rotation of {:?} = {:?}, want {:?}",
+                    test.name,
+                    point,
+                    got,
+                    want
+                );
+            });
+        });
+    }
+
+    #[test]
+    fn rotation_minimal() {
+        struct Test {
+            name: &'static str,
+            rotation: Rotation<f64>,
+            output: Rotation<f64>,
+        }
+
+        let axis = Cartesian::origin().with_x(0.6).with_y(0.8);
+
+        vec![
+            Test {
+                name: "rotation greater than pi must turn the other way around",
+                rotation: Rotation::noop()
+                    .with_axis(axis)
+                    .with_theta((3. * FRAC_PI_2).into()),
+                output: Rotation::noop()
+                    .with_axis(axis * -1.)
+                    .with_theta(FRAC_PI_2.into()),
+            },
+            Test {
+                name: "rotation lower than pi must not change",
+                rotation: Rotation::noop()
+                    .with_axis(axis)
+                    .with_theta(FRAC_PI_2.into()),
+                output: Rotation::noop()
+                    .with_axis(axis)
+                    .with_theta(FRAC_PI_2.into()),
+            },
+            Test {
+                name: "rotation of pi must not change",
+                rotation: Rotation::noop().with_axis(axis).with_theta(PI.into()),
+                output: Rotation::noop().with_axis(axis).with_theta(PI.into()),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let minimal = test.rotation.minimal();
+            let tolerance = 1e-09;
+
+            assert!(
+                minimal.theta.into_inner() <= PI
+                    && (minimal.theta.into_inner() - test.output.theta.into_inner()).abs()
+                        < tolerance
+                    && minimal.axis.distance(&test.output.axis) < tolerance,
+                "{}: got minimal rotation = {:?}, want {:?}",
+                test.name,
+                minimal,
+                test.output
+            );
+
+            [
+                Cartesian::origin().with_x(1.),
+                Cartesian::origin().with_z(1.),
+                Cartesian::origin().with_x(1.).with_y(2.).with_z(3.),
+            ]
+            .into_iter()
+            .for_each(|point| {
+                let got = minimal.transform(point);
+                let want = test.rotation.transform(point);
+
+                assert!(
+                    got.distance(&want) < tolerance,
+                    "{}: got rotated point = {:?}, want {:?}",
+                    test.name,
+                    got,
+                    want
+                );
+            });
+        });
+    }
 }
@@ -0,0 +1,73 @@
+//! Translation transformation.
+
+use core::ops::Add;
+
+use crate::cartesian::Cartesian;
+
+use super::Transform;
+
+/// Implements the [geometric transformation](https://en.wikipedia.org/wiki/Translation_(geometry))
+/// that moves every point by the same offset.
+#[derive(Debug, Default, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Translation<T> {
+    /// The vector by which every point is moved.
+    pub offset: Cartesian<T>,
+}
+
+impl<T> Transform<Cartesian<T>> for Translation<T>
+where
+    T: Copy + Add<Output = T>,
+{
+    fn transform(&self, coords: Cartesian<T>) -> Cartesian<T> {
+        coords + self.offset
+    }
+}
+
+impl<T> Translation<T> {
+    pub fn new(offset: Cartesian<T>) -> Self {
+        Self { offset }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        transform::{Transform, Translation},
+        Cartesian,
+    };
+
+    #[test]
+    fn cartesian_translation() {
+        struct Test {
+            name: &'static str,
+            translation: Translation<f64>,
+            input: Cartesian<f64>,
+            output: Cartesian<f64>,
+        }
+
+        vec![
+            Test {
+                name: "null offset must not move the point",
+                translation: Translation::default(),
+                input: Cartesian::origin().with_x(1.).with_y(2.).with_z(3.),
+                output: Cartesian::origin().with_x(1.).with_y(2.).with_z(3.),
+            },
+            Test {
+                name: "offset must be added to the point",
+                translation: Translation::new(Cartesian::origin().with_x(-1.).with_z(2.)),
+                input: Cartesian::origin().with_x(1.).with_y(2.).with_z(3.),
+                output: Cartesian::origin().with_y(2.).with_z(5.),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let output = test.translation.transform(test.input);
+            assert_eq!(
+                output, test.output,
+                "{}: got {:?}, want {:?}",
+                test.name, output, test.output
+            );
+        });
+    }
+}
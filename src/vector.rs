@@ -0,0 +1,106 @@
+//! Vector definition.
+
+use num_traits::Float;
+
+use crate::Cartesian;
+
+/// A direction in ℝ3, which is always a unit vector.
+///
+/// Since deserialized values could break that invariant, vectors can only be serialized.
+///
+/// ## Example
+/// ```
+/// use geocart::{Cartesian, Vector};
+///
+/// let up = Vector::from_cartesian(Cartesian::origin().with_z(5.)).unwrap();
+/// assert_eq!(up.as_cartesian(), &Cartesian::origin().with_z(1.));
+///
+/// assert!(Vector::<f64>::from_cartesian(Cartesian::origin()).is_none());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Vector<T>(Cartesian<T>);
+
+impl<T> From<Vector<T>> for Cartesian<T> {
+    fn from(vector: Vector<T>) -> Self {
+        vector.0
+    }
+}
+
+impl<T> Vector<T>
+where
+    T: Float,
+{
+    /// Returns the direction of the given cartesian vector, or [`None`] if it has no direction
+    /// at all (its magnitude is zero, or not even finite).
+    pub fn from_cartesian(coords: Cartesian<T>) -> Option<Self> {
+        let magnitude = coords.magnitude();
+        if !magnitude.is_finite() || magnitude <= T::epsilon() {
+            return None;
+        }
+
+        Some(Self(coords / magnitude))
+    }
+}
+
+impl<T> Vector<T> {
+    /// Returns the cartesian coordinates of the unit vector.
+    pub fn as_cartesian(&self) -> &Cartesian<T> {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Cartesian, Vector};
+
+    #[test]
+    fn vector_from_cartesian() {
+        struct Test {
+            name: &'static str,
+            input: Cartesian<f64>,
+            output: Option<Cartesian<f64>>,
+        }
+
+        vec![
+            Test {
+                name: "vector along an axis must be scaled to unit length",
+                input: Cartesian::origin().with_z(5.),
+                output: Some(Cartesian::origin().with_z(1.)),
+            },
+            Test {
+                name: "arbitrary vector must be normalized",
+                input: Cartesian::origin().with_x(3.).with_y(-4.),
+                output: Some(Cartesian::origin().with_x(0.6).with_y(-0.8)),
+            },
+            Test {
+                name: "origin must have no direction",
+                input: Cartesian::origin(),
+                output: None,
+            },
+            Test {
+                name: "infinite vector must have no direction",
+                input: Cartesian::origin().with_y(f64::INFINITY),
+                output: None,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let output = Vector::from_cartesian(test.input).map(Cartesian::from);
+
+            match (output, test.output) {
+                (Some(got), Some(want)) => {
+                    assert!(
+                        got.distance(&want) < 1e-09 && (got.magnitude() - 1.).abs() < 1e-09,
+                        "{}: got {:?}, want {:?}",
+                        test.name,
+                        got,
+                        want
+                    );
+                }
+                (None, None) => {}
+                (got, want) => panic!("{}: got {:?}, want {:?}", test.name, got, want),
+            }
+        });
+    }
+}